@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+use super::*;
+
+impl<A: Aleo> FromBits for Signature<A> {
+    type Boolean = Boolean<A>;
+
+    /// Initializes a signature from the little-endian bits of `(challenge, response, pk_sig, pr_sig)`.
+    fn from_bits_le(bits_le: &[Self::Boolean]) -> Self {
+        // Ensure the number of bits is correct.
+        let (scalar_size, field_size) = (A::ScalarField::size_in_bits(), A::BaseField::size_in_bits());
+        if bits_le.len() != 2 * scalar_size + 2 * field_size {
+            A::halt(format!("Invalid signature size: found {} bits", bits_le.len()))
+        }
+
+        // Split the bits into the challenge, response, pk_sig, and pr_sig.
+        let (challenge, bits_le) = bits_le.split_at(scalar_size);
+        let (response, bits_le) = bits_le.split_at(scalar_size);
+        let (pk_sig, pr_sig) = bits_le.split_at(field_size);
+
+        // Recover the compute key from the x-coordinates of pk_sig and pr_sig.
+        let compute_key = ComputeKey::from((Group::from_bits_le(pk_sig), Group::from_bits_le(pr_sig)));
+
+        Self { challenge: Scalar::from_bits_le(challenge), response: Scalar::from_bits_le(response), compute_key }
+    }
+
+    /// Initializes a signature from the big-endian bits of `(challenge, response, pk_sig, pr_sig)`.
+    fn from_bits_be(bits_be: &[Self::Boolean]) -> Self {
+        // Ensure the number of bits is correct.
+        let (scalar_size, field_size) = (A::ScalarField::size_in_bits(), A::BaseField::size_in_bits());
+        if bits_be.len() != 2 * scalar_size + 2 * field_size {
+            A::halt(format!("Invalid signature size: found {} bits", bits_be.len()))
+        }
+
+        // Split the bits into the challenge, response, pk_sig, and pr_sig.
+        let (challenge, bits_be) = bits_be.split_at(scalar_size);
+        let (response, bits_be) = bits_be.split_at(scalar_size);
+        let (pk_sig, pr_sig) = bits_be.split_at(field_size);
+
+        // Recover the compute key from the x-coordinates of pk_sig and pr_sig.
+        let compute_key = ComputeKey::from((Group::from_bits_be(pk_sig), Group::from_bits_be(pr_sig)));
+
+        Self { challenge: Scalar::from_bits_be(challenge), response: Scalar::from_bits_be(response), compute_key }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::{helpers::generate_account, Circuit};
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    #[test]
+    fn test_from_bits() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Generate a signature.
+        let (private_key, _compute_key, _view_key, _address) = generate_account()?;
+        let message: Vec<_> = (0..2).map(|_| Uniform::rand(rng)).collect();
+        let expected = console::Signature::sign(&private_key, &message, rng)?;
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let signature = Signature::<Circuit>::new(mode, expected);
+
+            // Ensure the bits match the console signature.
+            let bits_le = signature.to_bits_le();
+            assert_eq!(console::ToBits::to_bits_le(&expected), bits_le.eject_value());
+            let bits_be = signature.to_bits_be();
+            assert_eq!(console::ToBits::to_bits_be(&expected), bits_be.eject_value());
+
+            // Ensure the signature is recovered from its bits.
+            assert_eq!(expected, Signature::<Circuit>::from_bits_le(&bits_le).eject_value());
+            assert_eq!(expected, Signature::<Circuit>::from_bits_be(&bits_be).eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,176 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const TYPE: u8, const VARIANT: usize> Keccak<E, TYPE, VARIANT> {
+    /// Returns the Keccak hash of the given input as little-endian bits.
+    /// The input bits are packed into bytes in little-endian order, and the last byte is padded with zeros.
+    pub fn hash(&self, input: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        // The bitrate `r`, in bits.
+        let bitrate = PERMUTATION_WIDTH - 2 * VARIANT;
+
+        // Pad the input to a multiple of 8 bits.
+        let mut padded = input.to_vec();
+        padded.resize((input.len() + 7) / 8 * 8, Boolean::constant(false));
+        // Append the domain separation bits.
+        match TYPE {
+            0 => padded.push(Boolean::constant(true)),
+            1 => padded.extend([false, true, true].map(Boolean::constant)),
+            _ => E::halt(format!("Invalid Keccak variant: ({TYPE}, {VARIANT})")),
+        }
+        // Apply the `pad10*1` rule, so that the length is a multiple of the bitrate.
+        while padded.len() % bitrate != bitrate - 1 {
+            padded.push(Boolean::constant(false));
+        }
+        padded.push(Boolean::constant(true));
+
+        // Initialize the state.
+        let mut state = vec![Boolean::constant(false); PERMUTATION_WIDTH];
+        // Absorb each block of the padded input into the state.
+        for block in padded.chunks(bitrate) {
+            for (bit, input_bit) in state.iter_mut().zip(block) {
+                *bit = &*bit ^ input_bit;
+            }
+            state = Self::permutation_f(state);
+        }
+
+        // Squeeze the output from the state.
+        state.truncate(VARIANT);
+        state
+    }
+}
+
+impl<E: Environment, const TYPE: u8, const VARIANT: usize> Keccak<E, TYPE, VARIANT> {
+    /// Applies the Keccak-f[1600] permutation to the given state.
+    /// The state is laid out as 25 lanes of 64 bits, where lane `x + 5y` holds bits `[64 * (x + 5y), 64 * (x + 5y + 1))`.
+    fn permutation_f(mut a: Vec<Boolean<E>>) -> Vec<Boolean<E>> {
+        debug_assert_eq!(a.len(), PERMUTATION_WIDTH);
+
+        for round_constant in ROUND_CONSTANTS {
+            // Theta: XOR each bit with the parities of two nearby columns.
+            let c = (0..5 * LANE_WIDTH)
+                .map(|i| {
+                    let (x, z) = (i / LANE_WIDTH, i % LANE_WIDTH);
+                    (1..5).fold(a[x * LANE_WIDTH + z].clone(), |parity, y| parity ^ &a[(x + 5 * y) * LANE_WIDTH + z])
+                })
+                .collect::<Vec<_>>();
+            for x in 0..5 {
+                for z in 0..LANE_WIDTH {
+                    let d = &c[((x + 4) % 5) * LANE_WIDTH + z]
+                        ^ &c[((x + 1) % 5) * LANE_WIDTH + (z + LANE_WIDTH - 1) % LANE_WIDTH];
+                    for y in 0..5 {
+                        let index = (x + 5 * y) * LANE_WIDTH + z;
+                        a[index] = &a[index] ^ &d;
+                    }
+                }
+            }
+
+            // Rho and Pi: rotate each lane, and move it to its new position.
+            let mut b = vec![Boolean::constant(false); PERMUTATION_WIDTH];
+            for x in 0..5 {
+                for y in 0..5 {
+                    let (lane, offset) = (x + 5 * y, ROTATION_OFFSETS[x + 5 * y]);
+                    let target = y + 5 * ((2 * x + 3 * y) % 5);
+                    for z in 0..LANE_WIDTH {
+                        b[target * LANE_WIDTH + z] =
+                            a[lane * LANE_WIDTH + (z + LANE_WIDTH - offset) % LANE_WIDTH].clone();
+                    }
+                }
+            }
+
+            // Chi: combine each bit with the next two bits in its row.
+            for x in 0..5 {
+                for y in 0..5 {
+                    for z in 0..LANE_WIDTH {
+                        let first = &b[((x + 1) % 5 + 5 * y) * LANE_WIDTH + z];
+                        let second = &b[((x + 2) % 5 + 5 * y) * LANE_WIDTH + z];
+                        a[(x + 5 * y) * LANE_WIDTH + z] = &b[(x + 5 * y) * LANE_WIDTH + z] ^ &(!first & second);
+                    }
+                }
+            }
+
+            // Iota: XOR the first lane with the round constant.
+            for (z, bit) in a.iter_mut().take(LANE_WIDTH).enumerate() {
+                if (round_constant >> z) & 1 == 1 {
+                    *bit = !&*bit;
+                }
+            }
+        }
+        a
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use console::Hash as H;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    const ITERATIONS: usize = 3;
+
+    fn check_hash<const TYPE: u8, const VARIANT: usize>(
+        mode: Mode,
+        num_bits: usize,
+        native: console::Keccak<TYPE, VARIANT>,
+        rng: &mut TestRng,
+    ) {
+        let circuit = Keccak::<Circuit, TYPE, VARIANT>::new();
+
+        for i in 0..ITERATIONS {
+            // Sample a random input.
+            let input = (0..num_bits).map(|_| bool::rand(rng)).collect::<Vec<bool>>();
+            // Compute the expected hash.
+            let expected = native.hash(&input).expect("Failed to hash native input");
+            // Prepare the circuit input.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+            Circuit::scope(format!("Keccak {mode} {i}"), || {
+                // Perform the hash operation.
+                let candidate = circuit.hash(&circuit_input);
+                assert_eq!(expected, candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+            });
+            Circuit::reset();
+        }
+    }
+
+    fn check_hashes(mode: Mode) {
+        let mut rng = TestRng::default();
+        // Check inputs that are byte-aligned, unaligned, and span multiple blocks.
+        for num_bits in [8, 253, 1088, 1600] {
+            check_hash(mode, num_bits, console::Keccak256::new(), &mut rng);
+            check_hash(mode, num_bits, console::Sha3_256::new(), &mut rng);
+            check_hash(mode, num_bits, console::Sha3_512::new(), &mut rng);
+        }
+    }
+
+    #[test]
+    fn test_hash_constant() {
+        check_hashes(Mode::Constant);
+    }
+
+    #[test]
+    fn test_hash_public() {
+        check_hashes(Mode::Public);
+    }
+
+    #[test]
+    fn test_hash_private() {
+        check_hashes(Mode::Private);
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod hash;
+
+use snarkvm_circuit_types::prelude::*;
+
+use core::marker::PhantomData;
+
+/// Keccak256 is the Keccak hash function with a 256-bit output, as used in Ethereum.
+pub type Keccak256<E> = Keccak<E, { KeccakType::Keccak as u8 }, 256>;
+/// Keccak384 is the Keccak hash function with a 384-bit output.
+pub type Keccak384<E> = Keccak<E, { KeccakType::Keccak as u8 }, 384>;
+/// Keccak512 is the Keccak hash function with a 512-bit output.
+pub type Keccak512<E> = Keccak<E, { KeccakType::Keccak as u8 }, 512>;
+
+/// Sha3_256 is the SHA-3 hash function (FIPS 202) with a 256-bit output.
+pub type Sha3_256<E> = Keccak<E, { KeccakType::Sha3 as u8 }, 256>;
+/// Sha3_384 is the SHA-3 hash function (FIPS 202) with a 384-bit output.
+pub type Sha3_384<E> = Keccak<E, { KeccakType::Sha3 as u8 }, 384>;
+/// Sha3_512 is the SHA-3 hash function (FIPS 202) with a 512-bit output.
+pub type Sha3_512<E> = Keccak<E, { KeccakType::Sha3 as u8 }, 512>;
+
+/// The padding rule applied by the hash function.
+enum KeccakType {
+    /// The original Keccak padding, as used in Ethereum.
+    Keccak,
+    /// The SHA-3 padding, as standardized in FIPS 202.
+    Sha3,
+}
+
+/// The number of lanes in the Keccak-f[1600] state.
+const NUM_LANES: usize = 25;
+/// The number of bits in a lane of the Keccak-f[1600] state.
+const LANE_WIDTH: usize = 64;
+/// The permutation width `b` of Keccak-f[1600], in bits.
+const PERMUTATION_WIDTH: usize = NUM_LANES * LANE_WIDTH;
+
+/// The round constants for the iota step of Keccak-f[1600].
+const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808A,
+    0x8000000080008000,
+    0x000000000000808B,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008A,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000A,
+    0x000000008000808B,
+    0x800000000000008B,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800A,
+    0x800000008000000A,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// The rotation offsets for the rho step of Keccak-f[1600], indexed by `x + 5y`.
+const ROTATION_OFFSETS: [usize; NUM_LANES] =
+    [0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61, 56, 14];
+
+/// Keccak is a cryptographic hash function built on the Keccak-f[1600] permutation.
+/// The `TYPE` selects between the Keccak and SHA-3 padding rules, and `VARIANT` is the output size in bits.
+#[derive(Clone, Default)]
+pub struct Keccak<E: Environment, const TYPE: u8, const VARIANT: usize> {
+    _phantom: PhantomData<E>,
+}
+
+impl<E: Environment, const TYPE: u8, const VARIANT: usize> Keccak<E, TYPE, VARIANT> {
+    /// Initializes a new instance of the Keccak hash function.
+    pub fn new() -> Self {
+        Self { _phantom: PhantomData }
+    }
+}
@@ -22,11 +22,14 @@ use snarkvm_circuit_algorithms::{
     HashMany,
     HashToGroup,
     HashToScalar,
+    Keccak256,
     Pedersen128,
     Pedersen64,
     Poseidon2,
     Poseidon4,
     Poseidon8,
+    Sha3_256,
+    Sha3_512,
     BHP1024,
     BHP256,
     BHP512,
@@ -189,6 +192,21 @@ impl Aleo for AleoV0 {
         PEDERSEN_128.with(|pedersen| pedersen.hash(input))
     }
 
+    /// Returns the Keccak hash with a 256-bit output.
+    fn hash_keccak256(input: &[Boolean<Self>]) -> Vec<Boolean<Self>> {
+        Keccak256::<Self>::new().hash(input)
+    }
+
+    /// Returns the SHA-3 hash with a 256-bit output.
+    fn hash_sha3_256(input: &[Boolean<Self>]) -> Vec<Boolean<Self>> {
+        Sha3_256::<Self>::new().hash(input)
+    }
+
+    /// Returns the SHA-3 hash with a 512-bit output.
+    fn hash_sha3_512(input: &[Boolean<Self>]) -> Vec<Boolean<Self>> {
+        Sha3_512::<Self>::new().hash(input)
+    }
+
     /// Returns the Poseidon hash with an input rate of 2.
     fn hash_psd2(input: &[Field<Self>]) -> Field<Self> {
         POSEIDON_2.with(|poseidon| poseidon.hash(input))
@@ -0,0 +1,87 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Identifier;
+use snarkvm_circuit_network::Aleo;
+use snarkvm_circuit_types::{environment::prelude::*, U32};
+
+/// A single step in a register access path, i.e. `.owner` or `[3]`.
+#[derive(Clone)]
+pub enum Access<A: Aleo> {
+    /// A member access contains the identifier of the struct or record member.
+    Member(Identifier<A>),
+    /// An index access contains the (static) index of the array element.
+    Index(U32<A>),
+}
+
+#[cfg(console)]
+impl<A: Aleo> Inject for Access<A> {
+    type Primitive = console::Access<A::Network>;
+
+    /// Initializes a new access circuit from a primitive.
+    fn new(mode: Mode, access: Self::Primitive) -> Self {
+        match access {
+            Self::Primitive::Member(identifier) => Self::Member(Identifier::new(mode, identifier)),
+            Self::Primitive::Index(index) => Self::Index(U32::new(mode, console::U32::new(index))),
+        }
+    }
+}
+
+#[cfg(console)]
+impl<A: Aleo> Eject for Access<A> {
+    type Primitive = console::Access<A::Network>;
+
+    /// Ejects the mode of the access.
+    fn eject_mode(&self) -> Mode {
+        match self {
+            Self::Member(identifier) => identifier.eject_mode(),
+            Self::Index(index) => index.eject_mode(),
+        }
+    }
+
+    /// Ejects the access.
+    fn eject_value(&self) -> Self::Primitive {
+        match self {
+            Self::Member(identifier) => console::Access::Member(identifier.eject_value()),
+            Self::Index(index) => console::Access::Index(*index.eject_value()),
+        }
+    }
+}
+
+impl<A: Aleo> From<Identifier<A>> for Access<A> {
+    /// Initializes a member access from an identifier.
+    fn from(identifier: Identifier<A>) -> Self {
+        Self::Member(identifier)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    use anyhow::Result;
+
+    #[test]
+    fn test_inject_and_eject() -> Result<()> {
+        for expected in [console::Access::from_str(".owner")?, console::Access::from_str("[7]")?] {
+            let candidate = Access::<Circuit>::constant(expected);
+            assert_eq!(Mode::Constant, candidate.eject_mode());
+            assert_eq!(expected, candidate.eject_value());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,286 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_circuit_types::integers::Integer;
+
+#[cfg(console)]
+impl<A: Aleo> Literal<A> {
+    /// Casts the literal into the given literal type, enforcing that its value is preserved.
+    /// This method matches the semantics of `console::Literal::cast`.
+    pub fn cast(&self, to_type: console::LiteralType) -> Result<Self> {
+        match (self, to_type) {
+            // Casting into the same type is the identity.
+            _ if self.to_type() == to_type => Ok(self.clone()),
+            // Ensure neither type is a signature or a string.
+            (Self::Signature(..) | Self::String(..), _)
+            | (_, console::LiteralType::Signature | console::LiteralType::String) => {
+                bail!("Cannot cast a '{}' literal into a '{to_type}'", self.to_type())
+            }
+            // Addresses and groups share the same representation.
+            (Self::Address(address), console::LiteralType::Group) => Ok(Self::Group(address.to_group())),
+            (Self::Group(group), console::LiteralType::Address) => {
+                Ok(Self::Address(Address::from_group(group.clone())))
+            }
+            // Otherwise, cast through the base field representation.
+            _ => Self::from_field_checked(self.to_field_value()?, to_type),
+        }
+    }
+
+    /// Casts the literal into the given literal type, truncating its value if necessary.
+    /// This method matches the semantics of `console::Literal::cast_lossy`.
+    pub fn cast_lossy(&self, to_type: console::LiteralType) -> Result<Self> {
+        match (self, to_type) {
+            // Ensure neither type is a signature or a string.
+            (Self::Signature(..) | Self::String(..), _)
+            | (_, console::LiteralType::Signature | console::LiteralType::String) => {
+                bail!("Cannot cast a '{}' literal into a '{to_type}'", self.to_type())
+            }
+            (_, console::LiteralType::Address | console::LiteralType::Field | console::LiteralType::Group) => {
+                self.cast(to_type)
+            }
+            _ => Self::from_bits_lossy(&self.to_bits_lossy()?, to_type),
+        }
+    }
+}
+
+#[cfg(console)]
+impl<A: Aleo> Literal<A> {
+    /// Returns the base field representation of the literal.
+    fn to_field_value(&self) -> Result<Field<A>> {
+        match self {
+            Self::Address(address) => Ok(address.to_field()),
+            Self::Boolean(boolean) => Ok(Field::from_boolean(boolean)),
+            Self::Field(field) => Ok(field.clone()),
+            Self::Group(group) => Ok(group.to_x_coordinate()),
+            Self::I8(integer) => Ok(integer_to_field(integer)),
+            Self::I16(integer) => Ok(integer_to_field(integer)),
+            Self::I32(integer) => Ok(integer_to_field(integer)),
+            Self::I64(integer) => Ok(integer_to_field(integer)),
+            Self::I128(integer) => Ok(integer_to_field(integer)),
+            Self::U8(integer) => Ok(integer_to_field(integer)),
+            Self::U16(integer) => Ok(integer_to_field(integer)),
+            Self::U32(integer) => Ok(integer_to_field(integer)),
+            Self::U64(integer) => Ok(integer_to_field(integer)),
+            Self::U128(integer) => Ok(integer_to_field(integer)),
+            Self::Scalar(scalar) => Ok(scalar.to_field()),
+            Self::Signature(..) => bail!("Cannot cast a signature literal into a field element"),
+            Self::String(..) => bail!("Cannot cast a string literal into a field element"),
+        }
+    }
+
+    /// Returns the literal of the given type with the value of the given base field element.
+    fn from_field_checked(field: Field<A>, to_type: console::LiteralType) -> Result<Self> {
+        match to_type {
+            console::LiteralType::Address => Ok(Self::Address(Address::from_group(group_from_x_coordinate(field)))),
+            // Enforce the field element is `0` or `1`.
+            console::LiteralType::Boolean => Ok(Self::Boolean(field.to_lower_bits_le(1)[0].clone())),
+            console::LiteralType::Field => Ok(Self::Field(field)),
+            console::LiteralType::Group => Ok(Self::Group(group_from_x_coordinate(field))),
+            console::LiteralType::I8 => Ok(Self::I8(integer_from_field(field))),
+            console::LiteralType::I16 => Ok(Self::I16(integer_from_field(field))),
+            console::LiteralType::I32 => Ok(Self::I32(integer_from_field(field))),
+            console::LiteralType::I64 => Ok(Self::I64(integer_from_field(field))),
+            console::LiteralType::I128 => Ok(Self::I128(integer_from_field(field))),
+            console::LiteralType::U8 => Ok(Self::U8(integer_from_field(field))),
+            console::LiteralType::U16 => Ok(Self::U16(integer_from_field(field))),
+            console::LiteralType::U32 => Ok(Self::U32(integer_from_field(field))),
+            console::LiteralType::U64 => Ok(Self::U64(integer_from_field(field))),
+            console::LiteralType::U128 => Ok(Self::U128(integer_from_field(field))),
+            console::LiteralType::Scalar => Ok(Self::Scalar(Scalar::from_bits_le(&field.to_bits_le()))),
+            console::LiteralType::Signature => bail!("Cannot cast a field element into a signature"),
+            console::LiteralType::String => bail!("Cannot cast a field element into a string"),
+        }
+    }
+
+    /// Returns the little-endian bits used by `cast_lossy`, which are
+    /// the sign-extended bits for integers, and the base field bits otherwise.
+    fn to_bits_lossy(&self) -> Result<Vec<Boolean<A>>> {
+        // Sign-extends the given integer bits to the size of a base field element.
+        let sign_extend = |mut bits_le: Vec<Boolean<A>>, is_signed: bool| {
+            let extension = match is_signed {
+                true => bits_le[bits_le.len() - 1].clone(),
+                false => Boolean::constant(false),
+            };
+            bits_le.resize(console::Field::<A::Network>::size_in_bits(), extension);
+            bits_le
+        };
+
+        match self {
+            Self::I8(integer) => Ok(sign_extend(integer.to_bits_le(), true)),
+            Self::I16(integer) => Ok(sign_extend(integer.to_bits_le(), true)),
+            Self::I32(integer) => Ok(sign_extend(integer.to_bits_le(), true)),
+            Self::I64(integer) => Ok(sign_extend(integer.to_bits_le(), true)),
+            Self::I128(integer) => Ok(sign_extend(integer.to_bits_le(), true)),
+            Self::U8(integer) => Ok(sign_extend(integer.to_bits_le(), false)),
+            Self::U16(integer) => Ok(sign_extend(integer.to_bits_le(), false)),
+            Self::U32(integer) => Ok(sign_extend(integer.to_bits_le(), false)),
+            Self::U64(integer) => Ok(sign_extend(integer.to_bits_le(), false)),
+            Self::U128(integer) => Ok(sign_extend(integer.to_bits_le(), false)),
+            _ => Ok(self.to_field_value()?.to_bits_le()),
+        }
+    }
+
+    /// Returns the literal of the given type from the lower bits of the given little-endian bits.
+    fn from_bits_lossy(bits_le: &[Boolean<A>], to_type: console::LiteralType) -> Result<Self> {
+        match to_type {
+            console::LiteralType::Boolean => Ok(Self::Boolean(bits_le[0].clone())),
+            console::LiteralType::I8 => Ok(Self::I8(Integer::from_bits_le(&bits_le[..8]))),
+            console::LiteralType::I16 => Ok(Self::I16(Integer::from_bits_le(&bits_le[..16]))),
+            console::LiteralType::I32 => Ok(Self::I32(Integer::from_bits_le(&bits_le[..32]))),
+            console::LiteralType::I64 => Ok(Self::I64(Integer::from_bits_le(&bits_le[..64]))),
+            console::LiteralType::I128 => Ok(Self::I128(Integer::from_bits_le(&bits_le[..128]))),
+            console::LiteralType::U8 => Ok(Self::U8(Integer::from_bits_le(&bits_le[..8]))),
+            console::LiteralType::U16 => Ok(Self::U16(Integer::from_bits_le(&bits_le[..16]))),
+            console::LiteralType::U32 => Ok(Self::U32(Integer::from_bits_le(&bits_le[..32]))),
+            console::LiteralType::U64 => Ok(Self::U64(Integer::from_bits_le(&bits_le[..64]))),
+            console::LiteralType::U128 => Ok(Self::U128(Integer::from_bits_le(&bits_le[..128]))),
+            console::LiteralType::Scalar => {
+                let size_in_data_bits = console::Scalar::<A::Network>::size_in_data_bits();
+                Ok(Self::Scalar(Scalar::from_bits_le(&bits_le[..size_in_data_bits])))
+            }
+            _ => bail!("Cannot truncate a literal into a '{to_type}'"),
+        }
+    }
+}
+
+/// Returns `2^exponent` as a constant base field element.
+fn two_to_the<A: Aleo>(exponent: usize) -> Field<A> {
+    (0..exponent).fold(Field::one(), |power, _| power.double())
+}
+
+/// Returns the group element with the given x-coordinate, matching `console::Group::from_x_coordinate`.
+/// Enforces that the x-coordinate of the recovered point is the given base field element.
+/// The point is enforced to be in the prime-order subgroup, and of the two points `(x, y)` and `(x, -y)`,
+/// at most one is in the subgroup, as they differ by the point of order two. This fixes the y-coordinate.
+fn group_from_x_coordinate<A: Aleo>(x: Field<A>) -> Group<A> {
+    // Recover the point, which is enforced to be on the curve and in the subgroup.
+    let group = Group::from_x_coordinate(x.clone());
+    // Enforce the x-coordinate of the point matches.
+    A::assert_eq(group.to_x_coordinate(), x);
+    group
+}
+
+/// Returns the base field element with the same value as the given integer.
+fn integer_to_field<A: Aleo, I: IntegerType>(integer: &Integer<A, I>) -> Field<A> {
+    match I::is_signed() {
+        // The integer is non-negative, so its bits are its value.
+        false => integer.to_field(),
+        // Compute the two's complement value, as `sum(2^i * b_i) - 2^(n - 1) * b_(n - 1)`.
+        true => {
+            let bits_le = integer.to_bits_le();
+            let (magnitude_bits_le, sign_bit) = bits_le.split_at(bits_le.len() - 1);
+            Field::from_bits_le(magnitude_bits_le)
+                - Field::from_boolean(&sign_bit[0]) * two_to_the::<A>(magnitude_bits_le.len())
+        }
+    }
+}
+
+/// Returns the integer with the same value as the given base field element.
+/// Enforces that the value is in range for the integer type.
+fn integer_from_field<A: Aleo, I: IntegerType>(field: Field<A>) -> Integer<A, I> {
+    match I::is_signed() {
+        // Enforce the value is less than `2^n`.
+        false => Integer::from_field(field),
+        // Enforce the value is in `[-2^(n - 1), 2^(n - 1))`, by checking `value + 2^(n - 1)` is less than `2^n`.
+        // The two's complement bits of the value are then the bits of the shifted value, with the top bit flipped.
+        true => {
+            let num_bits = I::BITS as usize;
+            let shifted = field + two_to_the::<A>(num_bits - 1);
+            let mut bits_le = shifted.to_lower_bits_le(num_bits);
+            bits_le[num_bits - 1] = !&bits_le[num_bits - 1];
+            Integer::from_bits_le(&bits_le)
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    fn check_cast(input: &str, to_type: &str, mode: Mode) {
+        let expected = console::Literal::<<Circuit as Environment>::Network>::from_str(input).unwrap();
+        let to_type = console::LiteralType::from_str(to_type).unwrap();
+
+        // Ensure the checked cast matches the console cast.
+        let candidate = Literal::<Circuit>::new(mode, expected.clone()).cast(to_type).unwrap();
+        assert_eq!(expected.cast(to_type).unwrap(), candidate.eject_value());
+        assert!(Circuit::is_satisfied(), "Casting '{input}' into '{to_type}' should be satisfied");
+        Circuit::reset();
+
+        // Ensure the lossy cast matches the console cast.
+        let candidate = Literal::<Circuit>::new(mode, expected.clone()).cast_lossy(to_type).unwrap();
+        assert_eq!(expected.cast_lossy(to_type).unwrap(), candidate.eject_value());
+        assert!(Circuit::is_satisfied(), "Casting '{input}' into '{to_type}' (lossy) should be satisfied");
+        Circuit::reset();
+    }
+
+    fn check_cast_fails(input: &str, to_type: &str) {
+        let literal = console::Literal::<<Circuit as Environment>::Network>::from_str(input).unwrap();
+        let to_type = console::LiteralType::from_str(to_type).unwrap();
+
+        // Ensure the checked cast is unsatisfied.
+        let _candidate = Literal::<Circuit>::new(Mode::Private, literal).cast(to_type).unwrap();
+        assert!(!Circuit::is_satisfied(), "Casting '{input}' into '{to_type}' should not be satisfied");
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_cast() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_cast("5u8", "u128", mode);
+            check_cast("-128i16", "i8", mode);
+            check_cast("-1i64", "field", mode);
+            check_cast("-1field", "i8", mode);
+            check_cast("255field", "u8", mode);
+            check_cast("true", "field", mode);
+            check_cast("1field", "boolean", mode);
+            check_cast("7field", "scalar", mode);
+            check_cast("7scalar", "u16", mode);
+        }
+    }
+
+    #[test]
+    fn test_cast_field_to_group() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Sample a group element, and cast its x-coordinate.
+            let group: console::Group<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            let x_coordinate = format!("{}", group.to_x_coordinate());
+            check_cast(&x_coordinate, "group", mode);
+            check_cast(&x_coordinate, "address", mode);
+
+            // Ensure the recovered point is the sampled point.
+            let field = Field::<Circuit>::new(mode, group.to_x_coordinate());
+            let candidate = group_from_x_coordinate(field);
+            assert_eq!(group, candidate.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_cast_fails() {
+        check_cast_fails("255u8", "i8");
+        check_cast_fails("-1i8", "u8");
+        check_cast_fails("256field", "u8");
+        check_cast_fails("2field", "boolean");
+        check_cast_fails("-1field", "scalar");
+    }
+}
@@ -36,8 +36,9 @@ impl<A: Aleo> Literal<A> {
             12 => Literal::U64(U64::from_bits_le(literal)),
             13 => Literal::U128(U128::from_bits_le(literal)),
             14 => Literal::Scalar(Scalar::from_bits_le(literal)),
-            15 => Literal::String(StringType::from_bits_le(literal)),
-            16.. => A::halt(format!("Failed to initialize literal variant {} from bits (LE)", variant.eject_value())),
+            15 => Literal::Signature(Box::new(Signature::from_bits_le(literal))),
+            16 => Literal::String(StringType::from_bits_le(literal)),
+            17.. => A::halt(format!("Failed to initialize literal variant {} from bits (LE)", variant.eject_value())),
         }
     }
 
@@ -60,8 +61,9 @@ impl<A: Aleo> Literal<A> {
             12 => Literal::U64(U64::from_bits_be(literal)),
             13 => Literal::U128(U128::from_bits_be(literal)),
             14 => Literal::Scalar(Scalar::from_bits_be(literal)),
-            15 => Literal::String(StringType::from_bits_be(literal)),
-            16.. => A::halt(format!("Failed to initialize literal variant {} from bits (BE))", variant.eject_value())),
+            15 => Literal::Signature(Box::new(Signature::from_bits_be(literal))),
+            16 => Literal::String(StringType::from_bits_be(literal)),
+            17.. => A::halt(format!("Failed to initialize literal variant {} from bits (BE))", variant.eject_value())),
         }
     }
 }
@@ -129,6 +131,9 @@ mod tests {
             check_serialization(Literal::<Circuit>::U128(U128::new(mode, Uniform::rand(rng))));
             // Scalar
             check_serialization(Literal::<Circuit>::Scalar(Scalar::new(mode, Uniform::rand(rng))));
+            // Signature
+            let signature = console::Literal::sample(console::LiteralType::Signature, rng);
+            check_serialization(Literal::<Circuit>::new(mode, signature));
             // String
             // Sample a random string. Take 1/4th to ensure we fit for all code points.
             let string = rng.next_string(Circuit::MAX_STRING_BYTES / 4, false);
@@ -46,6 +46,9 @@ impl<A: Aleo> ToFields for &Literal<A> {
             Literal::U64(literal) => vec![literal.to_field()],
             Literal::U128(literal) => vec![literal.to_field()],
             Literal::Scalar(literal) => vec![literal.to_field()],
+            Literal::Signature(literal) => {
+                literal.to_bits_le().chunks(A::BaseField::size_in_data_bits()).map(Field::from_bits_le).collect()
+            }
             Literal::String(literal) => literal.to_fields(),
         }
     }
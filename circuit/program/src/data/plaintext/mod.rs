@@ -27,9 +27,9 @@ mod size_in_fields;
 mod to_bits;
 mod to_fields;
 
-use crate::{Ciphertext, Identifier, Literal, Visibility};
+use crate::{Access, Ciphertext, Identifier, Literal, Visibility};
 use snarkvm_circuit_network::Aleo;
-use snarkvm_circuit_types::{environment::prelude::*, Address, Boolean, Field, Scalar, U16, U8};
+use snarkvm_circuit_types::{environment::prelude::*, Address, Boolean, Field, Scalar, U16, U32, U8};
 
 #[derive(Clone)]
 pub enum Plaintext<A: Aleo> {
@@ -37,6 +37,8 @@ pub enum Plaintext<A: Aleo> {
     Literal(Literal<A>, OnceCell<Vec<Boolean<A>>>),
     /// A plaintext struct.
     Struct(IndexMap<Identifier<A>, Plaintext<A>>, OnceCell<Vec<Boolean<A>>>),
+    /// A plaintext array.
+    Array(Vec<Plaintext<A>>, OnceCell<Vec<Boolean<A>>>),
 }
 
 #[cfg(console)]
@@ -48,6 +50,7 @@ impl<A: Aleo> Inject for Plaintext<A> {
         match plaintext {
             Self::Primitive::Literal(literal, _) => Self::Literal(Literal::new(mode, literal), Default::default()),
             Self::Primitive::Struct(struct_, _) => Self::Struct(Inject::new(mode, struct_), Default::default()),
+            Self::Primitive::Array(elements, _) => Self::Array(Inject::new(mode, elements), Default::default()),
         }
     }
 }
@@ -65,6 +68,7 @@ impl<A: Aleo> Eject for Plaintext<A> {
                 .map(|(identifier, value)| (identifier, value).eject_mode())
                 .collect::<Vec<_>>()
                 .eject_mode(),
+            Self::Array(elements, _) => elements.eject_mode(),
         }
     }
 
@@ -75,6 +79,7 @@ impl<A: Aleo> Eject for Plaintext<A> {
             Self::Struct(struct_, _) => {
                 console::Plaintext::Struct(struct_.iter().map(|pair| pair.eject_value()).collect(), Default::default())
             }
+            Self::Array(elements, _) => console::Plaintext::Array(elements.eject_value(), Default::default()),
         }
     }
 }
@@ -226,6 +231,20 @@ mod tests {
             value.to_bits_le().eject(),
             Plaintext::<Circuit>::from_bits_le(&value.to_bits_le()).to_bits_le().eject()
         );
+
+        let value = Plaintext::<Circuit>::new(
+            Mode::Private,
+            console::Plaintext::from_str("{ a: [{ b: true }, { b: false }], c: [1u64, 2u64, 3u64] }")?,
+        );
+        assert_eq!(
+            value.to_bits_le().eject(),
+            Plaintext::<Circuit>::from_bits_le(&value.to_bits_le()).to_bits_le().eject()
+        );
+        assert_eq!(
+            value.to_bits_be().eject(),
+            Plaintext::<Circuit>::from_bits_be(&value.to_bits_be()).to_bits_be().eject()
+        );
+        assert_eq!(value.eject_value(), Plaintext::<Circuit>::from_fields(&value.to_fields()).eject_value());
         Ok(())
     }
 }
@@ -18,18 +18,23 @@ use super::*;
 
 impl<A: Aleo> Record<A, Plaintext<A>> {
     /// Returns the entry from the given path.
-    pub fn find(&self, path: &[Identifier<A>]) -> Result<Entry<A, Plaintext<A>>> {
+    pub fn find(&self, path: &[Access<A>]) -> Result<Entry<A, Plaintext<A>>> {
         // If the path is of length one, check if the path is requesting the `owner` or `gates`.
-        if path.len() == 1 {
-            if path[0] == Identifier::from_str("owner")? {
+        if let [Access::Member(member)] = path {
+            if member == &Identifier::from_str("owner")? {
                 return Ok(self.owner.to_entry());
-            } else if path[0] == Identifier::from_str("gates")? {
+            } else if member == &Identifier::from_str("gates")? {
                 return Ok(self.gates.to_entry());
             }
         }
 
         // Ensure the path is not empty.
         if let Some((first, rest)) = path.split_first() {
+            // Ensure the first access is a member.
+            let first = match first {
+                Access::Member(first) => first,
+                Access::Index(..) => bail!("Cannot index into a record."),
+            };
             // Retrieve the top-level entry.
             match self.data.get(first) {
                 Some(entry) => match rest.is_empty() {
@@ -15,8 +15,11 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod bytes;
+mod from_bits;
 mod parse;
 mod serialize;
+mod size_in_bits;
+mod to_bits;
 mod verify;
 
 #[cfg(feature = "private_key")]
@@ -29,7 +32,7 @@ use crate::PrivateKey;
 
 use crate::address::Address;
 use snarkvm_console_network::prelude::*;
-use snarkvm_console_types::{Field, Scalar};
+use snarkvm_console_types::{Field, Group, Scalar};
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Signature<N: Network> {
@@ -0,0 +1,38 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+use super::*;
+
+impl<N: Network> ToBits for Signature<N> {
+    /// Outputs the little-endian bit representation of `(challenge, response, pk_sig, pr_sig)`.
+    fn to_bits_le(&self) -> Vec<bool> {
+        let mut bits_le = Vec::with_capacity(Self::size_in_bits());
+        bits_le.extend(self.challenge.to_bits_le());
+        bits_le.extend(self.response.to_bits_le());
+        bits_le.extend(self.compute_key.pk_sig().to_x_coordinate().to_bits_le());
+        bits_le.extend(self.compute_key.pr_sig().to_x_coordinate().to_bits_le());
+        bits_le
+    }
+
+    /// Outputs the big-endian bit representation of `(challenge, response, pk_sig, pr_sig)`.
+    fn to_bits_be(&self) -> Vec<bool> {
+        let mut bits_be = Vec::with_capacity(Self::size_in_bits());
+        bits_be.extend(self.challenge.to_bits_be());
+        bits_be.extend(self.response.to_bits_be());
+        bits_be.extend(self.compute_key.pk_sig().to_x_coordinate().to_bits_be());
+        bits_be.extend(self.compute_key.pr_sig().to_x_coordinate().to_bits_be());
+        bits_be
+    }
+}
@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<const TYPE: u8, const VARIANT: usize> Hash for Keccak<TYPE, VARIANT> {
+    type Input = bool;
+    type Output = Vec<bool>;
+
+    /// Returns the Keccak hash of the given input as little-endian bits.
+    /// The input bits are packed into bytes in little-endian order, and the last byte is padded with zeros.
+    fn hash(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        // Pack the input bits into bytes.
+        let input = Vec::<u8>::from_bits_le(input)?;
+        // Initialize the output buffer.
+        let mut output = vec![0u8; VARIANT / 8];
+        // Compute the hash.
+        match (TYPE, VARIANT) {
+            (0, 256) => digest(TinyKeccak::v256(), &input, &mut output),
+            (0, 384) => digest(TinyKeccak::v384(), &input, &mut output),
+            (0, 512) => digest(TinyKeccak::v512(), &input, &mut output),
+            (1, 256) => digest(TinySha3::v256(), &input, &mut output),
+            (1, 384) => digest(TinySha3::v384(), &input, &mut output),
+            (1, 512) => digest(TinySha3::v512(), &input, &mut output),
+            _ => bail!("Invalid Keccak variant: ({TYPE}, {VARIANT})"),
+        }
+        // Return the output as bits.
+        Ok(output.to_bits_le())
+    }
+}
+
+/// Absorbs the given input into the hasher, and writes the digest into the output buffer.
+fn digest(mut hasher: impl Hasher, input: &[u8], output: &mut [u8]) {
+    hasher.update(input);
+    hasher.finalize(output);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_hash(hasher: &impl Hash<Input = bool, Output = Vec<bool>>, input: &str, expected: &str) {
+        // Compute the hash.
+        let candidate = hasher.hash(&input.as_bytes().to_bits_le()).unwrap();
+        // Check the hash against the expected digest.
+        assert_eq!(hex::decode(expected).unwrap().to_bits_le(), candidate);
+    }
+
+    #[test]
+    fn test_keccak256() {
+        check_hash(&Keccak256::new(), "", "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470");
+        check_hash(&Keccak256::new(), "abc", "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45");
+    }
+
+    #[test]
+    fn test_sha3_256() {
+        check_hash(&Sha3_256::new(), "", "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a");
+        check_hash(&Sha3_256::new(), "abc", "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532");
+    }
+
+    #[test]
+    fn test_sha3_512() {
+        check_hash(
+            &Sha3_512::new(),
+            "abc",
+            "b751850b1a57168a5693cd924b6b096e08f621827444f70d884f5d0240d2712e10e116e9192af3c91a7ec57647e3934057340b4cf408d5a56592f8274eec53f0",
+        );
+    }
+
+    #[test]
+    fn test_unaligned_input() {
+        // Ensure a non byte-aligned input is padded with zeros.
+        let input = vec![true, false, true];
+        let expected = Keccak256::new().hash(&[input.clone(), vec![false; 5]].concat()).unwrap();
+        assert_eq!(expected, Keccak256::new().hash(&input).unwrap());
+    }
+}
@@ -0,0 +1,54 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod hash;
+
+use snarkvm_console_types::prelude::*;
+use tiny_keccak::{Hasher, Keccak as TinyKeccak, Sha3 as TinySha3};
+
+/// Keccak256 is the Keccak hash function with a 256-bit output, as used in Ethereum.
+pub type Keccak256 = Keccak<{ KeccakType::Keccak as u8 }, 256>;
+/// Keccak384 is the Keccak hash function with a 384-bit output.
+pub type Keccak384 = Keccak<{ KeccakType::Keccak as u8 }, 384>;
+/// Keccak512 is the Keccak hash function with a 512-bit output.
+pub type Keccak512 = Keccak<{ KeccakType::Keccak as u8 }, 512>;
+
+/// Sha3_256 is the SHA-3 hash function (FIPS 202) with a 256-bit output.
+pub type Sha3_256 = Keccak<{ KeccakType::Sha3 as u8 }, 256>;
+/// Sha3_384 is the SHA-3 hash function (FIPS 202) with a 384-bit output.
+pub type Sha3_384 = Keccak<{ KeccakType::Sha3 as u8 }, 384>;
+/// Sha3_512 is the SHA-3 hash function (FIPS 202) with a 512-bit output.
+pub type Sha3_512 = Keccak<{ KeccakType::Sha3 as u8 }, 512>;
+
+/// The padding rule applied by the hash function.
+enum KeccakType {
+    /// The original Keccak padding, as used in Ethereum.
+    Keccak,
+    /// The SHA-3 padding, as standardized in FIPS 202.
+    Sha3,
+}
+
+/// Keccak is a cryptographic hash function built on the Keccak-f[1600] permutation.
+/// The `TYPE` selects between the Keccak and SHA-3 padding rules, and `VARIANT` is the output size in bits.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Keccak<const TYPE: u8, const VARIANT: usize>;
+
+impl<const TYPE: u8, const VARIANT: usize> Keccak<TYPE, VARIANT> {
+    /// Initializes a new instance of the Keccak hash function.
+    pub const fn new() -> Self {
+        Self
+    }
+}
@@ -30,6 +30,9 @@ pub use blake2xs::Blake2Xs;
 mod elligator2;
 pub use elligator2::Elligator2;
 
+mod keccak;
+pub use keccak::{Keccak, Keccak256, Keccak384, Keccak512, Sha3_256, Sha3_384, Sha3_512};
+
 mod pedersen;
 pub use pedersen::{Pedersen, Pedersen128, Pedersen64};
 
@@ -111,6 +111,33 @@ pub trait Network:
     /// The number of blocks per epoch (1 hour).
     const NUM_BLOCKS_PER_EPOCH: u32 = 1 << 8; // 256 blocks == ~1 hour
 
+    /// The cost in gates per byte of a transaction.
+    const TRANSACTION_BYTE_COST: u64 = 1;
+    /// The cost in gates per byte of a key and value written to a mapping in finalize.
+    const MAPPING_BYTE_COST: u64 = 10;
+
+    /// The cost in gates of an instruction, such as an arithmetic, logical, or comparison instruction on literals.
+    /// Note: The costs of instructions and commands are fixed weights, so changing a cost requires a network upgrade.
+    const INSTRUCTION_COST: u64 = 1;
+    /// The cost in gates of a cast, which checks the type of each member of its destination.
+    const CAST_COST: u64 = 2;
+    /// The cost in gates of a division, exponentiation, inversion, modulo, remainder, or square root.
+    const COMPLEX_ARITHMETIC_COST: u64 = 10;
+    /// The cost in gates of a Pedersen hash or commitment.
+    const PEDERSEN_COST: u64 = 20;
+    /// The cost in gates of a Poseidon hash.
+    const POSEIDON_COST: u64 = 30;
+    /// The cost in gates of a BHP hash or commitment.
+    const BHP_COST: u64 = 50;
+    /// The cost in gates of a Keccak or SHA-3 hash.
+    const KECCAK_COST: u64 = 100;
+    /// The cost in gates of a signature verification.
+    const SIGNATURE_VERIFICATION_COST: u64 = 100;
+    /// The cost in gates of a read from a mapping in finalize.
+    const MAPPING_READ_COST: u64 = 10;
+    /// The cost in gates of a write to a mapping in finalize, which may also read the current value.
+    const MAPPING_WRITE_COST: u64 = 20;
+
     /// The maximum recursive depth of a value and/or entry.
     /// Note: This value must be strictly less than u8::MAX.
     const MAX_DATA_DEPTH: usize = 32;
@@ -221,6 +248,15 @@ pub trait Network:
     /// Returns the Pedersen hash for a given (up to) 128-bit input.
     fn hash_ped128(input: &[bool]) -> Result<Field<Self>>;
 
+    /// Returns the Keccak hash with a 256-bit output.
+    fn hash_keccak256(input: &[bool]) -> Result<Vec<bool>>;
+
+    /// Returns the SHA-3 hash with a 256-bit output.
+    fn hash_sha3_256(input: &[bool]) -> Result<Vec<bool>>;
+
+    /// Returns the SHA-3 hash with a 512-bit output.
+    fn hash_sha3_512(input: &[bool]) -> Result<Vec<bool>>;
+
     /// Returns the Poseidon hash with an input rate of 2.
     fn hash_psd2(input: &[Field<Self>]) -> Result<Field<Self>>;
 
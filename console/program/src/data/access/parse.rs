@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Parser for Access<N> {
+    /// Parses a string into an access.
+    /// The access is of the form `.{identifier}` or `[{index}]`.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses a member access, i.e. `.owner`.
+        fn parse_member<N: Network>(string: &str) -> ParserResult<Access<N>> {
+            // Parse the "." from the string.
+            let (string, _) = tag(".")(string)?;
+            // Parse the identifier from the string.
+            map(Identifier::parse, Access::Member)(string)
+        }
+
+        /// Parses an index access, i.e. `[3]`.
+        fn parse_index<N: Network>(string: &str) -> ParserResult<Access<N>> {
+            // Parse the "[" from the string.
+            let (string, _) = tag("[")(string)?;
+            // Parse the index from the string.
+            let (string, index) =
+                map_res(recognize(many1(one_of("0123456789"))), |index: &str| index.parse::<u32>())(string)?;
+            // Parse the "]" from the string.
+            let (string, _) = tag("]")(string)?;
+            // Return the access.
+            Ok((string, Access::Index(index)))
+        }
+
+        alt((parse_member, parse_index))(string)
+    }
+}
+
+impl<N: Network> FromStr for Access<N> {
+    type Err = Error;
+
+    /// Parses a string into an access.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Access<N> {
+    /// Prints the access as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Access<N> {
+    /// Prints the access as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            // Prints the member access, i.e. .owner
+            Self::Member(identifier) => write!(f, ".{identifier}"),
+            // Prints the index access, i.e. [3]
+            Self::Index(index) => write!(f, "[{index}]"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        assert_eq!(("", Access::<CurrentNetwork>::Member(Identifier::from_str("owner")?)), Access::parse(".owner")?);
+        assert_eq!(("", Access::<CurrentNetwork>::Index(0)), Access::parse("[0]")?);
+        assert_eq!((".owner", Access::<CurrentNetwork>::Index(31)), Access::parse("[31].owner")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_fails() {
+        assert!(Access::<CurrentNetwork>::parse("").is_err());
+        assert!(Access::<CurrentNetwork>::parse("owner").is_err());
+        assert!(Access::<CurrentNetwork>::parse(".1").is_err());
+        assert!(Access::<CurrentNetwork>::parse("[]").is_err());
+        assert!(Access::<CurrentNetwork>::parse("[-1]").is_err());
+        assert!(Access::<CurrentNetwork>::parse("[r0]").is_err());
+        assert!(Access::<CurrentNetwork>::parse("[ 1 ]").is_err());
+        assert!(Access::<CurrentNetwork>::parse("[4294967296]").is_err());
+    }
+
+    #[test]
+    fn test_display() -> Result<()> {
+        assert_eq!(Access::<CurrentNetwork>::from_str(".owner")?.to_string(), ".owner");
+        assert_eq!(Access::<CurrentNetwork>::from_str("[3]")?.to_string(), "[3]");
+        Ok(())
+    }
+}
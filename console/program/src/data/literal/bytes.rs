@@ -36,8 +36,9 @@ impl<N: Network> FromBytes for Literal<N> {
             12 => Self::U64(U64::read_le(&mut reader)?),
             13 => Self::U128(U128::read_le(&mut reader)?),
             14 => Self::Scalar(Scalar::read_le(&mut reader)?),
-            15 => Self::String(StringType::read_le(&mut reader)?),
-            16.. => return Err(error(format!("Failed to decode literal variant {index}"))),
+            15 => Self::Signature(Box::new(Signature::read_le(&mut reader)?)),
+            16 => Self::String(StringType::read_le(&mut reader)?),
+            17.. => return Err(error(format!("Failed to decode literal variant {index}"))),
         };
         Ok(literal)
     }
@@ -108,10 +109,14 @@ impl<N: Network> ToBytes for Literal<N> {
                 (14 as Size).write_le(&mut writer)?;
                 primitive.write_le(&mut writer)
             }
-            Self::String(primitive) => {
+            Self::Signature(primitive) => {
                 (15 as Size).write_le(&mut writer)?;
                 primitive.write_le(&mut writer)
             }
+            Self::String(primitive) => {
+                (16 as Size).write_le(&mut writer)?;
+                primitive.write_le(&mut writer)
+            }
         }
     }
 }
@@ -171,6 +176,8 @@ mod tests {
             check_bytes(Literal::<CurrentNetwork>::U128(U128::new(Uniform::rand(rng))))?;
             // Scalar
             check_bytes(Literal::<CurrentNetwork>::Scalar(Uniform::rand(rng)))?;
+            // Signature
+            check_bytes(Literal::<CurrentNetwork>::sample(LiteralType::Signature, rng))?;
             // String
             check_bytes(Literal::<CurrentNetwork>::String(StringType::rand(rng)))?;
         }
@@ -0,0 +1,284 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_console_types::integers::Integer;
+
+impl<N: Network> Literal<N> {
+    /// Casts the literal into the given literal type, preserving its value.
+    /// Returns an error if the value is not representable in the given literal type.
+    ///
+    /// The value of a literal is the value of its base field representation, where
+    /// signed integers are mapped to the field element of the same (possibly negative) value,
+    /// and groups and addresses are represented by their x-coordinate.
+    pub fn cast(&self, to_type: LiteralType) -> Result<Self> {
+        match (self, to_type) {
+            // Casting into the same type is the identity.
+            _ if self.to_type() == to_type => Ok(self.clone()),
+            // Ensure neither type is a signature or a string.
+            (Self::Signature(..) | Self::String(..), _) | (_, LiteralType::Signature | LiteralType::String) => {
+                bail!("Cannot cast a '{}' literal into a '{to_type}'", self.to_type())
+            }
+            // Addresses and groups share the same representation.
+            (Self::Address(address), LiteralType::Group) => Ok(Self::Group(**address)),
+            (Self::Group(group), LiteralType::Address) => Ok(Self::Address(Address::new(*group))),
+            // Otherwise, cast through the base field representation.
+            _ => Self::from_field_checked(self.to_field_value()?, to_type),
+        }
+    }
+
+    /// Casts the literal into the given literal type, truncating its value if necessary.
+    ///
+    /// Casts into booleans, integers, and scalars keep the lower bits of the value,
+    /// where integers are sign-extended and all other literals use their base field representation.
+    /// Casts into all other literal types are checked, as in `cast`.
+    pub fn cast_lossy(&self, to_type: LiteralType) -> Result<Self> {
+        match (self, to_type) {
+            // Ensure neither type is a signature or a string.
+            (Self::Signature(..) | Self::String(..), _) | (_, LiteralType::Signature | LiteralType::String) => {
+                bail!("Cannot cast a '{}' literal into a '{to_type}'", self.to_type())
+            }
+            (_, LiteralType::Address | LiteralType::Field | LiteralType::Group) => self.cast(to_type),
+            _ => Self::from_bits_lossy(&self.to_bits_lossy()?, to_type),
+        }
+    }
+}
+
+impl<N: Network> Literal<N> {
+    /// Returns the base field representation of the literal.
+    fn to_field_value(&self) -> Result<Field<N>> {
+        match self {
+            Self::Address(address) => address.to_field(),
+            Self::Boolean(boolean) => match **boolean {
+                true => Ok(Field::one()),
+                false => Ok(Field::zero()),
+            },
+            Self::Field(field) => Ok(*field),
+            Self::Group(group) => group.to_field(),
+            Self::I8(integer) => integer_to_field(integer),
+            Self::I16(integer) => integer_to_field(integer),
+            Self::I32(integer) => integer_to_field(integer),
+            Self::I64(integer) => integer_to_field(integer),
+            Self::I128(integer) => integer_to_field(integer),
+            Self::U8(integer) => integer_to_field(integer),
+            Self::U16(integer) => integer_to_field(integer),
+            Self::U32(integer) => integer_to_field(integer),
+            Self::U64(integer) => integer_to_field(integer),
+            Self::U128(integer) => integer_to_field(integer),
+            Self::Scalar(scalar) => scalar.to_field(),
+            Self::Signature(..) => bail!("Cannot cast a signature literal into a field element"),
+            Self::String(..) => bail!("Cannot cast a string literal into a field element"),
+        }
+    }
+
+    /// Returns the literal of the given type with the value of the given base field element.
+    fn from_field_checked(field: Field<N>, to_type: LiteralType) -> Result<Self> {
+        match to_type {
+            LiteralType::Address => Ok(Self::Address(Address::new(Group::from_x_coordinate(field)?))),
+            LiteralType::Boolean => match field {
+                field if field == Field::zero() => Ok(Self::Boolean(Boolean::new(false))),
+                field if field == Field::one() => Ok(Self::Boolean(Boolean::new(true))),
+                _ => bail!("Failed to cast '{field}' into a boolean"),
+            },
+            LiteralType::Field => Ok(Self::Field(field)),
+            LiteralType::Group => Ok(Self::Group(Group::from_x_coordinate(field)?)),
+            LiteralType::I8 => Ok(Self::I8(integer_from_field(field)?)),
+            LiteralType::I16 => Ok(Self::I16(integer_from_field(field)?)),
+            LiteralType::I32 => Ok(Self::I32(integer_from_field(field)?)),
+            LiteralType::I64 => Ok(Self::I64(integer_from_field(field)?)),
+            LiteralType::I128 => Ok(Self::I128(integer_from_field(field)?)),
+            LiteralType::U8 => Ok(Self::U8(integer_from_field(field)?)),
+            LiteralType::U16 => Ok(Self::U16(integer_from_field(field)?)),
+            LiteralType::U32 => Ok(Self::U32(integer_from_field(field)?)),
+            LiteralType::U64 => Ok(Self::U64(integer_from_field(field)?)),
+            LiteralType::U128 => Ok(Self::U128(integer_from_field(field)?)),
+            LiteralType::Scalar => Ok(Self::Scalar(Scalar::from_bits_le(&field.to_bits_le())?)),
+            LiteralType::Signature => bail!("Cannot cast a field element into a signature"),
+            LiteralType::String => bail!("Cannot cast a field element into a string"),
+        }
+    }
+
+    /// Returns the little-endian bits used by `cast_lossy`, which are
+    /// the sign-extended bits for integers, and the base field bits otherwise.
+    fn to_bits_lossy(&self) -> Result<Vec<bool>> {
+        // Sign-extends the given integer bits to the size of a base field element.
+        let sign_extend = |mut bits_le: Vec<bool>, is_signed: bool| {
+            let extension = is_signed && bits_le.last().copied().unwrap_or(false);
+            bits_le.resize(Field::<N>::size_in_bits(), extension);
+            bits_le
+        };
+
+        match self {
+            Self::I8(integer) => Ok(sign_extend(integer.to_bits_le(), true)),
+            Self::I16(integer) => Ok(sign_extend(integer.to_bits_le(), true)),
+            Self::I32(integer) => Ok(sign_extend(integer.to_bits_le(), true)),
+            Self::I64(integer) => Ok(sign_extend(integer.to_bits_le(), true)),
+            Self::I128(integer) => Ok(sign_extend(integer.to_bits_le(), true)),
+            Self::U8(integer) => Ok(sign_extend(integer.to_bits_le(), false)),
+            Self::U16(integer) => Ok(sign_extend(integer.to_bits_le(), false)),
+            Self::U32(integer) => Ok(sign_extend(integer.to_bits_le(), false)),
+            Self::U64(integer) => Ok(sign_extend(integer.to_bits_le(), false)),
+            Self::U128(integer) => Ok(sign_extend(integer.to_bits_le(), false)),
+            _ => Ok(self.to_field_value()?.to_bits_le()),
+        }
+    }
+
+    /// Returns the literal of the given type from the lower bits of the given little-endian bits.
+    fn from_bits_lossy(bits_le: &[bool], to_type: LiteralType) -> Result<Self> {
+        match to_type {
+            LiteralType::Boolean => Ok(Self::Boolean(Boolean::new(bits_le[0]))),
+            LiteralType::I8 => Ok(Self::I8(Integer::from_bits_le(&bits_le[..8])?)),
+            LiteralType::I16 => Ok(Self::I16(Integer::from_bits_le(&bits_le[..16])?)),
+            LiteralType::I32 => Ok(Self::I32(Integer::from_bits_le(&bits_le[..32])?)),
+            LiteralType::I64 => Ok(Self::I64(Integer::from_bits_le(&bits_le[..64])?)),
+            LiteralType::I128 => Ok(Self::I128(Integer::from_bits_le(&bits_le[..128])?)),
+            LiteralType::U8 => Ok(Self::U8(Integer::from_bits_le(&bits_le[..8])?)),
+            LiteralType::U16 => Ok(Self::U16(Integer::from_bits_le(&bits_le[..16])?)),
+            LiteralType::U32 => Ok(Self::U32(Integer::from_bits_le(&bits_le[..32])?)),
+            LiteralType::U64 => Ok(Self::U64(Integer::from_bits_le(&bits_le[..64])?)),
+            LiteralType::U128 => Ok(Self::U128(Integer::from_bits_le(&bits_le[..128])?)),
+            LiteralType::Scalar => {
+                Ok(Self::Scalar(Scalar::from_bits_le(&bits_le[..Scalar::<N>::size_in_data_bits()])?))
+            }
+            _ => bail!("Cannot truncate a literal into a '{to_type}'"),
+        }
+    }
+}
+
+/// Returns `2^exponent` as a base field element.
+fn two_to_the<N: Network>(exponent: usize) -> Result<Field<N>> {
+    let mut bits_le = vec![false; exponent];
+    bits_le.push(true);
+    Field::from_bits_le(&bits_le)
+}
+
+/// Returns the base field element with the same value as the given integer.
+fn integer_to_field<N: Network, I: IntegerType>(integer: &Integer<N, I>) -> Result<Field<N>> {
+    let bits_le = integer.to_bits_le();
+    match I::is_signed() {
+        // The integer is non-negative, so its bits are its value.
+        false => Field::from_bits_le(&bits_le),
+        // Compute the two's complement value, as `sum(2^i * b_i) - 2^(n - 1) * b_(n - 1)`.
+        true => {
+            let (magnitude_bits_le, sign_bit) = bits_le.split_at(bits_le.len() - 1);
+            let magnitude = Field::from_bits_le(magnitude_bits_le)?;
+            match sign_bit[0] {
+                true => Ok(magnitude - two_to_the(magnitude_bits_le.len())?),
+                false => Ok(magnitude),
+            }
+        }
+    }
+}
+
+/// Returns the integer with the same value as the given base field element.
+/// Returns an error if the value is out of range for the integer type.
+fn integer_from_field<N: Network, I: IntegerType>(field: Field<N>) -> Result<Integer<N, I>> {
+    match I::is_signed() {
+        // Ensure the value is less than `2^n`.
+        false => Integer::from_field(&field),
+        // Ensure the value is in `[-2^(n - 1), 2^(n - 1))`, by checking `value + 2^(n - 1)` is less than `2^n`.
+        // The two's complement bits of the value are then the bits of the shifted value, with the top bit flipped.
+        true => {
+            let num_bits = usize::try_from(I::BITS)?;
+            let shifted = field + two_to_the(num_bits - 1)?;
+            let mut bits_le = shifted.to_bits_le();
+            let type_name = I::type_name();
+            ensure!(bits_le[num_bits..].iter().all(|bit| !bit), "Failed to cast '{field}' into '{type_name}'");
+            bits_le.truncate(num_bits);
+            bits_le[num_bits - 1] = !bits_le[num_bits - 1];
+            Integer::from_bits_le(&bits_le)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    fn check_cast(input: &str, to_type: &str, expected: Option<&str>) {
+        let literal = Literal::<CurrentNetwork>::from_str(input).unwrap();
+        let candidate = literal.cast(LiteralType::from_str(to_type).unwrap());
+        match expected {
+            Some(expected) => assert_eq!(candidate.unwrap(), Literal::from_str(expected).unwrap()),
+            None => assert!(candidate.is_err(), "Casting '{input}' into '{to_type}' should fail"),
+        }
+    }
+
+    fn check_cast_lossy(input: &str, to_type: &str, expected: &str) {
+        let literal = Literal::<CurrentNetwork>::from_str(input).unwrap();
+        let candidate = literal.cast_lossy(LiteralType::from_str(to_type).unwrap()).unwrap();
+        assert_eq!(candidate, Literal::from_str(expected).unwrap());
+    }
+
+    #[test]
+    fn test_cast_integers() {
+        check_cast("5u8", "u128", Some("5u128"));
+        check_cast("255u8", "i8", None);
+        check_cast("127u8", "i8", Some("127i8"));
+        check_cast("-1i8", "u8", None);
+        check_cast("-128i8", "i128", Some("-128i128"));
+        check_cast("-129i16", "i8", None);
+        check_cast("-128i16", "i8", Some("-128i8"));
+        check_cast("128i16", "i8", None);
+        check_cast("340282366920938463463374607431768211455u128", "i128", None);
+    }
+
+    #[test]
+    fn test_cast_field() {
+        check_cast("5u8", "field", Some("5field"));
+        check_cast("-1i64", "field", Some("-1field"));
+        check_cast("-1field", "i8", Some("-1i8"));
+        check_cast("-1field", "u8", None);
+        check_cast("256field", "u8", None);
+        check_cast("255field", "u8", Some("255u8"));
+        check_cast("true", "field", Some("1field"));
+        check_cast("1field", "boolean", Some("true"));
+        check_cast("0u32", "boolean", Some("false"));
+        check_cast("2field", "boolean", None);
+        check_cast("7scalar", "field", Some("7field"));
+        check_cast("7field", "scalar", Some("7scalar"));
+        check_cast("-1field", "scalar", None);
+    }
+
+    #[test]
+    fn test_cast_group() {
+        let group = Literal::<CurrentNetwork>::Group(Group::generator());
+        // Ensure the x-coordinate round-trips into the group.
+        let x_coordinate = group.cast(LiteralType::Field).unwrap();
+        assert_eq!(x_coordinate.cast(LiteralType::Group).unwrap(), group);
+        // Ensure a group is castable into an address, and back.
+        let address = group.cast(LiteralType::Address).unwrap();
+        assert_eq!(address.cast(LiteralType::Group).unwrap(), group);
+        // Ensure strings are not castable.
+        check_cast("\"hello\"", "field", None);
+        check_cast("1field", "string", None);
+    }
+
+    #[test]
+    fn test_cast_lossy() {
+        check_cast_lossy("256u16", "u8", "0u8");
+        check_cast_lossy("255u8", "i8", "-1i8");
+        check_cast_lossy("-1i8", "u128", "340282366920938463463374607431768211455u128");
+        check_cast_lossy("-2i8", "boolean", "false");
+        check_cast_lossy("3u8", "boolean", "true");
+        check_cast_lossy("258field", "u8", "2u8");
+        check_cast_lossy("5u8", "field", "5field");
+        check_cast_lossy("7field", "scalar", "7scalar");
+    }
+}
@@ -15,6 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod bytes;
+mod cast;
 mod equal;
 mod from_bits;
 mod parse;
@@ -26,6 +27,7 @@ mod to_type;
 mod variant;
 
 use crate::LiteralType;
+use snarkvm_console_account::Signature;
 use snarkvm_console_network::Network;
 use snarkvm_console_types::{prelude::*, Boolean};
 
@@ -62,6 +64,8 @@ pub enum Literal<N: Network> {
     U128(U128<N>),
     /// The scalar type.
     Scalar(Scalar<N>),
+    /// The signature type.
+    Signature(Box<Signature<N>>),
     /// The string type.
     String(StringType<N>),
 }
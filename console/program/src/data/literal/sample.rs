@@ -15,6 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
+use snarkvm_console_account::PrivateKey;
 
 impl<N: Network> Literal<N> {
     /// Returns a randomly-sampled literal of the given literal type.
@@ -35,6 +36,13 @@ impl<N: Network> Literal<N> {
             LiteralType::U64 => Literal::U64(U64::rand(rng)),
             LiteralType::U128 => Literal::U128(U128::rand(rng)),
             LiteralType::Scalar => Literal::Scalar(Scalar::rand(rng)),
+            LiteralType::Signature => {
+                // Sign a random message with a random private key.
+                let private_key = PrivateKey::new(rng).or_halt_with::<N>("Failed to sample a private key");
+                let message = [Field::rand(rng)];
+                let signature = Signature::sign(&private_key, &message, rng).or_halt_with::<N>("Failed to sign");
+                Literal::Signature(Box::new(signature))
+            }
             LiteralType::String => Literal::String(StringType::rand(rng)),
         }
     }
@@ -43,7 +43,18 @@ impl<N: Network> Equal<Self> for Plaintext<N> {
                 }
                 false => Boolean::new(false),
             },
-            (Self::Literal(..), _) | (Self::Struct(..), _) => Boolean::new(false),
+            (Self::Array(a, _), Self::Array(b, _)) => match a.len() == b.len() {
+                true => {
+                    // Recursively check each element for equality.
+                    let mut equal = Boolean::new(true);
+                    for (plaintext_a, plaintext_b) in a.iter().zip_eq(b.iter()) {
+                        equal = equal & plaintext_a.is_equal(plaintext_b);
+                    }
+                    equal
+                }
+                false => Boolean::new(false),
+            },
+            (Self::Literal(..), _) | (Self::Struct(..), _) | (Self::Array(..), _) => Boolean::new(false),
         }
     }
 
@@ -62,7 +73,18 @@ impl<N: Network> Equal<Self> for Plaintext<N> {
                 }
                 false => Boolean::new(true),
             },
-            (Self::Literal(..), _) | (Self::Struct(..), _) => Boolean::new(true),
+            (Self::Array(a, _), Self::Array(b, _)) => match a.len() == b.len() {
+                true => {
+                    // Recursively check each element for inequality.
+                    let mut not_equal = Boolean::new(false);
+                    for (plaintext_a, plaintext_b) in a.iter().zip_eq(b.iter()) {
+                        not_equal = not_equal | plaintext_a.is_not_equal(plaintext_b);
+                    }
+                    not_equal
+                }
+                false => Boolean::new(true),
+            },
+            (Self::Literal(..), _) | (Self::Struct(..), _) | (Self::Array(..), _) => Boolean::new(true),
         }
     }
 }
@@ -139,4 +161,20 @@ mod tests {
     fn test_is_not_equal() {
         check_is_not_equal()
     }
+
+    #[test]
+    fn test_is_equal_array() -> Result<()> {
+        let array = Plaintext::<CurrentNetwork>::from_str("[1u64, 2u64, 3u64]")?;
+        let mismatched_element = Plaintext::<CurrentNetwork>::from_str("[1u64, 2u64, 4u64]")?;
+        let mismatched_length = Plaintext::<CurrentNetwork>::from_str("[1u64, 2u64]")?;
+        let struct_ = Plaintext::<CurrentNetwork>::from_str("{ a: 1u64, b: 2u64, c: 3u64 }")?;
+
+        assert!(*array.is_equal(&array));
+        assert!(!*array.is_not_equal(&array));
+        for other in [&mismatched_element, &mismatched_length, &struct_] {
+            assert!(!*array.is_equal(other));
+            assert!(*array.is_not_equal(other));
+        }
+        Ok(())
+    }
 }
@@ -27,7 +27,7 @@ mod size_in_fields;
 mod to_bits;
 mod to_fields;
 
-use crate::{Ciphertext, Identifier, Literal};
+use crate::{Access, Ciphertext, Identifier, Literal};
 use snarkvm_console_network::Network;
 use snarkvm_console_types::prelude::*;
 
@@ -40,6 +40,8 @@ pub enum Plaintext<N: Network> {
     Literal(Literal<N>, OnceCell<Vec<bool>>),
     /// A struct.
     Struct(IndexMap<Identifier<N>, Plaintext<N>>, OnceCell<Vec<bool>>),
+    /// An array.
+    Array(Vec<Plaintext<N>>, OnceCell<Vec<bool>>),
 }
 
 impl<N: Network> From<Literal<N>> for Plaintext<N> {
@@ -153,6 +155,15 @@ mod tests {
             OnceCell::new(),
         );
         assert_eq!(value.to_bits_le(), Plaintext::<CurrentNetwork>::from_bits_le(&value.to_bits_le())?.to_bits_le());
+
+        let value = Plaintext::<CurrentNetwork>::from_str("[1u64, 2u64, 3u64]")?;
+        assert_eq!(value.to_bits_le(), Plaintext::<CurrentNetwork>::from_bits_le(&value.to_bits_le())?.to_bits_le());
+        assert_eq!(value.to_bits_be(), Plaintext::<CurrentNetwork>::from_bits_be(&value.to_bits_be())?.to_bits_be());
+
+        let value = Plaintext::<CurrentNetwork>::from_str("{ a: [{ b: true }, { b: false }], c: [0field] }")?;
+        assert_eq!(value.to_bits_le(), Plaintext::<CurrentNetwork>::from_bits_le(&value.to_bits_le())?.to_bits_le());
+        assert_eq!(value.to_bits_be(), Plaintext::<CurrentNetwork>::from_bits_be(&value.to_bits_be())?.to_bits_be());
+        assert_eq!(value, Plaintext::<CurrentNetwork>::from_fields(&value.to_fields()?)?);
         Ok(())
     }
 }
@@ -62,6 +62,36 @@ impl<N: Network> Parser for Plaintext<N> {
             Ok((string, Plaintext::Struct(IndexMap::from_iter(members.into_iter()), Default::default())))
         }
 
+        /// Parses a sanitized element: `plaintext`.
+        fn parse_element<N: Network>(string: &str) -> ParserResult<Plaintext<N>> {
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the plaintext from the string.
+            Plaintext::parse(string)
+        }
+
+        /// Parses a plaintext as an array: `[plaintext_0, ..., plaintext_n]`.
+        fn parse_array<N: Network>(string: &str) -> ParserResult<Plaintext<N>> {
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the "[" from the string.
+            let (string, _) = tag("[")(string)?;
+            // Parse the elements.
+            let (string, elements) = map_res(separated_list1(tag(","), parse_element), |elements: Vec<_>| {
+                // Ensure the number of elements is within `N::MAX_DATA_ENTRIES`.
+                match elements.len() <= N::MAX_DATA_ENTRIES {
+                    true => Ok(elements),
+                    false => Err(error(format!("Found a plaintext array that exceeds size ({})", elements.len()))),
+                }
+            })(string)?;
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the ']' from the string.
+            let (string, _) = tag("]")(string)?;
+            // Output the plaintext.
+            Ok((string, Plaintext::Array(elements, Default::default())))
+        }
+
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse to determine the plaintext (order matters).
@@ -70,6 +100,8 @@ impl<N: Network> Parser for Plaintext<N> {
             map(Literal::parse, |literal| Self::Literal(literal, Default::default())),
             // Parse a plaintext struct.
             parse_struct,
+            // Parse a plaintext array.
+            parse_array,
         ))(string)
     }
 }
@@ -131,7 +163,7 @@ impl<N: Network> Plaintext<N> {
                             // Print the member with a comma.
                             false => write!(f, "\n{:indent$}{name}: {literal},", "", indent = (depth + 1) * INDENT),
                         },
-                        Self::Struct(..) => {
+                        Self::Struct(..) | Self::Array(..) => {
                             // Print the member name.
                             write!(f, "\n{:indent$}{name}: ", "", indent = (depth + 1) * INDENT)?;
                             // Print the member.
@@ -141,7 +173,40 @@ impl<N: Network> Plaintext<N> {
                                 // Print the last member without a comma.
                                 true => write!(f, "\n{:indent$}}}", "", indent = depth * INDENT),
                                 // Print the member with a comma.
-                                false => write!(f, "\n{:indent$}}},", "", indent = depth * INDENT),
+                                false => write!(f, ","),
+                            }
+                        }
+                    }
+                })
+            }
+            // Prints the array, i.e. [ 10u64, 198u64 ]
+            Self::Array(elements, ..) => {
+                // Print the opening bracket.
+                write!(f, "[")?;
+                // Print the elements.
+                elements.iter().enumerate().try_for_each(|(i, plaintext)| {
+                    match plaintext {
+                        Self::Literal(literal, ..) => match i == elements.len() - 1 {
+                            true => {
+                                // Print the last element without a comma.
+                                write!(f, "\n{:indent$}{literal}", "", indent = (depth + 1) * INDENT)?;
+                                // Print the closing bracket.
+                                write!(f, "\n{:indent$}]", "", indent = depth * INDENT)
+                            }
+                            // Print the element with a comma.
+                            false => write!(f, "\n{:indent$}{literal},", "", indent = (depth + 1) * INDENT),
+                        },
+                        Self::Struct(..) | Self::Array(..) => {
+                            // Print the indentation.
+                            write!(f, "\n{:indent$}", "", indent = (depth + 1) * INDENT)?;
+                            // Print the element.
+                            plaintext.fmt_internal(f, depth + 1)?;
+                            // Print the closing bracket.
+                            match i == elements.len() - 1 {
+                                // Print the last element without a comma.
+                                true => write!(f, "\n{:indent$}]", "", indent = depth * INDENT),
+                                // Print the element with a comma.
+                                false => write!(f, ","),
                             }
                         }
                     }
@@ -213,11 +278,56 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_array() -> Result<()> {
+        // Sanity check.
+        let expected = r"[
+  1u64,
+  2u64
+]";
+        let (remainder, candidate) = Plaintext::<CurrentNetwork>::parse("[1u64,2u64]")?;
+        assert_eq!(expected, candidate.to_string());
+        assert_eq!("", remainder);
+
+        let expected = r"{
+  foo: [
+    {
+      bar: 1u8
+    },
+    {
+      bar: 2u8
+    }
+  ],
+  baz: {
+    qux: true
+  },
+  quux: [
+    0field
+  ]
+}";
+        let (remainder, candidate) = Plaintext::<CurrentNetwork>::parse(
+            "{ foo: [{ bar: 1u8 }, { bar: 2u8 }], baz: { qux: true }, quux: [0field] }",
+        )?;
+        println!("\nExpected: {expected}\n\nFound: {candidate}\n");
+        assert_eq!(expected, candidate.to_string());
+        assert_eq!("", remainder);
+        assert_eq!(candidate, Plaintext::from_str(expected)?);
+
+        // Must not exceed `N::MAX_DATA_ENTRIES` elements.
+        let elements = vec!["1u8"; CurrentNetwork::MAX_DATA_ENTRIES];
+        assert!(Plaintext::<CurrentNetwork>::from_str(&format!("[{}]", elements.join(", "))).is_ok());
+        let elements = vec!["1u8"; CurrentNetwork::MAX_DATA_ENTRIES + 1];
+        assert!(Plaintext::<CurrentNetwork>::from_str(&format!("[{}]", elements.join(", "))).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_parse_fails() {
         // Must be non-empty.
         assert!(Plaintext::<CurrentNetwork>::parse("").is_err());
         assert!(Plaintext::<CurrentNetwork>::parse("{}").is_err());
+        assert!(Plaintext::<CurrentNetwork>::parse("[]").is_err());
 
         // Invalid characters.
         assert!(Plaintext::<CurrentNetwork>::parse("_").is_err());
@@ -18,7 +18,7 @@ mod bytes;
 mod parse;
 mod serialize;
 
-use crate::Identifier;
+use crate::Access;
 use snarkvm_console_network::prelude::*;
 
 /// A register contains the location data to a value in memory.
@@ -26,8 +26,8 @@ use snarkvm_console_network::prelude::*;
 pub enum Register<N: Network> {
     /// A register contains its locator in memory.
     Locator(u64),
-    /// A register member contains its locator and identifier(s) in memory.
-    Member(u64, Vec<Identifier<N>>),
+    /// A register member contains its locator and access path (member identifiers and array indices) in memory.
+    Member(u64, Vec<Access<N>>),
 }
 
 impl<N: Network> Register<N> {
@@ -42,14 +42,14 @@ impl<N: Network> Register<N> {
 }
 
 impl<N: Network> Ord for Register<N> {
-    /// Ordering is determined by the register locator (any member identifiers are ignored).
+    /// Ordering is determined by the register locator (any access path is ignored).
     fn cmp(&self, other: &Self) -> Ordering {
         self.locator().cmp(&other.locator())
     }
 }
 
 impl<N: Network> PartialOrd for Register<N> {
-    /// Ordering is determined by the register locator (any member identifiers are ignored).
+    /// Ordering is determined by the register locator (any access path is ignored).
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
@@ -58,6 +58,7 @@ impl<N: Network> PartialOrd for Register<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Identifier;
     use snarkvm_console_network::Testnet3;
 
     type CurrentNetwork = Testnet3;
@@ -81,30 +82,21 @@ mod tests {
         // Register::Member
         assert_eq!(
             Some(Ordering::Equal),
-            Register::<CurrentNetwork>::Member(0, vec![Identifier::from_str("owner")?]).partial_cmp(&Register::<
-                CurrentNetwork,
-            >::Member(
-                0,
-                vec![Identifier::from_str("owner")?]
-            ))
+            Register::<CurrentNetwork>::Member(0, vec![Access::Member(Identifier::from_str("owner")?)]).partial_cmp(
+                &Register::<CurrentNetwork>::Member(0, vec![Access::Member(Identifier::from_str("owner")?)])
+            )
         );
         assert_eq!(
             Some(Ordering::Less),
-            Register::<CurrentNetwork>::Member(0, vec![Identifier::from_str("owner")?]).partial_cmp(&Register::<
-                CurrentNetwork,
-            >::Member(
-                1,
-                vec![Identifier::from_str("owner")?]
-            ))
+            Register::<CurrentNetwork>::Member(0, vec![Access::Member(Identifier::from_str("owner")?)]).partial_cmp(
+                &Register::<CurrentNetwork>::Member(1, vec![Access::Member(Identifier::from_str("owner")?)])
+            )
         );
         assert_eq!(
             Some(Ordering::Greater),
-            Register::<CurrentNetwork>::Member(1, vec![Identifier::from_str("owner")?]).partial_cmp(&Register::<
-                CurrentNetwork,
-            >::Member(
-                0,
-                vec![Identifier::from_str("owner")?]
-            ))
+            Register::<CurrentNetwork>::Member(1, vec![Access::Member(Identifier::from_str("owner")?)]).partial_cmp(
+                &Register::<CurrentNetwork>::Member(0, vec![Access::Member(Identifier::from_str("owner")?)])
+            )
         );
         Ok(())
     }
@@ -120,24 +112,24 @@ mod tests {
 
         // Register::Member
         assert_eq!(
-            Register::<CurrentNetwork>::Member(0, vec![Identifier::from_str("owner")?]),
-            Register::<CurrentNetwork>::Member(0, vec![Identifier::from_str("owner")?])
+            Register::<CurrentNetwork>::Member(0, vec![Access::Member(Identifier::from_str("owner")?)]),
+            Register::<CurrentNetwork>::Member(0, vec![Access::Member(Identifier::from_str("owner")?)])
         );
         assert_ne!(
-            Register::<CurrentNetwork>::Member(0, vec![Identifier::from_str("owner")?]),
-            Register::<CurrentNetwork>::Member(1, vec![Identifier::from_str("owner")?])
+            Register::<CurrentNetwork>::Member(0, vec![Access::Member(Identifier::from_str("owner")?)]),
+            Register::<CurrentNetwork>::Member(1, vec![Access::Member(Identifier::from_str("owner")?)])
         );
         assert_ne!(
-            Register::<CurrentNetwork>::Member(0, vec![Identifier::from_str("owner")?]),
-            Register::<CurrentNetwork>::Member(2, vec![Identifier::from_str("owner")?])
+            Register::<CurrentNetwork>::Member(0, vec![Access::Member(Identifier::from_str("owner")?)]),
+            Register::<CurrentNetwork>::Member(2, vec![Access::Member(Identifier::from_str("owner")?)])
         );
         assert_ne!(
-            Register::<CurrentNetwork>::Member(0, vec![Identifier::from_str("owner")?]),
-            Register::<CurrentNetwork>::Member(3, vec![Identifier::from_str("owner")?])
+            Register::<CurrentNetwork>::Member(0, vec![Access::Member(Identifier::from_str("owner")?)]),
+            Register::<CurrentNetwork>::Member(3, vec![Access::Member(Identifier::from_str("owner")?)])
         );
         assert_ne!(
-            Register::<CurrentNetwork>::Member(0, vec![Identifier::from_str("owner")?]),
-            Register::<CurrentNetwork>::Member(4, vec![Identifier::from_str("owner")?])
+            Register::<CurrentNetwork>::Member(0, vec![Access::Member(Identifier::from_str("owner")?)]),
+            Register::<CurrentNetwork>::Member(4, vec![Access::Member(Identifier::from_str("owner")?)])
         );
         Ok(())
     }
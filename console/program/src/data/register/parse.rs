@@ -18,7 +18,7 @@ use super::*;
 
 impl<N: Network> Parser for Register<N> {
     /// Parses a string into a register.
-    /// The register is of the form `r{locator}` or `r{locator}.{identifier}`.
+    /// The register is of the form `r{locator}`, `r{locator}.{identifier}`, or `r{locator}[{index}]`.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
         // Parse the register character from the string.
@@ -26,20 +26,19 @@ impl<N: Network> Parser for Register<N> {
         // Parse the locator from the string.
         let (string, locator) =
             map_res(recognize(many1(one_of("0123456789"))), |locator: &str| locator.parse::<u64>())(string)?;
-        // Parse the identifier from the string, if it is a register member.
-        let (string, identifiers): (&str, Vec<Identifier<N>>) =
-            map_res(many0(pair(tag("."), Identifier::parse)), |identifiers| {
-                // Ensure the number of identifiers is within `N::MAX_DATA_DEPTH`.
-                if identifiers.len() <= N::MAX_DATA_DEPTH {
-                    Ok(identifiers.iter().cloned().map(|(_, identifier)| identifier).collect())
-                } else {
-                    Err(error(format!("Register \'r{locator}\' has too many identifiers ({})", identifiers.len())))
-                }
-            })(string)?;
+        // Parse the access path from the string, if it is a register member.
+        let (string, path): (&str, Vec<Access<N>>) = map_res(many0(Access::parse), |path: Vec<Access<N>>| {
+            // Ensure the number of accesses is within `N::MAX_DATA_DEPTH`.
+            if path.len() <= N::MAX_DATA_DEPTH {
+                Ok(path)
+            } else {
+                Err(error(format!("Register \'r{locator}\' has too many accesses ({})", path.len())))
+            }
+        })(string)?;
         // Return the register.
-        Ok((string, match identifiers.len() {
+        Ok((string, match path.len() {
             0 => Self::Locator(locator),
-            _ => Self::Member(locator, identifiers),
+            _ => Self::Member(locator, path),
         }))
     }
 }
@@ -75,11 +74,11 @@ impl<N: Network> Display for Register<N> {
         match self {
             // Prints the register, i.e. r0
             Self::Locator(locator) => write!(f, "r{locator}"),
-            // Prints the register member, i.e. r0.owner
-            Self::Member(locator, identifiers) => {
+            // Prints the register member, i.e. r0.owner or r0.balances[3]
+            Self::Member(locator, path) => {
                 write!(f, "r{locator}")?;
-                for identifier in identifiers {
-                    write!(f, ".{identifier}")?;
+                for access in path {
+                    write!(f, "{access}")?;
                 }
                 Ok(())
             }
@@ -90,6 +89,7 @@ impl<N: Network> Display for Register<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Identifier;
     use snarkvm_console_network::Testnet3;
 
     type CurrentNetwork = Testnet3;
@@ -106,23 +106,23 @@ mod tests {
         // Register::Member
         assert_eq!(
             "r0.owner",
-            format!("{}", Register::<CurrentNetwork>::Member(0, vec![Identifier::from_str("owner")?]))
+            format!("{}", Register::<CurrentNetwork>::Member(0, vec![Access::Member(Identifier::from_str("owner")?)]))
         );
         assert_eq!(
             "r1.owner",
-            format!("{}", Register::<CurrentNetwork>::Member(1, vec![Identifier::from_str("owner")?]))
+            format!("{}", Register::<CurrentNetwork>::Member(1, vec![Access::Member(Identifier::from_str("owner")?)]))
         );
         assert_eq!(
             "r2.owner",
-            format!("{}", Register::<CurrentNetwork>::Member(2, vec![Identifier::from_str("owner")?]))
+            format!("{}", Register::<CurrentNetwork>::Member(2, vec![Access::Member(Identifier::from_str("owner")?)]))
         );
         assert_eq!(
             "r3.owner",
-            format!("{}", Register::<CurrentNetwork>::Member(3, vec![Identifier::from_str("owner")?]))
+            format!("{}", Register::<CurrentNetwork>::Member(3, vec![Access::Member(Identifier::from_str("owner")?)]))
         );
         assert_eq!(
             "r4.owner",
-            format!("{}", Register::<CurrentNetwork>::Member(4, vec![Identifier::from_str("owner")?]))
+            format!("{}", Register::<CurrentNetwork>::Member(4, vec![Access::Member(Identifier::from_str("owner")?)]))
         );
         Ok(())
     }
@@ -138,23 +138,23 @@ mod tests {
 
         // Register::Member
         assert_eq!(
-            Register::<CurrentNetwork>::Member(0, vec![Identifier::from_str("owner")?]).to_string(),
+            Register::<CurrentNetwork>::Member(0, vec![Access::Member(Identifier::from_str("owner")?)]).to_string(),
             "r0.owner".to_string()
         );
         assert_eq!(
-            Register::<CurrentNetwork>::Member(1, vec![Identifier::from_str("owner")?]).to_string(),
+            Register::<CurrentNetwork>::Member(1, vec![Access::Member(Identifier::from_str("owner")?)]).to_string(),
             "r1.owner".to_string()
         );
         assert_eq!(
-            Register::<CurrentNetwork>::Member(2, vec![Identifier::from_str("owner")?]).to_string(),
+            Register::<CurrentNetwork>::Member(2, vec![Access::Member(Identifier::from_str("owner")?)]).to_string(),
             "r2.owner".to_string()
         );
         assert_eq!(
-            Register::<CurrentNetwork>::Member(3, vec![Identifier::from_str("owner")?]).to_string(),
+            Register::<CurrentNetwork>::Member(3, vec![Access::Member(Identifier::from_str("owner")?)]).to_string(),
             "r3.owner".to_string()
         );
         assert_eq!(
-            Register::<CurrentNetwork>::Member(4, vec![Identifier::from_str("owner")?]).to_string(),
+            Register::<CurrentNetwork>::Member(4, vec![Access::Member(Identifier::from_str("owner")?)]).to_string(),
             "r4.owner".to_string()
         );
         Ok(())
@@ -171,26 +171,44 @@ mod tests {
 
         // Register::Member
         assert_eq!(
-            ("", Register::<CurrentNetwork>::Member(0, vec![Identifier::from_str("owner")?])),
+            ("", Register::<CurrentNetwork>::Member(0, vec![Access::Member(Identifier::from_str("owner")?)])),
             Register::parse("r0.owner").unwrap()
         );
         assert_eq!(
-            ("", Register::<CurrentNetwork>::Member(1, vec![Identifier::from_str("owner")?])),
+            ("", Register::<CurrentNetwork>::Member(1, vec![Access::Member(Identifier::from_str("owner")?)])),
             Register::parse("r1.owner").unwrap()
         );
         assert_eq!(
-            ("", Register::<CurrentNetwork>::Member(2, vec![Identifier::from_str("owner")?])),
+            ("", Register::<CurrentNetwork>::Member(2, vec![Access::Member(Identifier::from_str("owner")?)])),
             Register::parse("r2.owner").unwrap()
         );
         assert_eq!(
-            ("", Register::<CurrentNetwork>::Member(3, vec![Identifier::from_str("owner")?])),
+            ("", Register::<CurrentNetwork>::Member(3, vec![Access::Member(Identifier::from_str("owner")?)])),
             Register::parse("r3.owner").unwrap()
         );
         assert_eq!(
-            ("", Register::<CurrentNetwork>::Member(4, vec![Identifier::from_str("owner")?])),
+            ("", Register::<CurrentNetwork>::Member(4, vec![Access::Member(Identifier::from_str("owner")?)])),
             Register::parse("r4.owner").unwrap()
         );
 
+        // Register::Member with array indices
+        assert_eq!(
+            ("", Register::<CurrentNetwork>::Member(0, vec![Access::Index(3)])),
+            Register::parse("r0[3]").unwrap()
+        );
+        assert_eq!(
+            (
+                "",
+                Register::<CurrentNetwork>::Member(1, vec![
+                    Access::Member(Identifier::from_str("balances")?),
+                    Access::Index(15),
+                    Access::Member(Identifier::from_str("owner")?)
+                ])
+            ),
+            Register::parse("r1.balances[15].owner").unwrap()
+        );
+        assert_eq!("r1.balances[15].owner", Register::<CurrentNetwork>::from_str("r1.balances[15].owner")?.to_string());
+
         // Register::Member with multiple identifiers
         for i in 1..=CurrentNetwork::MAX_DATA_DEPTH {
             let mut string = "r0.".to_string();
@@ -200,7 +218,7 @@ mod tests {
             string.pop(); // Remove last '.'
 
             assert_eq!(
-                ("", Register::<CurrentNetwork>::Member(0, vec![Identifier::from_str("owner")?; i])),
+                ("", Register::<CurrentNetwork>::Member(0, vec![Access::Member(Identifier::from_str("owner")?); i])),
                 Register::<CurrentNetwork>::parse(&string).unwrap()
             );
         }
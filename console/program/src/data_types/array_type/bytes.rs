@@ -0,0 +1,62 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> FromBytes for ArrayType<N> {
+    /// Reads an array type from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the element type.
+        let element_type = PlaintextType::read_le(&mut reader)?;
+        // Read the length.
+        let length = u32::read_le(&mut reader)?;
+        // Return the array type.
+        Self::new(element_type, length).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> ToBytes for ArrayType<N> {
+    /// Writes an array type to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the element type.
+        self.element_type().write_le(&mut writer)?;
+        // Write the length.
+        self.length.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        for string in ["[u64; 16]", "[field; 1]", "[token; 32]"] {
+            let expected = ArrayType::<CurrentNetwork>::from_str(string)?;
+            let expected_bytes = expected.to_bytes_le()?;
+            assert_eq!(expected, ArrayType::read_le(&expected_bytes[..])?);
+        }
+
+        // Ensure an invalid length is rejected.
+        let mut bytes = PlaintextType::<CurrentNetwork>::Literal(LiteralType::U64).to_bytes_le()?;
+        bytes.extend(0u32.to_bytes_le()?);
+        assert!(ArrayType::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+        Ok(())
+    }
+}
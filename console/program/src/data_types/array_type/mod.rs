@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod bytes;
+mod parse;
+mod serialize;
+
+use crate::{Identifier, LiteralType, PlaintextType};
+use snarkvm_console_network::prelude::*;
+
+/// An array type contains its element type and its length.
+/// The format of the type is `[<element_type>; <length>]`.
+///
+/// The element type may be a literal type or a struct type. Arrays of arrays are not supported;
+/// use an array of structs instead.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ArrayType<N: Network> {
+    /// The element type.
+    element_type: ElementType<N>,
+    /// The number of elements in the array.
+    length: u32,
+}
+
+/// The element type of an array type.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+enum ElementType<N: Network> {
+    /// A literal element type.
+    Literal(LiteralType),
+    /// A struct element type.
+    Struct(Identifier<N>),
+}
+
+impl<N: Network> ArrayType<N> {
+    /// Initializes a new array type from the given element type and length.
+    pub fn new(element_type: PlaintextType<N>, length: u32) -> Result<Self> {
+        // Ensure the array is not empty.
+        ensure!(length > 0, "An array must contain at least one element");
+        // Ensure the length is within `N::MAX_DATA_ENTRIES`.
+        ensure!(length as usize <= N::MAX_DATA_ENTRIES, "An array cannot exceed {} elements", N::MAX_DATA_ENTRIES);
+        // Retrieve the element type.
+        let element_type = match element_type {
+            PlaintextType::Literal(literal_type) => ElementType::Literal(literal_type),
+            PlaintextType::Struct(struct_name) => ElementType::Struct(struct_name),
+            PlaintextType::Array(..) => bail!("Array elements cannot be arrays: found '{element_type}'"),
+        };
+        Ok(Self { element_type, length })
+    }
+
+    /// Returns the element type.
+    #[inline]
+    pub const fn element_type(&self) -> PlaintextType<N> {
+        match self.element_type {
+            ElementType::Literal(literal_type) => PlaintextType::Literal(literal_type),
+            ElementType::Struct(struct_name) => PlaintextType::Struct(struct_name),
+        }
+    }
+
+    /// Returns the number of elements in the array.
+    #[inline]
+    pub const fn length(&self) -> u32 {
+        self.length
+    }
+}
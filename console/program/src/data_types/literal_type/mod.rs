@@ -56,6 +56,8 @@ pub enum LiteralType {
     U128,
     /// The scalar type (scalar field).
     Scalar,
+    /// The signature type.
+    Signature,
     /// The string type.
     String,
 }
@@ -79,6 +81,7 @@ impl LiteralType {
             Self::U64 => "u64",
             Self::U128 => "u128",
             Self::Scalar => "scalar",
+            Self::Signature => "signature",
             Self::String => "string",
         }
     }
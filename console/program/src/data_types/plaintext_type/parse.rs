@@ -22,6 +22,7 @@ impl<N: Network> Parser for PlaintextType<N> {
     fn parse(string: &str) -> ParserResult<Self> {
         // Parse to determine the plaintext type (order matters).
         alt((
+            map(ArrayType::parse, |type_| Self::Array(type_)),
             map(LiteralType::parse, |type_| Self::Literal(type_)),
             map(Identifier::parse, |identifier| Self::Struct(identifier)),
         ))(string)
@@ -60,6 +61,8 @@ impl<N: Network> Display for PlaintextType<N> {
             Self::Literal(literal) => Display::fmt(literal, f),
             // Prints the struct, i.e. signature
             Self::Struct(struct_) => Display::fmt(struct_, f),
+            // Prints the array, i.e. [u64; 16]
+            Self::Array(array) => Display::fmt(array, f),
         }
     }
 }
@@ -81,6 +84,10 @@ mod tests {
             PlaintextType::parse("signature"),
             Ok(("", PlaintextType::<CurrentNetwork>::Struct(Identifier::from_str("signature")?)))
         );
+        assert_eq!(
+            PlaintextType::parse("[u64; 16]"),
+            Ok(("", PlaintextType::<CurrentNetwork>::Array(ArrayType::new(LiteralType::U64.into(), 16)?)))
+        );
         Ok(())
     }
 
@@ -153,6 +160,10 @@ mod tests {
             PlaintextType::<CurrentNetwork>::Struct(Identifier::from_str("signature")?).to_string(),
             "signature"
         );
+        assert_eq!(
+            PlaintextType::<CurrentNetwork>::Array(ArrayType::from_str("[signature;4]")?).to_string(),
+            "[signature; 4]"
+        );
         Ok(())
     }
 }
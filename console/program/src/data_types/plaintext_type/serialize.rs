@@ -69,6 +69,10 @@ mod tests {
         "passport",
         "object",
         "array",
+        // Array
+        "[u64; 16]",
+        "[signature; 4]",
+        "[field; 32]",
     ];
 
     fn check_serde_json<
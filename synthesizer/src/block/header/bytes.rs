@@ -23,18 +23,23 @@ impl<N: Network> FromBytes for Header<N> {
         // Read the version.
         let version = u16::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 0 {
+        if version > 1 {
             return Err(error("Invalid header version"));
         }
 
         // Read from the buffer.
         let previous_state_root = Field::<N>::read_le(&mut reader)?;
         let transactions_root = Field::<N>::read_le(&mut reader)?;
+        // Note: Headers before version 1 do not commit to the finalize state, so their finalize root is zero.
+        let finalize_root = match version {
+            0 => Field::zero(),
+            _ => Field::<N>::read_le(&mut reader)?,
+        };
         let coinbase_accumulator_point = Field::<N>::read_le(&mut reader)?;
         let metadata = Metadata::read_le(&mut reader)?;
 
         // Construct the block header.
-        Self::from(previous_state_root, transactions_root, coinbase_accumulator_point, metadata)
+        Self::from(previous_state_root, transactions_root, finalize_root, coinbase_accumulator_point, metadata)
             .map_err(|e| error(e.to_string()))
     }
 }
@@ -44,11 +49,12 @@ impl<N: Network> ToBytes for Header<N> {
     #[inline]
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        0u16.write_le(&mut writer)?;
+        1u16.write_le(&mut writer)?;
 
         // Write to the buffer.
         self.previous_state_root.write_le(&mut writer)?;
         self.transactions_root.write_le(&mut writer)?;
+        self.finalize_root.write_le(&mut writer)?;
         self.coinbase_accumulator_point.write_le(&mut writer)?;
         self.metadata.write_le(&mut writer)
     }
@@ -22,11 +22,13 @@ impl<N: Network> Header<N> {
         // Prepare a genesis block header.
         let previous_state_root = Field::zero();
         let transactions_root = transactions.to_root()?;
+        // Note: The genesis transactions do not write to any mappings, so the finalize state is empty.
+        let finalize_root = Field::zero();
         let coinbase_accumulator_point = Field::zero();
         let metadata = Metadata::genesis()?;
 
         // Return the genesis block header.
-        Self::from(previous_state_root, transactions_root, coinbase_accumulator_point, metadata)
+        Self::from(previous_state_root, transactions_root, finalize_root, coinbase_accumulator_point, metadata)
     }
 
     /// Returns `true` if the block header is a genesis block header.
@@ -35,6 +37,8 @@ impl<N: Network> Header<N> {
         self.previous_state_root == Field::zero()
             // Ensure the transactions root is nonzero.
             && self.transactions_root != Field::zero()
+            // Ensure the finalize root is zero.
+            && self.finalize_root == Field::zero()
             // Ensure the coinbase accumulator point is zero.
             && self.coinbase_accumulator_point == Field::zero()
             // Ensure the metadata is a genesis metadata.
@@ -52,8 +56,8 @@ mod tests {
     /// Returns the expected block header size by summing its subcomponent sizes.
     /// Update this method if the contents of a block header have changed.
     fn get_expected_size<N: Network>() -> usize {
-        // Previous state root, transactions root, and accumulator point size.
-        (Field::<N>::size_in_bytes() * 3)
+        // Previous state root, transactions root, finalize root, and accumulator point size.
+        (Field::<N>::size_in_bytes() * 4)
             // Metadata size.
             + 2 + 4 + 8 + 8 + 8 + 8 + 8 + 8
             // Add an additional 4 bytes for versioning.
@@ -83,6 +87,7 @@ mod tests {
 
         // Ensure the genesis block contains the following.
         assert_eq!(header.previous_state_root(), Field::zero());
+        assert_eq!(header.finalize_root(), Field::zero());
         assert_eq!(header.coinbase_accumulator_point(), Field::zero());
         assert_eq!(header.network(), CurrentNetwork::ID);
         assert_eq!(header.height(), 0);
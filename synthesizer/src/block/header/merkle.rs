@@ -42,6 +42,10 @@ impl<N: Network> Header<N> {
         else if id == &self.coinbase_accumulator_point {
             Ok(HeaderLeaf::<N>::new(2, self.coinbase_accumulator_point))
         }
+        // If the ID is the finalize root, return the 3rd leaf.
+        else if id == &self.finalize_root {
+            Ok(HeaderLeaf::<N>::new(3, self.finalize_root))
+        }
         // If the ID is the metadata hash, then return the 7th leaf.
         else if id == &self.metadata.to_hash()? {
             Ok(HeaderLeaf::<N>::new(7, *id))
@@ -62,7 +66,8 @@ impl<N: Network> Header<N> {
         leaves.push(HeaderLeaf::<N>::new(0, self.previous_state_root).to_bits_le());
         leaves.push(HeaderLeaf::<N>::new(1, self.transactions_root).to_bits_le());
         leaves.push(HeaderLeaf::<N>::new(2, self.coinbase_accumulator_point).to_bits_le());
-        for i in 3..7 {
+        leaves.push(HeaderLeaf::<N>::new(3, self.finalize_root).to_bits_le());
+        for i in 4..7 {
             leaves.push(HeaderLeaf::<N>::new(i, Field::zero()).to_bits_le());
         }
         leaves.push(HeaderLeaf::<N>::new(7, self.metadata.to_hash()?).to_bits_le());
@@ -108,6 +113,7 @@ mod tests {
                 Field::rand(rng),
                 Field::rand(rng),
                 Field::rand(rng),
+                Field::rand(rng),
                 Metadata::new(
                     CurrentNetwork::ID,
                     u64::rand(rng),
@@ -138,6 +144,11 @@ mod tests {
             assert_eq!(leaf.index(), 2);
             check_path(header.to_path(&leaf)?, root, &leaf)?;
 
+            // Check the 3rd leaf.
+            let leaf = header.to_leaf(&header.finalize_root())?;
+            assert_eq!(leaf.index(), 3);
+            check_path(header.to_path(&leaf)?, root, &leaf)?;
+
             // Check the 7th leaf.
             let leaf = header.to_leaf(&CurrentNetwork::hash_bhp512(&header.metadata().to_bits_le())?)?;
             assert_eq!(leaf.index(), 7);
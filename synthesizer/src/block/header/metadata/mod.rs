@@ -18,6 +18,7 @@ mod bytes;
 mod genesis;
 mod serialize;
 mod string;
+mod targets;
 mod to_bits;
 mod to_hash;
 
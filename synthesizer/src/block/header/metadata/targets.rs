@@ -0,0 +1,141 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The number of fractional bits in the fixed-point exponent of the retargeting.
+const RBITS: u32 = 16;
+
+impl<N: Network> Metadata<N> {
+    /// Returns the coinbase target of the next block, with the given timestamp.
+    ///
+    /// The coinbase target is retargeted from the last coinbase target, by the drift of the time elapsed
+    /// since the last coinbase from `N::ANCHOR_TIME`. The target halves for every half-life of positive drift,
+    /// and doubles for every half-life of negative drift, where the half-life is half an epoch of anchor times.
+    /// The coinbase target is at least `N::GENESIS_COINBASE_TARGET`.
+    pub fn next_coinbase_target(&self, next_timestamp: i64) -> u64 {
+        // Compute the half-life (in seconds).
+        let half_life = i128::from(N::NUM_BLOCKS_PER_EPOCH / 2) * i128::from(N::ANCHOR_TIME);
+        // Compute the drift (in seconds), where the elapsed time is at least one second.
+        let elapsed = next_timestamp.saturating_sub(self.last_coinbase_timestamp).max(1);
+        let drift = i128::from(elapsed) - i128::from(N::ANCHOR_TIME);
+
+        // Compute the exponent `-drift / half_life` in fixed point, and split it into its integral and fractional parts.
+        let exponent = (-drift << RBITS).div_euclid(half_life);
+        let integral = exponent >> RBITS;
+        let fractional = (exponent & ((1 << RBITS) - 1)) as u128;
+        // Approximate `2^fractional` in fixed point, with the cubic polynomial of the aserti3-2d algorithm.
+        let factor = (1u128 << RBITS)
+            + ((195_766_423_245_049 * fractional
+                + 971_821_376 * fractional.pow(2)
+                + 5_127 * fractional.pow(3)
+                + (1 << 47))
+                >> 48);
+
+        // Compute `last_coinbase_target * 2^exponent`, saturating on overflow.
+        let target = u128::from(self.last_coinbase_target) * factor;
+        let shift = integral - i128::from(RBITS);
+        let target = match shift < 0 {
+            true => u32::try_from(-shift).ok().and_then(|shift| target.checked_shr(shift)).unwrap_or(0),
+            false => match u32::try_from(shift) {
+                Ok(shift) if shift < target.leading_zeros() => target << shift,
+                _ => u128::MAX,
+            },
+        };
+        // Clamp the target between `N::GENESIS_COINBASE_TARGET` and `u64::MAX`.
+        u64::try_from(target).unwrap_or(u64::MAX).max(N::GENESIS_COINBASE_TARGET)
+    }
+
+    /// Returns the proof target for the given coinbase target.
+    /// The proof target is at least `N::GENESIS_PROOF_TARGET`, and less than the coinbase target.
+    pub fn to_proof_target(coinbase_target: u64) -> u64 {
+        (coinbase_target >> 7).saturating_add(1).max(N::GENESIS_PROOF_TARGET)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns metadata with the given last coinbase target, and a last coinbase at the genesis timestamp.
+    fn sample_metadata(last_coinbase_target: u64) -> Metadata<CurrentNetwork> {
+        Metadata::new(
+            CurrentNetwork::ID,
+            1,
+            1,
+            last_coinbase_target,
+            Metadata::<CurrentNetwork>::to_proof_target(last_coinbase_target),
+            last_coinbase_target,
+            CurrentNetwork::GENESIS_TIMESTAMP,
+            CurrentNetwork::GENESIS_TIMESTAMP + 1,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_next_coinbase_target() {
+        let target = 1u64 << 40;
+        let metadata = sample_metadata(target);
+        let anchor_time = i64::from(CurrentNetwork::ANCHOR_TIME);
+        let half_life = i64::from(CurrentNetwork::NUM_BLOCKS_PER_EPOCH / 2) * anchor_time;
+        let timestamp = |elapsed: i64| CurrentNetwork::GENESIS_TIMESTAMP + elapsed;
+
+        // Ensure the target is unchanged when the coinbase arrives on time.
+        assert_eq!(metadata.next_coinbase_target(timestamp(anchor_time)), target);
+        // Ensure the target halves, or doubles, for every half-life of drift.
+        assert_eq!(metadata.next_coinbase_target(timestamp(anchor_time + half_life)), target / 2);
+        assert_eq!(metadata.next_coinbase_target(timestamp(anchor_time + 2 * half_life)), target / 4);
+        // Note: The elapsed time is at least one second, so a negative drift is at most the anchor time.
+        let target_if_fast = metadata.next_coinbase_target(timestamp(1));
+        assert_eq!(metadata.next_coinbase_target(timestamp(-half_life)), target_if_fast);
+        assert!(target_if_fast > target && target_if_fast < 2 * target);
+
+        // Ensure the target decreases monotonically with the elapsed time.
+        let mut previous = u64::MAX;
+        for elapsed in (1..4 * half_life).step_by(97) {
+            let next = metadata.next_coinbase_target(timestamp(elapsed));
+            assert!(next <= previous, "The target increased at an elapsed time of {elapsed} seconds");
+            previous = next;
+        }
+    }
+
+    #[test]
+    fn test_next_coinbase_target_bounds() {
+        let anchor_time = i64::from(CurrentNetwork::ANCHOR_TIME);
+
+        // Ensure the target is at least the genesis coinbase target.
+        let metadata = sample_metadata(CurrentNetwork::GENESIS_COINBASE_TARGET);
+        assert_eq!(metadata.next_coinbase_target(i64::MAX), CurrentNetwork::GENESIS_COINBASE_TARGET);
+        // Ensure the target saturates at `u64::MAX`.
+        let metadata = sample_metadata(u64::MAX);
+        assert_eq!(metadata.next_coinbase_target(CurrentNetwork::GENESIS_TIMESTAMP + 1), u64::MAX);
+        assert_eq!(metadata.next_coinbase_target(CurrentNetwork::GENESIS_TIMESTAMP + anchor_time), u64::MAX);
+    }
+
+    #[test]
+    fn test_to_proof_target() {
+        // Ensure the genesis targets are consistent.
+        let proof_target = Metadata::<CurrentNetwork>::to_proof_target(CurrentNetwork::GENESIS_COINBASE_TARGET);
+        assert_eq!(proof_target, CurrentNetwork::GENESIS_PROOF_TARGET);
+        // Ensure the proof target is less than the coinbase target.
+        for coinbase_target in [CurrentNetwork::GENESIS_COINBASE_TARGET, 1 << 20, 1 << 40, u64::MAX] {
+            assert!(Metadata::<CurrentNetwork>::to_proof_target(coinbase_target) < coinbase_target);
+        }
+    }
+}
@@ -109,6 +109,22 @@ impl<N: Network> Block<N> {
         // Construct the block.
         Ok(Self { block_hash: block_hash.into(), previous_hash, header, transactions, coinbase, signature })
     }
+
+    /// Ensures the block hash is computed from the previous block hash and the block header,
+    /// and the block signature is valid for the block hash.
+    pub fn check_hash_and_signature(&self) -> Result<()> {
+        // Ensure the block hash is correct.
+        let block_hash =
+            N::hash_bhp1024(&[self.previous_hash.to_bits_le(), self.header.to_root()?.to_bits_le()].concat())?;
+        ensure!(*self.block_hash == block_hash, "Incorrect block hash for block {}", self.height());
+        // Ensure the block signature is valid.
+        ensure!(
+            self.signature.verify(&self.signature.to_address(), &[block_hash]),
+            "Invalid signature for block {}",
+            self.height()
+        );
+        Ok(())
+    }
 }
 
 impl<N: Network> Block<N> {
@@ -432,9 +448,30 @@ pub(crate) mod test_helpers {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::vm::test_helpers::CurrentNetwork;
 
     use indexmap::IndexMap;
 
+    #[test]
+    fn test_check_hash_and_signature() {
+        let rng = &mut TestRng::default();
+
+        let (block, _) = crate::block::test_helpers::sample_block_and_transaction(rng);
+        // Ensure the block hash and signature are valid.
+        assert!(block.check_hash_and_signature().is_ok());
+
+        // Ensure a block with a different previous hash is rejected.
+        let mut candidate = block.clone();
+        candidate.previous_hash = Field::<CurrentNetwork>::one().into();
+        assert!(candidate.check_hash_and_signature().is_err());
+
+        // Ensure a block with a signature for a different block hash is rejected.
+        let private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let mut candidate = block.clone();
+        candidate.signature = private_key.sign(&[Field::one()], rng).unwrap();
+        assert!(candidate.check_hash_and_signature().is_err());
+    }
+
     #[test]
     fn test_find_transaction_for_transition_id() {
         let rng = &mut TestRng::default();
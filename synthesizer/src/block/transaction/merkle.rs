@@ -35,6 +35,15 @@ impl<N: Network> Transaction<N> {
                     ));
                 }
 
+                // Check if the ID is the hash of the edition and owner of the deployment.
+                if *id == deployment.to_owner_id()? {
+                    // Return the transaction leaf.
+                    return Ok(TransactionLeaf::new_deployment(
+                        deployment.program().functions().len() as u16 + 1, // The index after the fee.
+                        *id,
+                    ));
+                }
+
                 // Iterate through the functions in the deployment.
                 for (index, function) in deployment.program().functions().values().enumerate() {
                     // Check if the function hash matches the given ID.
@@ -90,7 +99,8 @@ impl<N: Network> Transaction<N> {
 }
 
 impl<N: Network> Transaction<N> {
-    /// Returns the Merkle tree for the given deployment.
+    /// Returns the Merkle tree for the given deployment, whose leaves are the function hashes, the fee transition ID,
+    /// and the hash of the edition and owner of the deployment.
     pub(super) fn deployment_tree(deployment: &Deployment<N>, fee: &Fee<N>) -> Result<TransactionTree<N>> {
         // Ensure the number of leaves is within the Merkle tree size.
         Self::check_deployment_size(deployment)?;
@@ -116,6 +126,14 @@ impl<N: Network> Transaction<N> {
                 )
                 .to_bits_le())]
                 .into_iter(),
+            )
+            .chain(
+                [Ok(TransactionLeaf::new_deployment(
+                    program.functions().len() as u16 + 1, // The index after the fee.
+                    deployment.to_owner_id()?,
+                )
+                .to_bits_le())]
+                .into_iter(),
             );
         // Compute the deployment tree.
         N::merkle_tree_bhp::<TRANSACTION_DEPTH>(&leaves.collect::<Result<Vec<_>>>()?)
@@ -167,9 +185,9 @@ impl<N: Network> Transaction<N> {
         );
         // Ensure the number of functions is within the allowed range.
         ensure!(
-            functions.len() < Self::MAX_TRANSITIONS, // Note: Observe we hold back 1 for the additional fee.
+            functions.len() < Self::MAX_TRANSITIONS - 1, // Note: Observe we hold back 2 for the fee and the owner.
             "Deployment must contain less than {} functions, found {}",
-            Self::MAX_TRANSITIONS,
+            Self::MAX_TRANSITIONS - 1,
             functions.len()
         );
         Ok(())
@@ -187,3 +205,45 @@ impl<N: Network> Transaction<N> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_deployment_tree_commits_to_owner() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Retrieve a deployment and its fee.
+        let (deployment, fee) = match crate::vm::test_helpers::sample_deployment_transaction(rng) {
+            Transaction::Deploy(_, deployment, fee) => (*deployment, fee),
+            _ => unreachable!("The sample transaction is a deployment"),
+        };
+
+        // Sign the deployment.
+        let owner_private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let signed = deployment.clone().sign(&owner_private_key, rng)?;
+        let transaction = Transaction::from_deployment(signed.clone(), fee.clone())?;
+
+        // Ensure the owner leaf is in the transaction tree.
+        let leaf = transaction.to_leaf(&signed.to_owner_id()?)?;
+        assert_eq!(leaf.index() as usize, signed.program().functions().len() + 1);
+        let path = transaction.to_path(&leaf)?;
+        assert!(CurrentNetwork::verify_merkle_path_bhp(&path, &transaction.to_root()?, &leaf.to_bits_le()));
+
+        // Ensure a deployment that is re-signed by another owner, or by the same owner, has a different ID.
+        let other_private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        for resigned in [deployment.clone().sign(&other_private_key, rng)?, deployment.sign(&owner_private_key, rng)?] {
+            let resigned_transaction = Transaction::from_deployment(resigned.clone(), fee.clone())?;
+            assert_ne!(transaction.id(), resigned_transaction.id());
+
+            // Ensure the re-signed deployment fails to load under the original transaction ID.
+            let tampered = Transaction::Deploy(transaction.id(), Box::new(resigned), fee.clone());
+            assert!(Transaction::<CurrentNetwork>::read_le(&tampered.to_bytes_le()?[..]).is_err());
+        }
+        Ok(())
+    }
+}
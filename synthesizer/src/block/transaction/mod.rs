@@ -88,8 +88,25 @@ impl<N: Network> Transaction<N> {
         query: Option<Query<N, C::BlockStorage>>,
         rng: &mut R,
     ) -> Result<Self> {
-        // Compute the deployment.
-        let deployment = vm.deploy(program, rng)?;
+        // Compute the deployment, signed by the owner.
+        let deployment = vm.deploy(program, rng)?.sign(private_key, rng)?;
+        // Compute the fee.
+        let (_, fee, _) = vm.execute_fee(private_key, credits, fee_in_gates, query, rng)?;
+        // Initialize the transaction.
+        Self::from_deployment(deployment, fee)
+    }
+
+    /// Initializes a new deployment transaction for the next edition of an existing program.
+    pub fn upgrade<C: ConsensusStorage<N>, R: Rng + CryptoRng>(
+        vm: &VM<N, C>,
+        private_key: &PrivateKey<N>,
+        program: &Program<N>,
+        (credits, fee_in_gates): (Record<N, Plaintext<N>>, u64),
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Compute the deployment, signed by the owner.
+        let deployment = vm.upgrade(program, rng)?.sign(private_key, rng)?;
         // Compute the fee.
         let (_, fee, _) = vm.execute_fee(private_key, credits, fee_in_gates, query, rng)?;
         // Initialize the transaction.
@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Process<N> {
+    /// Returns the cost (in gates) of the given deployment, including the cost of its fee, if it is given.
+    /// Note: The size of a fee does not depend on its amount, so the cost of a fee may be computed
+    /// from a fee of any amount, before computing the fee that covers the total cost.
+    #[inline]
+    pub fn deployment_cost(&self, deployment: &Deployment<N>, fee: Option<&Fee<N>>) -> Result<u64> {
+        // Compute the byte cost of the deployment.
+        let cost = byte_cost::<N>(deployment.to_bytes_le()?.len())?;
+        // Add the cost of the fee.
+        self.add_fee_cost(cost, fee)
+    }
+
+    /// Returns the cost (in gates) of the given execution, including the cost of its additional fee, if it is given.
+    /// Note: This does not include the storage cost of the mapping writes, which is metered in finalize.
+    #[inline]
+    pub fn execution_cost(&self, execution: &Execution<N>, additional_fee: Option<&Fee<N>>) -> Result<u64> {
+        // Compute the byte cost of the execution.
+        let cost = byte_cost::<N>(execution.to_bytes_le()?.len())?;
+        // Add the cost of each transition.
+        let cost = execution.transitions().try_fold(cost, |cost, transition| {
+            // Retrieve the stack.
+            let stack = self.get_stack(transition.program_id())?;
+            // Compute the cost of the function.
+            let function_cost = stack.get_function_cost(transition.function_name())?;
+            cost.checked_add(function_cost).ok_or_else(|| anyhow!("The cost of the execution overflowed"))
+        })?;
+        // Add the cost of the additional fee.
+        self.add_fee_cost(cost, additional_fee)
+    }
+
+    /// Returns the cost (in gates) of executing the given authorization.
+    /// Note: This does not include the byte cost of the execution, which is only known once the execution is
+    /// proven, nor the storage cost of the mapping writes, which is metered in finalize.
+    #[inline]
+    pub fn authorization_cost(&self, authorization: &Authorization<N>) -> Result<u64> {
+        authorization.to_vec_deque().iter().try_fold(0u64, |cost, request| {
+            // Retrieve the stack.
+            let stack = self.get_stack(request.program_id())?;
+            // Compute the cost of the function.
+            let function_cost = stack.get_function_cost(request.function_name())?;
+            cost.checked_add(function_cost).ok_or_else(|| anyhow!("The cost of the authorization overflowed"))
+        })
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Returns the given cost, plus the byte cost and the function cost of the given fee, if it exists.
+    fn add_fee_cost(&self, cost: u64, fee: Option<&Fee<N>>) -> Result<u64> {
+        // If there is no fee, return the given cost.
+        let fee = match fee {
+            Some(fee) => fee,
+            None => return Ok(cost),
+        };
+        // Retrieve the fee transition.
+        let transition = fee.transition();
+        // Compute the cost of the fee function.
+        let function_cost = self.get_stack(transition.program_id())?.get_function_cost(transition.function_name())?;
+        // Compute the cost of the fee.
+        byte_cost::<N>(fee.to_bytes_le()?.len())?
+            .checked_add(function_cost)
+            .and_then(|fee_cost| cost.checked_add(fee_cost))
+            .ok_or_else(|| anyhow!("The cost of the fee overflowed"))
+    }
+}
+
+/// Returns the cost (in gates) of the given number of bytes in a transaction.
+fn byte_cost<N: Network>(num_bytes: usize) -> Result<u64> {
+    (num_bytes as u64).checked_mul(N::TRANSACTION_BYTE_COST).ok_or_else(|| anyhow!("The byte cost overflowed"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::process::test_helpers::{sample_execution, sample_process};
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_execution_cost() {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    output r2 as u32.public;",
+        )
+        .unwrap();
+
+        // Construct the process.
+        let process = sample_process(&program);
+        // Fetch a sample execution of `compute`.
+        let execution = sample_execution();
+
+        // Compute the byte cost of the execution.
+        let byte_cost = execution.to_bytes_le().unwrap().len() as u64 * CurrentNetwork::TRANSACTION_BYTE_COST;
+        // Ensure the cost is the byte cost, plus the cost of the `add` instruction.
+        assert_eq!(process.execution_cost(&execution, None).unwrap(), byte_cost + 1);
+    }
+}
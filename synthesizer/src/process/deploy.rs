@@ -39,7 +39,30 @@ impl<N: Network> Process<N> {
         deployment
     }
 
+    /// Deploys the next edition of the given program ID, if it exists.
+    #[inline]
+    pub fn upgrade<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        program: &Program<N>,
+        rng: &mut R,
+    ) -> Result<Deployment<N>> {
+        let timer = timer!("Process::upgrade");
+
+        // Compute the stack.
+        let stack = Stack::new_upgrade(self, program)?;
+        lap!(timer, "Compute the stack");
+
+        // Return the deployment.
+        let deployment = stack.deploy::<A, R>(rng);
+        lap!(timer, "Construct the deployment");
+
+        finish!(timer);
+
+        deployment
+    }
+
     /// Verifies the given deployment is well-formed.
+    /// To check the deployment against storage, use `check_deployment_storage`.
     #[inline]
     pub fn verify_deployment<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         &self,
@@ -47,12 +70,8 @@ impl<N: Network> Process<N> {
         rng: &mut R,
     ) -> Result<()> {
         let timer = timer!("Process::verify_deployment");
-        // Retrieve the program ID.
-        let program_id = deployment.program().id();
-        // Ensure the program does not already exist in the process.
-        ensure!(!self.contains_program(program_id), "Program '{program_id}' already exists");
         // Ensure the program is well-formed, by computing the stack.
-        let stack = Stack::new(self, deployment.program())?;
+        let stack = self.compute_deployment_stack(deployment)?;
         lap!(timer, "Compute the stack");
 
         // Ensure the verifying keys are well-formed and the certificates are valid.
@@ -66,6 +85,10 @@ impl<N: Network> Process<N> {
 
     /// Finalizes the deployment.
     /// This method assumes the given deployment **is valid**.
+    ///
+    /// The deployment is finalized atomically: if any step fails, the process stacks
+    /// and the program store are restored to their state before this call.
+    /// If the process is in an atomic batch, the stack is staged until the batch finishes.
     #[inline]
     pub fn finalize_deployment<P: ProgramStorage<N>>(
         &mut self,
@@ -74,11 +97,12 @@ impl<N: Network> Process<N> {
     ) -> Result<()> {
         let timer = timer!("Process::finalize_deployment");
 
-        // TODO (howardwu): Make this function atomic.
-        // TODO (howardwu): Check the program ID and all mappings don't exist in the 'store'. (add this to verify_deployment too)
+        // Ensure the program ID and mappings are consistent with the store.
+        self.check_deployment_storage(store, deployment)?;
+        lap!(timer, "Check the program store");
 
         // Compute the program stack.
-        let stack = Stack::new(self, deployment.program())?;
+        let stack = self.compute_deployment_stack(deployment)?;
         lap!(timer, "Compute the stack");
 
         // Insert the verifying keys.
@@ -87,20 +111,54 @@ impl<N: Network> Process<N> {
         }
         lap!(timer, "Insert the verifying keys");
 
-        // Retrieve the program ID.
-        let program_id = deployment.program_id();
-        // Iterate through the program mappings.
-        for mapping in deployment.program().mappings().values() {
-            store.initialize_mapping(program_id, mapping.name())?;
+        // Determine if the store and the process are already part of an atomic batch.
+        let is_store_part_of_atomic_batch = store.is_atomic_in_progress();
+        let is_process_part_of_atomic_batch = self.is_atomic_in_progress();
+        // Checkpoint the ongoing atomic batches, or start new ones.
+        match is_store_part_of_atomic_batch {
+            true => store.atomic_checkpoint(),
+            false => store.start_atomic(),
+        }
+        match is_process_part_of_atomic_batch {
+            true => self.atomic_checkpoint(),
+            false => self.start_atomic(),
         }
-        lap!(timer, "Initialize the program mappings");
 
-        // Add the stack to the process.
-        self.stacks.insert(*deployment.program_id(), stack);
+        // Initialize the program mappings, and stage the stack in the process.
+        let result =
+            self.apply_deployment(store, deployment, stack).and_then(|()| match is_store_part_of_atomic_batch {
+                // Keep the writes in the ongoing atomic batch.
+                true => {
+                    store.clear_latest_checkpoint();
+                    Ok(())
+                }
+                // Commit the atomic batch.
+                false => store.finish_atomic(),
+            });
+        lap!(timer, "Apply the deployment");
+
+        match result.is_ok() {
+            // Keep the staged stack in the ongoing atomic batch, or add it to the process.
+            true => match is_process_part_of_atomic_batch {
+                true => self.clear_latest_checkpoint(),
+                false => self.finish_atomic(),
+            },
+            // If finalize failed, restore the program store and discard the staged stack.
+            false => {
+                match is_store_part_of_atomic_batch {
+                    true => store.atomic_rewind(),
+                    false => store.abort_atomic(),
+                }
+                match is_process_part_of_atomic_batch {
+                    true => self.atomic_rewind(),
+                    false => self.abort_atomic(),
+                }
+            }
+        }
 
         finish!(timer);
 
-        Ok(())
+        result
     }
 
     /// Adds the newly-deployed program.
@@ -110,7 +168,7 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::load_deployment");
 
         // Compute the program stack.
-        let stack = Stack::new(self, deployment.program())?;
+        let stack = self.compute_deployment_stack(deployment)?;
         lap!(timer, "Compute the stack");
 
         // Insert the verifying keys.
@@ -120,7 +178,7 @@ impl<N: Network> Process<N> {
         lap!(timer, "Insert the verifying keys");
 
         // Add the stack to the process.
-        self.stacks.insert(*deployment.program_id(), stack);
+        self.insert_stack(stack)?;
 
         finish!(timer);
 
@@ -128,11 +186,131 @@ impl<N: Network> Process<N> {
     }
 }
 
+impl<N: Network> Process<N> {
+    /// Ensures the program ID and mappings of the given deployment are consistent with the given store.
+    /// A new program must not exist in storage, and an upgrade must only add new mappings to storage.
+    #[inline]
+    pub fn check_deployment_storage<P: ProgramStorage<N>>(
+        &self,
+        store: &ProgramStore<N, P>,
+        deployment: &Deployment<N>,
+    ) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = deployment.program_id();
+
+        match self.contains_program(program_id) {
+            // Ensure the program ID and its mappings do not exist in storage.
+            false => {
+                ensure!(!store.contains_program(program_id)?, "Program '{program_id}' already exists in storage");
+                for mapping_name in deployment.program().mappings().keys() {
+                    if store.contains_mapping(program_id, mapping_name)? {
+                        bail!("Mapping '{program_id}/{mapping_name}' already exists in storage")
+                    }
+                }
+            }
+            // Ensure the existing mappings exist in storage, and the new mappings do not.
+            true => {
+                // Retrieve the existing program.
+                let existing = self.get_program(program_id)?;
+                for mapping_name in deployment.program().mappings().keys() {
+                    match (existing.contains_mapping(mapping_name), store.contains_mapping(program_id, mapping_name)?) {
+                        (true, false) => bail!("Mapping '{program_id}/{mapping_name}' does not exist in storage"),
+                        (false, true) => bail!("Mapping '{program_id}/{mapping_name}' already exists in storage"),
+                        _ => (),
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Initializes the new mappings of the given deployment, and adds the given stack to the process.
+    #[inline]
+    fn apply_deployment<P: ProgramStorage<N>>(
+        &mut self,
+        store: &ProgramStore<N, P>,
+        deployment: &Deployment<N>,
+        stack: Stack<N>,
+    ) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = deployment.program_id();
+        // Iterate through the program mappings.
+        for mapping in deployment.program().mappings().values() {
+            // Initialize the mapping, if it is new. The mappings of an upgraded program are preserved.
+            if !store.contains_mapping(program_id, mapping.name())? {
+                store.initialize_mapping(program_id, mapping.name())?;
+            }
+        }
+        // Add the stack to the process.
+        self.insert_stack(stack)
+    }
+
+    /// Computes the stack for the given deployment, as a new program or the next edition of an existing one.
+    /// The owner recorded for the first edition is the only one authorized to deploy the later editions.
+    #[inline]
+    fn compute_deployment_stack(&self, deployment: &Deployment<N>) -> Result<Stack<N>> {
+        // Retrieve the program ID.
+        let program_id = deployment.program_id();
+        // Ensure the owner signature, if present, is valid.
+        deployment.check_owner()?;
+        // If this is a new program, record the owner of the deployment.
+        if !self.contains_program(program_id) {
+            return Ok(Stack::new(self, deployment.program())?.with_owner(deployment.owner_address()));
+        }
+        // Ensure the upgrade is signed by the owner of the program.
+        match (self.get_stack(program_id)?.owner(), deployment.owner_address()) {
+            (None, _) => bail!("Cannot upgrade program '{program_id}' because it has no owner"),
+            (Some(owner), Some(signer)) if *owner == signer => Stack::new_upgrade(self, deployment.program()),
+            (Some(_), _) => bail!("Cannot upgrade program '{program_id}' because only its owner may upgrade it"),
+        }
+    }
+
+    /// Inserts the given stack into the process.
+    /// If the stack upgrades an existing program, the programs that import it are reloaded.
+    #[inline]
+    fn insert_stack(&mut self, stack: Stack<N>) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = *stack.program_id();
+        // Determine if the stack upgrades an existing program.
+        let is_upgrade = self.contains_program(&program_id);
+        // Add the stack to the process.
+        self.stage_stack(program_id, stack);
+        // If this is a new program, return early.
+        if !is_upgrade {
+            return Ok(());
+        }
+
+        // Retrieve the program IDs, which are ordered after their imports.
+        let program_ids = self.program_ids().copied().collect::<Vec<_>>();
+        // Retrieve the index of the upgraded program.
+        let index = match program_ids.iter().position(|id| id == &program_id) {
+            Some(index) => index,
+            None => bail!("Failed to retrieve the index of program '{program_id}'"),
+        };
+
+        // Track the program IDs that have changed.
+        let mut changed = vec![program_id];
+        // Reload the dependent stacks.
+        for dependent_id in program_ids.into_iter().skip(index + 1) {
+            // Retrieve the stack.
+            let dependent = self.get_stack(dependent_id)?;
+            // Reload the stack, if it imports a program that has changed.
+            if dependent.program().imports().keys().any(|import| changed.contains(import)) {
+                let reloaded = dependent.reload(self)?;
+                self.stage_stack(dependent_id, reloaded);
+                changed.push(dependent_id);
+            }
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use snarkvm_utilities::TestRng;
 
+    type CurrentNetwork = console::network::Testnet3;
     type CurrentAleo = circuit::network::AleoV0;
 
     #[test]
@@ -156,4 +334,92 @@ mod tests {
         // Ensure the program exists.
         assert!(process.contains_program(program.id()));
     }
+
+    #[test]
+    fn test_finalize_deployment_with_existing_storage() {
+        let rng = &mut TestRng::default();
+
+        // Fetch the program from the deployment.
+        let program = crate::vm::test_helpers::sample_program();
+        // Initialize a new process.
+        let mut process = Process::load().unwrap();
+        // Deploy the program.
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+
+        // Initialize a new VM.
+        let vm = crate::vm::test_helpers::sample_vm();
+        // Initialize a stale mapping for the program ID in storage.
+        let mapping_name = Identifier::from_str("stale").unwrap();
+        vm.program_store().initialize_mapping(program.id(), &mapping_name).unwrap();
+
+        // Ensure the deployment is rejected.
+        assert!(process.check_deployment_storage(vm.program_store(), &deployment).is_err());
+        assert!(process.finalize_deployment(vm.program_store(), &deployment).is_err());
+        // Ensure the program does not exist, and the store is unchanged.
+        assert!(!process.contains_program(program.id()));
+        assert!(!vm.program_store().is_atomic_in_progress());
+        assert!(vm.program_store().contains_mapping(program.id(), &mapping_name).unwrap());
+    }
+
+    #[test]
+    fn test_finalize_deployment_in_atomic_batch() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new program with a mapping.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+mapping counts:
+    key owner as address.public;
+    value amount as u64.public;
+
+function bump:
+    input r0 as address.public;
+    finalize r0;
+
+finalize bump:
+    input r0 as address.public;
+    increment counts[r0] by 1u64;
+",
+        )
+        .unwrap();
+        let mapping_name = Identifier::from_str("counts").unwrap();
+        // Initialize a new process.
+        let mut process = Process::load().unwrap();
+        // Deploy the program.
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+
+        // Initialize a new VM.
+        let vm = crate::vm::test_helpers::sample_vm();
+        let store = vm.program_store();
+
+        // Finalize the deployment in an atomic batch.
+        store.start_atomic();
+        process.start_atomic();
+        process.finalize_deployment(store, &deployment).unwrap();
+        // Ensure the program is staged in the process.
+        assert!(process.contains_program(program.id()));
+        assert!(process.is_atomic_in_progress());
+        assert!(store.is_atomic_in_progress());
+
+        // Abort the atomic batch.
+        store.abort_atomic();
+        process.abort_atomic();
+        // Ensure the program does not exist in the process or the store.
+        assert!(!process.contains_program(program.id()));
+        assert!(!store.contains_program(program.id()).unwrap());
+        assert!(!store.contains_mapping(program.id(), &mapping_name).unwrap());
+
+        // Finalize the deployment in an atomic batch, and finish it.
+        store.start_atomic();
+        process.start_atomic();
+        process.finalize_deployment(store, &deployment).unwrap();
+        store.finish_atomic().unwrap();
+        process.finish_atomic();
+        // Ensure the program exists in the process and the store.
+        assert!(process.contains_program(program.id()));
+        assert!(store.contains_program(program.id()).unwrap());
+        assert!(store.contains_mapping(program.id(), &mapping_name).unwrap());
+    }
 }
@@ -20,6 +20,16 @@ impl<N: Network> Process<N> {
     /// Evaluates a program function on the given request.
     #[inline]
     pub fn evaluate<A: circuit::Aleo<Network = N>>(&self, authorization: Authorization<N>) -> Result<Response<N>> {
+        self.evaluate_with_finalize::<A>(authorization).map(|(response, _)| response)
+    }
+
+    /// Evaluates a program function on the given request, and returns the response along with
+    /// the inputs for finalize of each function call, as `(program ID, function name, inputs)`.
+    #[inline]
+    pub fn evaluate_with_finalize<A: circuit::Aleo<Network = N>>(
+        &self,
+        authorization: Authorization<N>,
+    ) -> Result<(Response<N>, Vec<(ProgramID<N>, Identifier<N>, Vec<Value<N>>)>)> {
         let timer = timer!("Process::evaluate");
 
         // Retrieve the main request (without popping it).
@@ -28,13 +38,21 @@ impl<N: Network> Process<N> {
         #[cfg(feature = "aleo-cli")]
         println!("{}", format!(" • Evaluating '{}/{}'...", request.program_id(), request.function_name()).dimmed());
 
+        // Initialize the inputs for finalize.
+        let finalize_inputs = FinalizeInputs::<N>::default();
+        // Initialize the call stack.
+        let call_stack = CallStack::Evaluate(authorization, finalize_inputs.clone());
         // Evaluate the function.
-        let response =
-            self.get_stack(request.program_id())?.evaluate_function::<A>(CallStack::evaluate(authorization)?);
+        let response = self.get_stack(request.program_id())?.evaluate_function::<A>(call_stack)?;
         lap!(timer, "Evaluate the function");
+        // Extract the inputs for finalize.
+        let finalize_inputs = match Arc::try_unwrap(finalize_inputs) {
+            Ok(finalize_inputs) => finalize_inputs.into_inner(),
+            Err(_) => bail!("The inputs for finalize are still in use after evaluating the function"),
+        };
 
         finish!(timer);
 
-        response
+        Ok((response, finalize_inputs))
     }
 }
@@ -61,6 +61,18 @@ impl<N: Network> Process<N> {
     /// Note: This does *not* check that the global state root exists in the ledger.
     #[inline]
     pub fn verify_execution<const VERIFY_INCLUSION: bool>(&self, execution: &Execution<N>) -> Result<()> {
+        self.verify_execution_at_editions::<VERIFY_INCLUSION>(execution, &IndexMap::new())
+    }
+
+    /// Verifies the given execution is valid, against the given editions of its programs.
+    /// The programs that are not in the given editions are verified against their current edition.
+    /// Note: This does *not* check that the global state root exists in the ledger.
+    #[inline]
+    pub fn verify_execution_at_editions<const VERIFY_INCLUSION: bool>(
+        &self,
+        execution: &Execution<N>,
+        editions: &IndexMap<ProgramID<N>, u16>,
+    ) -> Result<()> {
         let timer = timer!("Process::verify_execution");
 
         // Ensure the execution contains transitions.
@@ -71,7 +83,7 @@ impl<N: Network> Process<N> {
             // Retrieve the transition (without popping it).
             let transition = execution.peek()?;
             // Retrieve the stack.
-            let stack = self.get_stack(transition.program_id())?;
+            let stack = self.get_stack_at_edition(transition.program_id(), editions)?;
             // Ensure the number of calls matches the number of transitions.
             let number_of_calls = stack.get_number_of_calls(transition.function_name())?;
             ensure!(
@@ -152,7 +164,7 @@ impl<N: Network> Process<N> {
             inputs.extend(transition.inputs().iter().flat_map(|input| input.verifier_inputs()));
 
             // Retrieve the stack.
-            let stack = self.get_stack(transition.program_id())?;
+            let stack = self.get_stack_at_edition(transition.program_id(), editions)?;
             // Retrieve the function from the stack.
             let function = stack.get_function(transition.function_name())?;
             // Determine the number of function calls in this function.
@@ -217,7 +229,7 @@ impl<N: Network> Process<N> {
             println!("Transition public inputs ({} elements): {:#?}", inputs.len(), inputs);
 
             // Retrieve the verifying key.
-            let verifying_key = self.get_verifying_key(stack.program_id(), function.name())?;
+            let verifying_key = stack.get_verifying_key(function.name())?;
             // Ensure the transition proof is valid.
             ensure!(
                 verifying_key.verify(function.name(), &inputs, transition.proof()),
@@ -231,14 +243,29 @@ impl<N: Network> Process<N> {
         Ok(())
     }
 
-    /// Finalizes the execution.
+    /// Returns the stack of the given program, at its edition in the given editions, or at its current edition.
+    #[inline]
+    fn get_stack_at_edition(
+        &self,
+        program_id: &ProgramID<N>,
+        editions: &IndexMap<ProgramID<N>, u16>,
+    ) -> Result<&Stack<N>> {
+        let stack = self.get_stack(program_id)?;
+        match editions.get(program_id) {
+            Some(edition) => stack.get_stack_for_edition(*edition),
+            None => Ok(stack),
+        }
+    }
+
+    /// Finalizes the execution, and returns the storage cost (in gates) of the mapping writes.
     /// This method assumes the given execution **is valid**.
     #[inline]
     pub fn finalize_execution<P: ProgramStorage<N>>(
         &self,
+        state: FinalizeGlobalState,
         store: &ProgramStore<N, P>,
         execution: &Execution<N>,
-    ) -> Result<()> {
+    ) -> Result<u64> {
         let timer = timer!("Program::finalize_execution");
 
         // Ensure the execution contains transitions.
@@ -260,6 +287,9 @@ impl<N: Network> Process<N> {
         }
         lap!(timer, "Verify the number of transitions");
 
+        // Initialize the storage cost.
+        let mut storage_cost = 0u64;
+
         // TODO (howardwu): This is a temporary approach. We should create a "CallStack" and recurse through the stack.
         //  Currently this loop assumes a linearly execution stack.
         // Finalize each transition, starting from the last one.
@@ -273,50 +303,143 @@ impl<N: Network> Process<N> {
             let function_name = transition.function_name();
 
             // If there is a finalize scope, finalize the function.
-            if let Some((_, finalize)) = stack.get_function(function_name)?.finalize() {
+            if stack.get_function(function_name)?.finalize().is_some() {
                 // Retrieve the finalize inputs.
                 let inputs = match transition.finalize() {
                     Some(inputs) => inputs,
                     // Ensure the transition contains finalize inputs.
                     None => bail!("The transition is missing inputs for 'finalize'"),
                 };
+                // Finalize the function, and meter the storage cost.
+                storage_cost = storage_cost
+                    .checked_add(finalize_function(state, store, stack, function_name, inputs)?)
+                    .ok_or_else(|| anyhow!("The storage cost of the execution overflowed"))?;
+
+                lap!(timer, "Finalize transition for {function_name}");
+            }
+        }
+        finish!(timer);
 
-                // Initialize the registers.
-                let mut registers = FinalizeRegisters::<N>::new(stack.get_finalize_types(finalize.name())?.clone());
+        Ok(storage_cost)
+    }
 
-                // Store the inputs.
-                finalize.inputs().iter().map(|i| i.register()).zip_eq(inputs).try_for_each(|(register, input)| {
-                    // Assign the input value to the register.
-                    registers.store(stack, register, input.clone())
-                })?;
+    /// Finalizes the function calls of an evaluation, and returns the storage cost (in gates) of the mapping writes.
+    /// The calls are given as `(program ID, function name, inputs for finalize)`, in the order they were evaluated.
+    /// This method assumes the given function calls **are valid**.
+    #[inline]
+    pub fn finalize_evaluation<P: ProgramStorage<N>>(
+        &self,
+        state: FinalizeGlobalState,
+        store: &ProgramStore<N, P>,
+        calls: &[(ProgramID<N>, Identifier<N>, Vec<Value<N>>)],
+    ) -> Result<u64> {
+        let timer = timer!("Program::finalize_evaluation");
 
-                // Evaluate the commands.
-                for command in finalize.commands() {
-                    // If the evaluation fails, bail and return the error.
-                    if let Err(error) = command.evaluate_finalize(stack, store, &mut registers) {
-                        bail!("'finalize' failed to evaluate command ({command}): {error}");
-                    }
-                }
+        // Finalize each function call, starting from the last one, to match the order in `finalize_execution`.
+        let storage_cost = calls.iter().rev().try_fold(0u64, |storage_cost, (program_id, function_name, inputs)| {
+            // Retrieve the stack.
+            let stack = self.get_stack(program_id)?;
+            // Finalize the function, and meter the storage cost.
+            let function_cost = finalize_function(state, store, stack, function_name, inputs)?;
+            lap!(timer, "Finalize call to {function_name}");
 
-                // Retrieve the output registers.
-                let output_registers =
-                    &finalize.outputs().iter().map(|output| output.register().clone()).collect::<Vec<_>>();
+            storage_cost.checked_add(function_cost).ok_or_else(|| anyhow!("The storage cost overflowed"))
+        })?;
+        finish!(timer);
 
-                // TODO (howardwu): Save the outputs in ProgramStore.
-                // Load the outputs.
-                let _outputs = output_registers
-                    .iter()
-                    .map(|register| {
-                        // Retrieve the stack value from the register.
-                        registers.load(stack, &Operand::Register(register.clone()))
-                    })
-                    .collect::<Result<Vec<_>>>()?;
+        Ok(storage_cost)
+    }
+}
 
-                lap!(timer, "Finalize transition for {function_name}");
-            }
+/// Finalizes the given function with the given inputs for finalize, and returns the storage cost (in gates).
+#[inline]
+fn finalize_function<N: Network, P: ProgramStorage<N>>(
+    state: FinalizeGlobalState,
+    store: &ProgramStore<N, P>,
+    stack: &Stack<N>,
+    function_name: &Identifier<N>,
+    inputs: &[Value<N>],
+) -> Result<u64> {
+    // Retrieve the finalize scope.
+    let function = stack.get_function(function_name)?;
+    let finalize = match function.finalize() {
+        Some((_, finalize)) => finalize,
+        None => bail!("Function '{function_name}' does not have a finalize scope"),
+    };
+
+    // Initialize the storage cost.
+    let mut storage_cost = 0u64;
+
+    // Initialize the registers.
+    let mut registers = FinalizeRegisters::<N>::new(state, stack.get_finalize_types(finalize.name())?.clone());
+
+    // Store the inputs.
+    finalize.inputs().iter().map(|i| i.register()).zip_eq(inputs).try_for_each(|(register, input)| {
+        // Assign the input value to the register.
+        registers.store(stack, register, input.clone())
+    })?;
+
+    // Initialize a counter for the command index.
+    let mut counter = 0;
+    // Evaluate the commands.
+    while counter < finalize.commands().len() {
+        // Retrieve the command.
+        let command = &finalize.commands()[counter];
+        // Meter the storage cost of the command.
+        storage_cost = storage_cost
+            .checked_add(command.storage_cost(stack, &registers)?)
+            .ok_or_else(|| anyhow!("The storage cost of the execution overflowed"))?;
+        // Evaluate the command, and retrieve the index of the next command.
+        let result = match command {
+            Command::BranchEq(branch_eq) => branch_to(counter, branch_eq, finalize, stack, &registers),
+            Command::BranchNeq(branch_neq) => branch_to(counter, branch_neq, finalize, stack, &registers),
+            _ => command.evaluate_finalize(stack, store, &mut registers).map(|_| counter + 1),
+        };
+        // If the evaluation fails, bail and return the error.
+        match result {
+            Ok(next) => counter = next,
+            Err(error) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
         }
-        finish!(timer);
+    }
 
-        Ok(())
+    // Retrieve the output registers.
+    let output_registers = &finalize.outputs().iter().map(|output| output.register().clone()).collect::<Vec<_>>();
+
+    // TODO (howardwu): Save the outputs in ProgramStore.
+    // Load the outputs.
+    let _outputs = output_registers
+        .iter()
+        .map(|register| {
+            // Retrieve the stack value from the register.
+            registers.load(stack, &Operand::Register(register.clone()))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(storage_cost)
+}
+
+/// Evaluates the branch command.
+/// If the branch is taken, returns the index of the position to jump to, otherwise returns the next index.
+#[inline]
+fn branch_to<N: Network, const VARIANT: u8>(
+    counter: usize,
+    branch: &Branch<N, VARIANT>,
+    finalize: &Finalize<N>,
+    stack: &Stack<N>,
+    registers: &FinalizeRegisters<N>,
+) -> Result<usize> {
+    // If the branch is not taken, proceed to the next command.
+    if !branch.evaluate_finalize(stack, registers)? {
+        return Ok(counter + 1);
     }
+
+    // Retrieve the index of the position.
+    let position = match finalize.positions().get(branch.position()) {
+        Some(position) => *position,
+        None => bail!("Position '{}' does not exist in 'finalize {}'", branch.position(), finalize.name()),
+    };
+    // Ensure the branch jumps forward.
+    ensure!(position > counter, "Position '{}' must be after the branch command", branch.position());
+
+    Ok(position)
 }
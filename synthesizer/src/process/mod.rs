@@ -18,6 +18,7 @@ mod stack;
 pub use stack::*;
 
 mod authorize;
+mod cost;
 mod deploy;
 mod evaluate;
 mod execute;
@@ -25,7 +26,12 @@ mod execute_fee;
 
 use crate::{
     block::{Input, Transition},
-    program::{Instruction, Operand, Program},
+    program::{
+        finalize::{Branch, Command, Finalize},
+        Instruction,
+        Operand,
+        Program,
+    },
     snark::{ProvingKey, UniversalSRS, VerifyingKey},
     store::{ProgramStorage, ProgramStore},
 };
@@ -53,6 +59,12 @@ pub struct Process<N: Network> {
     universal_srs: Arc<UniversalSRS<N>>,
     /// The mapping of program IDs to stacks.
     stacks: IndexMap<ProgramID<N>, Stack<N>>,
+    /// The stacks staged in the ongoing atomic batch, in order of insertion.
+    atomic_batch: Vec<(ProgramID<N>, Stack<N>)>,
+    /// The checkpoints of the ongoing atomic batch.
+    checkpoints: Vec<usize>,
+    /// Whether an atomic batch is in progress.
+    batch_in_progress: bool,
 }
 
 impl<N: Network> Process<N> {
@@ -62,7 +74,13 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process:setup");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            atomic_batch: Vec::new(),
+            checkpoints: Vec::new(),
+            batch_in_progress: false,
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -95,12 +113,84 @@ impl<N: Network> Process<N> {
         // Compute the program stack.
         let stack = Stack::new(self, program)?;
         // Add the stack to the process.
-        self.stacks.insert(*program.id(), stack);
+        self.stage_stack(*program.id(), stack);
         // Return success.
         Ok(())
     }
 }
 
+impl<N: Network> Process<N> {
+    /// Starts an atomic batch, in which new stacks are staged until `finish_atomic` is called.
+    #[inline]
+    pub fn start_atomic(&mut self) {
+        // Ensure that the atomic batch is empty.
+        assert!(self.atomic_batch.is_empty());
+        // Ensure that there are no checkpoints.
+        assert!(self.checkpoints.is_empty());
+        // Set the atomic batch flag to `true`.
+        self.batch_in_progress = true;
+    }
+
+    /// Checks whether an atomic batch is in progress.
+    #[inline]
+    pub const fn is_atomic_in_progress(&self) -> bool {
+        self.batch_in_progress
+    }
+
+    /// Saves the current list of staged stacks, so that if `atomic_rewind` is called,
+    /// all stacks staged after this checkpoint are discarded.
+    #[inline]
+    pub fn atomic_checkpoint(&mut self) {
+        self.checkpoints.push(self.atomic_batch.len());
+    }
+
+    /// Removes the latest atomic checkpoint, keeping the stacks staged after it.
+    #[inline]
+    pub fn clear_latest_checkpoint(&mut self) {
+        self.checkpoints.pop();
+    }
+
+    /// Discards all stacks staged after the latest `atomic_checkpoint`
+    /// (or after `start_atomic`, if no checkpoints have been created).
+    #[inline]
+    pub fn atomic_rewind(&mut self) {
+        // Retrieve the latest checkpoint, or the start of the batch if there are none.
+        let checkpoint = self.checkpoints.pop().unwrap_or(0);
+        // Discard the stacks staged after the checkpoint.
+        self.atomic_batch.truncate(checkpoint);
+    }
+
+    /// Aborts the current atomic batch, discarding all staged stacks.
+    #[inline]
+    pub fn abort_atomic(&mut self) {
+        self.atomic_batch.clear();
+        self.checkpoints.clear();
+        self.batch_in_progress = false;
+    }
+
+    /// Finishes the current atomic batch, adding all staged stacks to the process.
+    #[inline]
+    pub fn finish_atomic(&mut self) {
+        // Add the staged stacks, in order of insertion.
+        for (program_id, stack) in core::mem::take(&mut self.atomic_batch) {
+            self.stacks.insert(program_id, stack);
+        }
+        self.checkpoints.clear();
+        self.batch_in_progress = false;
+    }
+
+    /// Adds the given stack to the process, or stages it if an atomic batch is in progress.
+    #[inline]
+    fn stage_stack(&mut self, program_id: ProgramID<N>, stack: Stack<N>) {
+        match self.batch_in_progress {
+            true => self.atomic_batch.push((program_id, stack)),
+            false => {
+                self.stacks.insert(program_id, stack);
+            }
+        }
+    }
+}
+
 impl<N: Network> Process<N> {
     /// Initializes a new process.
     #[inline]
@@ -108,7 +198,13 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::load");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            atomic_batch: Vec::new(),
+            checkpoints: Vec::new(),
+            batch_in_progress: false,
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -155,7 +251,13 @@ impl<N: Network> Process<N> {
     #[inline]
     pub fn load_with_cache(cache: &mut HashMap<String, (ProvingKey<N>, VerifyingKey<N>)>) -> Result<Self> {
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            atomic_batch: Vec::new(),
+            checkpoints: Vec::new(),
+            batch_in_progress: false,
+        };
 
         // Initialize the 'credits.aleo' program.
         let program = Program::credits()?;
@@ -191,18 +293,39 @@ impl<N: Network> Process<N> {
     }
 
     /// Returns `true` if the process contains the program with the given ID.
+    /// Note: This includes the programs staged in the ongoing atomic batch.
     #[inline]
     pub fn contains_program(&self, program_id: &ProgramID<N>) -> bool {
-        self.stacks.contains_key(program_id)
+        self.stacks.contains_key(program_id) || self.atomic_batch.iter().any(|(id, _)| id == program_id)
+    }
+
+    /// Returns the program IDs in the process, in order of insertion.
+    /// Note: This includes the programs staged in the ongoing atomic batch.
+    #[inline]
+    pub fn program_ids(&self) -> impl '_ + Iterator<Item = &ProgramID<N>> {
+        // Retrieve the program IDs that are new in the atomic batch.
+        let staged = self.atomic_batch.iter().enumerate().filter_map(move |(index, (program_id, _))| {
+            // Skip the program ID if it is already in the process, or was staged before.
+            let is_staged_before = self.atomic_batch[..index].iter().any(|(id, _)| id == program_id);
+            match self.stacks.contains_key(program_id) || is_staged_before {
+                true => None,
+                false => Some(program_id),
+            }
+        });
+        self.stacks.keys().chain(staged)
     }
 
     /// Returns the stack for the given program ID.
+    /// Note: A stack staged in the ongoing atomic batch takes precedence.
     #[inline]
     pub fn get_stack(&self, program_id: impl TryInto<ProgramID<N>>) -> Result<&Stack<N>> {
         // Prepare the program ID.
         let program_id = program_id.try_into().map_err(|_| anyhow!("Invalid program ID"))?;
         // Retrieve the stack.
-        let stack = self.stacks.get(&program_id).ok_or_else(|| anyhow!("Program '{program_id}' does not exist"))?;
+        let stack = match self.atomic_batch.iter().rev().find(|(id, _)| id == &program_id) {
+            Some((_, stack)) => stack,
+            None => self.stacks.get(&program_id).ok_or_else(|| anyhow!("Program '{program_id}' does not exist"))?,
+        };
         // Ensure the program ID matches.
         ensure!(stack.program_id() == &program_id, "Expected program '{}', found '{program_id}'", stack.program_id());
         // Return the stack.
@@ -920,6 +1043,364 @@ function compute:
         // assert_eq!(159387, CurrentAleo::num_gates());
     }
 
+    #[test]
+    fn test_process_execute_cast_literals() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program casting.aleo;
+
+function compute:
+    input r0 as i8.private;
+    input r1 as u16.public;
+    cast r0 into r2 as field;
+    cast r2 into r3 as i16;
+    cast.lossy r1 into r4 as u8;
+    cast.lossy r1 into r5 as boolean;
+    output r2 as field.private;
+    output r3 as i16.private;
+    output r4 as u8.private;
+    output r5 as boolean.private;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let process = super::test_helpers::sample_process(&program);
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Declare the input values.
+        let r0 = Value::<CurrentNetwork>::from_str("-5i8").unwrap();
+        let r1 = Value::<CurrentNetwork>::from_str("515u16").unwrap();
+
+        // Declare the expected output values.
+        let r2 = Value::from_str("-5field").unwrap();
+        let r3 = Value::from_str("-5i16").unwrap();
+        let r4 = Value::from_str("3u8").unwrap();
+        let r5 = Value::from_str("true").unwrap();
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+            .unwrap();
+        assert_eq!(authorization.len(), 1);
+
+        // Compute the output values.
+        let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+        assert_eq!(response.outputs(), &[r2.clone(), r3.clone(), r4.clone(), r5.clone()]);
+
+        // Execute the request.
+        let (response, execution, _inclusion, _metrics) =
+            process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        assert_eq!(response.outputs(), &[r2, r3, r4, r5]);
+
+        // Verify the execution.
+        process.verify_execution::<false>(&execution).unwrap();
+    }
+
+    #[test]
+    fn test_process_execute_arrays() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program arrays.aleo;
+
+struct point:
+    x as u64;
+    y as u64;
+
+function compute:
+    input r0 as [u64; 4].private;
+    input r1 as [point; 2].public;
+    add r0[2] r1[1].y into r2;
+    cast r2 r0[0] into r3 as [u64; 2];
+    output r2 as u64.private;
+    output r3 as [u64; 2].private;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let process = super::test_helpers::sample_process(&program);
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Declare the input values.
+        let r0 = Value::<CurrentNetwork>::from_str("[1u64, 2u64, 3u64, 4u64]").unwrap();
+        let r1 = Value::<CurrentNetwork>::from_str("[{ x: 5u64, y: 6u64 }, { x: 7u64, y: 8u64 }]").unwrap();
+
+        // Declare the expected output values.
+        let r2 = Value::from_str("11u64").unwrap();
+        let r3 = Value::from_str("[11u64, 1u64]").unwrap();
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+            .unwrap();
+        assert_eq!(authorization.len(), 1);
+
+        // Compute the output values.
+        let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+        assert_eq!(response.outputs(), &[r2.clone(), r3.clone()]);
+
+        // Execute the request.
+        let (response, execution, _inclusion, _metrics) =
+            process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        assert_eq!(response.outputs(), &[r2, r3]);
+
+        // Verify the execution.
+        process.verify_execution::<false>(&execution).unwrap();
+
+        // Initialize a program with an out-of-bounds index.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program arrays_oob.aleo;
+
+function compute:
+    input r0 as [u64; 4].private;
+    add r0[4] r0[0] into r1;
+    output r1 as u64.private;",
+        )
+        .unwrap();
+
+        // Ensure the program is rejected.
+        let mut process = Process::load().unwrap();
+        assert!(process.add_program(&program).is_err());
+    }
+
+    #[test]
+    fn test_process_execute_sign_verify() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program oracle.aleo;
+
+function verify:
+    input r0 as signature.private;
+    input r1 as address.public;
+    input r2 as field.public;
+    sign.verify r0 r1 r2 into r3;
+    output r3 as boolean.private;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the function name.
+        let function_name = Identifier::from_str("verify").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let process = super::test_helpers::sample_process(&program);
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Initialize a new signer account.
+        let signer_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let signer = Address::try_from(&signer_private_key).unwrap();
+
+        // Sign the message.
+        let message = Plaintext::from(Literal::Field(Field::from_u64(42)));
+        let signature =
+            console::account::Signature::sign(&signer_private_key, &message.to_fields().unwrap(), rng).unwrap();
+
+        for (address, expected) in [(signer, "true"), (Address::try_from(&caller_private_key).unwrap(), "false")] {
+            // Declare the input values.
+            let r0 = Value::<CurrentNetwork>::Plaintext(Plaintext::from(Literal::Signature(Box::new(signature))));
+            let r1 = Value::<CurrentNetwork>::Plaintext(Plaintext::from(Literal::Address(address)));
+            let r2 = Value::<CurrentNetwork>::Plaintext(message.clone());
+
+            // Declare the expected output value.
+            let r3 = Value::from_str(expected).unwrap();
+
+            // Authorize the function call.
+            let authorization = process
+                .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1, r2].iter(), rng)
+                .unwrap();
+            assert_eq!(authorization.len(), 1);
+
+            // Compute the output value.
+            let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+            assert_eq!(response.outputs(), &[r3.clone()]);
+
+            // Execute the request.
+            let (response, execution, _inclusion, _metrics) =
+                process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+            assert_eq!(response.outputs(), &[r3]);
+
+            // Verify the execution.
+            process.verify_execution::<false>(&execution).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_process_execute_hash_into_types() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program hashing.aleo;
+
+function compute:
+    input r0 as field.private;
+    hash.bhp256 r0 into r1;
+    hash.bhp256 r0 into r2 as u64;
+    hash.psd2 r0 into r3 as scalar;
+    hash.psd4 r0 into r4 as group;
+    hash.psd8 r0 into r5 as address;
+    hash.psd2 r0 into r6 as u128;
+    cast.lossy r1 into r7 as u64;
+    output r1 as field.private;
+    output r2 as u64.private;
+    output r3 as scalar.private;
+    output r4 as group.private;
+    output r5 as address.private;
+    output r6 as u128.private;
+    output r7 as u64.private;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let process = super::test_helpers::sample_process(&program);
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Declare the input value.
+        let r0 = Value::<CurrentNetwork>::from_str("1field").unwrap();
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0].iter(), rng)
+            .unwrap();
+        assert_eq!(authorization.len(), 1);
+
+        // Compute the output values.
+        let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+        let expected = response.outputs().to_vec();
+        assert_eq!(expected.len(), 7);
+        // Ensure hashing into an integer keeps the lower bits of the field output.
+        assert_eq!(expected[1], expected[6]);
+
+        // Execute the request.
+        let (response, execution, _inclusion, _metrics) =
+            process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        assert_eq!(response.outputs(), expected.as_slice());
+
+        // Verify the execution.
+        process.verify_execution::<false>(&execution).unwrap();
+    }
+
+    #[test]
+    fn test_process_execute_hash_keccak() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program keccak.aleo;
+
+function compute:
+    input r0 as [u8; 3].private;
+    hash.keccak256 r0 into r1;
+    hash.sha3_256 r0 into r2;
+    hash.sha3_512 r0 into r3;
+    output r1 as [u128; 2].private;
+    output r2 as [u128; 2].private;
+    output r3 as [u128; 4].private;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let process = super::test_helpers::sample_process(&program);
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Declare the input value, i.e. the bytes of "abc".
+        let r0 = Value::<CurrentNetwork>::from_str("[97u8, 98u8, 99u8]").unwrap();
+
+        // Declare the expected output values, which are the standard digests of "abc".
+        let r1 = Value::from_str(&format!(
+            "[{}u128, {}u128]",
+            0x4e03657aea45a94fc7d47ba826c8d667u128, 0xc0d1e6e33a64a036ec44f58fa12d6c45u128
+        ))
+        .unwrap();
+        let r2 = Value::from_str(&format!(
+            "[{}u128, {}u128]",
+            0x3a985da74fe225b2045c172d6bd390bdu128, 0x855f086e3e9d525b46bfe24511431532u128
+        ))
+        .unwrap();
+        let r3 = Value::from_str(&format!(
+            "[{}u128, {}u128, {}u128, {}u128]",
+            0xb751850b1a57168a5693cd924b6b096eu128,
+            0x08f621827444f70d884f5d0240d2712eu128,
+            0x10e116e9192af3c91a7ec57647e39340u128,
+            0x57340b4cf408d5a56592f8274eec53f0u128
+        ))
+        .unwrap();
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0].iter(), rng)
+            .unwrap();
+        assert_eq!(authorization.len(), 1);
+
+        // Compute the output values.
+        let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+        assert_eq!(response.outputs(), &[r1.clone(), r2.clone(), r3.clone()]);
+
+        // Execute the request.
+        let (response, execution, _inclusion, _metrics) =
+            process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        assert_eq!(response.outputs(), &[r1, r2, r3]);
+
+        // Verify the execution.
+        process.verify_execution::<false>(&execution).unwrap();
+
+        // Ensure Keccak cannot hash into a literal type.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program keccak_literal.aleo;
+
+function compute:
+    input r0 as field.private;
+    hash.keccak256 r0 into r1 as u128;
+    output r1 as u128.private;",
+        )
+        .unwrap();
+        let mut process = Process::load().unwrap();
+        assert!(process.add_program(&program).is_err());
+    }
+
     #[test]
     fn test_process_execute_call_external_function() {
         // Initialize a new program.
@@ -1168,7 +1649,7 @@ finalize compute:
         process.verify_execution::<true>(&execution).unwrap();
 
         // Now, finalize the execution.
-        process.finalize_execution(&store, &execution).unwrap();
+        process.finalize_execution(FinalizeGlobalState::new(0, 0), &store, &execution).unwrap();
 
         // Check that the account balance is now 8.
         let candidate =
@@ -1266,7 +1747,7 @@ finalize compute:
         process.verify_execution::<true>(&execution).unwrap();
 
         // Now, finalize the execution.
-        process.finalize_execution(&store, &execution).unwrap();
+        process.finalize_execution(FinalizeGlobalState::new(0, 0), &store, &execution).unwrap();
 
         // Check that the account balance is now 0.
         let candidate =
@@ -1274,6 +1755,918 @@ finalize compute:
         assert_eq!(candidate, Value::from_str("0u64").unwrap());
     }
 
+    #[test]
+    fn test_process_execute_and_finalize_with_instructions() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program testing.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    input r2 as u64.public;
+    add r1 r2 into r3;
+    finalize r0 r3;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    mul r1 2u64 into r2;
+    is.eq r2 16u64 into r3;
+    ternary r3 r2 0u64 into r4;
+    hash.bhp256 r0 into r5;
+    assert.neq r5 0field;
+    increment account[r0] by r4;
+",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the program ID.
+        let program_id = program.id();
+        // Declare the mapping.
+        let mapping_name = Identifier::from_str("account").unwrap();
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let mut process = Process::load().unwrap();
+
+        // Initialize a new program store.
+        let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+
+        // Add the program to the process.
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+        // Check that the deployment verifies.
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+        // Finalize the deployment.
+        process.finalize_deployment(&store, &deployment).unwrap();
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Declare the input value.
+        let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+        let r1 = Value::<CurrentNetwork>::from_str("3u64").unwrap();
+        let r2 = Value::<CurrentNetwork>::from_str("5u64").unwrap();
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1, r2].iter(), rng)
+            .unwrap();
+        assert_eq!(authorization.len(), 1);
+
+        // Execute the request.
+        let (response, execution, _inclusion, _metrics) =
+            process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        let candidate = response.outputs();
+        assert_eq!(0, candidate.len());
+
+        // Verify the execution.
+        process.verify_execution::<true>(&execution).unwrap();
+
+        // Now, finalize the execution.
+        process.finalize_execution(FinalizeGlobalState::new(0, 0), &store, &execution).unwrap();
+
+        // Check that the account balance is now 16.
+        let candidate =
+            store.get_value(program_id, &mapping_name, &Plaintext::from(Literal::Address(caller))).unwrap().unwrap();
+        assert_eq!(candidate, Value::from_str("16u64").unwrap());
+    }
+
+    #[test]
+    fn test_process_execute_and_finalize_get_set_remove() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program testing.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+mapping pending:
+    key owner as address.public;
+    value amount as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_init account[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into account[r0];
+    get account[r0] into r4;
+    assert.eq r3 r4;
+    set r1 into pending[r0];
+    remove pending[r0];
+",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the program ID.
+        let program_id = program.id();
+        // Declare the mappings.
+        let account = Identifier::from_str("account").unwrap();
+        let pending = Identifier::from_str("pending").unwrap();
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let mut process = Process::load().unwrap();
+
+        // Initialize a new program store.
+        let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+
+        // Add the program to the process.
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+        // Check that the deployment verifies.
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+        // Finalize the deployment.
+        process.finalize_deployment(&store, &deployment).unwrap();
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+        let key = Plaintext::from(Literal::Address(caller));
+
+        // Execute and finalize the function twice.
+        for _ in 0..2 {
+            // Declare the input value.
+            let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+            let r1 = Value::<CurrentNetwork>::from_str("8u64").unwrap();
+
+            // Authorize the function call.
+            let authorization = process
+                .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+                .unwrap();
+            assert_eq!(authorization.len(), 1);
+
+            // Execute the request.
+            let (_response, execution, _inclusion, _metrics) =
+                process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+
+            // Verify the execution.
+            process.verify_execution::<true>(&execution).unwrap();
+
+            // Now, finalize the execution.
+            process.finalize_execution(FinalizeGlobalState::new(0, 0), &store, &execution).unwrap();
+        }
+
+        // Check that the account balance is now 16.
+        let candidate = store.get_value(program_id, &account, &key).unwrap().unwrap();
+        assert_eq!(candidate, Value::from_str("16u64").unwrap());
+        // Check that the pending entry was removed.
+        assert!(!store.contains_key(program_id, &pending, &key).unwrap());
+    }
+
+    #[test]
+    fn test_process_execute_and_finalize_get_external_mapping() {
+        // Initialize the oracle program.
+        let oracle = Program::<CurrentNetwork>::from_str(
+            r"
+program oracle.aleo;
+
+mapping prices:
+    key token as u8.public;
+    value price as u64.public;
+
+function set_price:
+    input r0 as u8.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize set_price:
+    input r0 as u8.public;
+    input r1 as u64.public;
+    set r1 into prices[r0];
+",
+        )
+        .unwrap();
+
+        // Initialize the program that reads from the oracle.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+import oracle.aleo;
+
+program amm.aleo;
+
+mapping quotes:
+    key token as u8.public;
+    value amount as u64.public;
+
+function quote:
+    input r0 as u8.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize quote:
+    input r0 as u8.public;
+    input r1 as u64.public;
+    get oracle.aleo/prices[r0] into r2;
+    mul r2 r1 into r3;
+    set r3 into quotes[r0];
+",
+        )
+        .unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let mut process = Process::load().unwrap();
+
+        // Initialize a new program store.
+        let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+
+        // Deploy both programs.
+        for program in [&oracle, &program] {
+            let deployment = process.deploy::<CurrentAleo, _>(program, rng).unwrap();
+            process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+            process.finalize_deployment(&store, &deployment).unwrap();
+        }
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Execute and finalize each function call.
+        for (program_id, function_name) in [(oracle.id(), "set_price"), (program.id(), "quote")] {
+            // Declare the input values.
+            let r0 = Value::<CurrentNetwork>::from_str("1u8").unwrap();
+            let r1 = Value::<CurrentNetwork>::from_str("3u64").unwrap();
+
+            // Authorize the function call.
+            let authorization = process
+                .authorize::<CurrentAleo, _>(
+                    &caller_private_key,
+                    program_id,
+                    Identifier::from_str(function_name).unwrap(),
+                    [r0, r1].iter(),
+                    rng,
+                )
+                .unwrap();
+
+            // Execute the request.
+            let (_response, execution, _inclusion, _metrics) =
+                process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+            // Verify the execution.
+            process.verify_execution::<true>(&execution).unwrap();
+            // Finalize the execution.
+            process.finalize_execution(FinalizeGlobalState::new(0, 0), &store, &execution).unwrap();
+        }
+
+        // Check that the quote was computed from the oracle price.
+        let key = Plaintext::from_str("1u8").unwrap();
+        let candidate = store.get_value(program.id(), &Identifier::from_str("quotes").unwrap(), &key).unwrap();
+        assert_eq!(candidate, Some(Value::from_str("9u64").unwrap()));
+
+        // Ensure a write into the imported mapping does not parse.
+        assert!(Command::<CurrentNetwork>::from_str("set r1 into oracle.aleo/prices[r0];").is_err());
+
+        // Initialize a program that reads the imported mapping with the wrong key type.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+import oracle.aleo;
+
+program mismatch.aleo;
+
+function quote:
+    input r0 as u16.public;
+    finalize r0;
+
+finalize quote:
+    input r0 as u16.public;
+    get oracle.aleo/prices[r0] into r1;
+",
+        )
+        .unwrap();
+
+        // Ensure the program is rejected.
+        assert!(process.add_program(&program).is_err());
+    }
+
+    #[test]
+    fn test_process_deploy_and_finalize_upgrade() {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program counter.aleo;
+
+mapping counts:
+    key owner as address.public;
+    value amount as u64.public;
+
+function bump:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize bump:
+    input r0 as address.public;
+    input r1 as u64.public;
+    increment counts[r0] by r1;
+",
+        )
+        .unwrap();
+
+        // Initialize the next edition of the program, which changes a function and adds a function.
+        let upgrade = Program::<CurrentNetwork>::from_str(
+            r"
+program counter.aleo;
+
+mapping counts:
+    key owner as address.public;
+    value amount as u64.public;
+
+function bump:
+    input r0 as address.public;
+    input r1 as u64.public;
+    add r1 1u64 into r2;
+    finalize r0 r2;
+
+finalize bump:
+    input r0 as address.public;
+    input r1 as u64.public;
+    increment counts[r0] by r1;
+
+function double:
+    input r0 as address.public;
+    finalize r0;
+
+finalize double:
+    input r0 as address.public;
+    get counts[r0] into r1;
+    add r1 r1 into r2;
+    set r2 into counts[r0];
+",
+        )
+        .unwrap();
+
+        // Declare the mapping.
+        let mapping_name = Identifier::from_str("counts").unwrap();
+        // Declare the function names.
+        let bump = Identifier::from_str("bump").unwrap();
+        let double = Identifier::from_str("double").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Initialize a new process.
+        let mut process = Process::load().unwrap();
+        // Initialize a new program store.
+        let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+
+        // Ensure a program that does not exist cannot be upgraded.
+        assert!(process.upgrade::<CurrentAleo, _>(&program, rng).is_err());
+
+        // Ensure 'credits.aleo' cannot be upgraded.
+        assert!(process.upgrade::<CurrentAleo, _>(&Program::credits().unwrap(), rng).is_err());
+
+        // Initialize the owner account.
+        let owner_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let owner = Address::try_from(&owner_private_key).unwrap();
+
+        // Deploy the program, signed by the owner.
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+        let deployment = deployment.sign(&owner_private_key, rng).unwrap();
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+        process.finalize_deployment(&store, &deployment).unwrap();
+        assert_eq!(process.get_stack(program.id()).unwrap().edition(), CurrentNetwork::EDITION);
+        assert_eq!(process.get_stack(program.id()).unwrap().owner(), Some(&owner));
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+        let key = Plaintext::from(Literal::Address(caller));
+
+        // Execute and finalize 'bump'.
+        let inputs =
+            [Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap(), Value::from_str("3u64").unwrap()];
+        let authorization =
+            process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), bump, inputs.iter(), rng).unwrap();
+        let (_, bump_execution, _, _) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        process.verify_execution::<true>(&bump_execution).unwrap();
+        process.finalize_execution(FinalizeGlobalState::new(0, 0), &store, &bump_execution).unwrap();
+        assert_eq!(store.get_value(program.id(), &mapping_name, &key).unwrap(), Some(Value::from_str("3u64").unwrap()));
+
+        // Ensure the same program cannot be deployed or upgraded again.
+        assert!(process.deploy::<CurrentAleo, _>(&program, rng).is_err());
+        assert!(process.upgrade::<CurrentAleo, _>(&program, rng).is_err());
+
+        // Upgrade the program.
+        let upgrade_deployment = process.upgrade::<CurrentAleo, _>(&upgrade, rng).unwrap();
+        assert_eq!(upgrade_deployment.edition(), CurrentNetwork::EDITION + 1);
+        // Ensure an unsigned upgrade is rejected.
+        assert!(process.verify_deployment::<CurrentAleo, _>(&upgrade_deployment, rng).is_err());
+        assert!(process.finalize_deployment(&store, &upgrade_deployment).is_err());
+        // Ensure an upgrade signed by another account is rejected.
+        let other_upgrade = upgrade_deployment.clone().sign(&caller_private_key, rng).unwrap();
+        assert!(process.verify_deployment::<CurrentAleo, _>(&other_upgrade, rng).is_err());
+        assert!(process.finalize_deployment(&store, &other_upgrade).is_err());
+        // Ensure an upgrade signed by the owner is accepted.
+        let upgrade_deployment = upgrade_deployment.sign(&owner_private_key, rng).unwrap();
+        process.verify_deployment::<CurrentAleo, _>(&upgrade_deployment, rng).unwrap();
+        // Ensure a deployment with the wrong edition is rejected.
+        assert!(process.verify_deployment::<CurrentAleo, _>(&deployment, rng).is_err());
+        process.finalize_deployment(&store, &upgrade_deployment).unwrap();
+
+        // Ensure the stack is upgraded, and the owner is preserved.
+        let stack = process.get_stack(program.id()).unwrap();
+        assert_eq!(stack.edition(), CurrentNetwork::EDITION + 1);
+        assert_eq!(stack.program(), &upgrade);
+        assert_eq!(stack.owner(), Some(&owner));
+        assert_eq!(stack.get_verifying_key(&bump).unwrap(), upgrade_deployment.verifying_keys()[&bump].0);
+        assert!(stack.get_verifying_key(&double).is_ok());
+
+        // Ensure the previous edition is retained, with its program and verifying keys.
+        let retired = stack.get_stack_for_edition(CurrentNetwork::EDITION).unwrap();
+        assert_eq!(retired.program(), &program);
+        assert_eq!(retired.get_verifying_key(&bump).unwrap(), deployment.verifying_keys()[&bump].0);
+        assert!(stack.get_stack_for_edition(CurrentNetwork::EDITION + 2).is_err());
+        // Ensure the execution of the previous edition only verifies against the previous edition.
+        assert!(process.verify_execution::<true>(&bump_execution).is_err());
+        let editions = IndexMap::from([(*program.id(), CurrentNetwork::EDITION)]);
+        process.verify_execution_at_editions::<true>(&bump_execution, &editions).unwrap();
+
+        // Ensure the mapping is preserved.
+        assert_eq!(store.get_value(program.id(), &mapping_name, &key).unwrap(), Some(Value::from_str("3u64").unwrap()));
+
+        // Execute and finalize 'double'.
+        let inputs = [Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap()];
+        let authorization =
+            process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), double, inputs.iter(), rng).unwrap();
+        let (_, execution, _, _) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        process.verify_execution::<true>(&execution).unwrap();
+        process.finalize_execution(FinalizeGlobalState::new(0, 0), &store, &execution).unwrap();
+        assert_eq!(store.get_value(program.id(), &mapping_name, &key).unwrap(), Some(Value::from_str("6u64").unwrap()));
+
+        // Ensure an upgrade that changes a mapping type is rejected.
+        let incompatible = upgrade.to_string().replace("value amount as u64.public;", "value amount as u128.public;");
+        let incompatible = Program::<CurrentNetwork>::from_str(&incompatible).unwrap();
+        assert!(process.upgrade::<CurrentAleo, _>(&incompatible, rng).is_err());
+
+        // Ensure an upgrade that removes a function is rejected.
+        assert!(process.upgrade::<CurrentAleo, _>(&program, rng).is_err());
+
+        // Ensure a program deployed without an owner cannot be upgraded.
+        let mut process = Process::load().unwrap();
+        let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+        process.finalize_deployment(&store, &deployment).unwrap();
+        assert_eq!(process.get_stack(program.id()).unwrap().owner(), None);
+        let upgrade_deployment = process.upgrade::<CurrentAleo, _>(&upgrade, rng).unwrap();
+        let upgrade_deployment = upgrade_deployment.sign(&owner_private_key, rng).unwrap();
+        assert!(process.verify_deployment::<CurrentAleo, _>(&upgrade_deployment, rng).is_err());
+        assert!(process.finalize_deployment(&store, &upgrade_deployment).is_err());
+    }
+
+    #[test]
+    fn test_process_upgrade_preserves_closures() {
+        // Initialize a program with a closure.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program square.aleo;
+
+closure square:
+    input r0 as u64;
+    mul r0 r0 into r1;
+    output r1 as u64;
+
+function compute:
+    input r0 as u64.public;
+    call square r0 into r1;
+    output r1 as u64.public;
+",
+        )
+        .unwrap();
+
+        // Initialize a program that imports the program, and inlines its closure into its circuit.
+        let importer = Program::<CurrentNetwork>::from_str(
+            r"
+import square.aleo;
+
+program square_twice.aleo;
+
+function compute:
+    input r0 as u64.public;
+    call square.aleo/square r0 into r1;
+    call square.aleo/square r1 into r2;
+    output r2 as u64.public;
+",
+        )
+        .unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Initialize a new process, with both programs.
+        let mut process = super::test_helpers::sample_process(&program);
+        process.add_program(&importer).unwrap();
+
+        // Ensure an upgrade that changes the closure is rejected.
+        let changed = program.to_string().replace("mul r0 r0 into r1;", "add r0 r0 into r1;");
+        let changed = Program::<CurrentNetwork>::from_str(&changed).unwrap();
+        assert_ne!(changed, program);
+        assert!(process.upgrade::<CurrentAleo, _>(&changed, rng).is_err());
+
+        // Ensure an upgrade that preserves the closure, and adds a closure, is accepted.
+        let extended = Program::<CurrentNetwork>::from_str(
+            r"
+program square.aleo;
+
+closure square:
+    input r0 as u64;
+    mul r0 r0 into r1;
+    output r1 as u64;
+
+closure cube:
+    input r0 as u64;
+    mul r0 r0 into r1;
+    mul r1 r0 into r2;
+    output r2 as u64;
+
+function compute:
+    input r0 as u64.public;
+    call cube r0 into r1;
+    output r1 as u64.public;
+",
+        )
+        .unwrap();
+        let deployment = process.upgrade::<CurrentAleo, _>(&extended, rng).unwrap();
+        assert_eq!(deployment.edition(), CurrentNetwork::EDITION + 1);
+    }
+
+    #[test]
+    fn test_process_execute_and_finalize_with_branch() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program testing.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    is.eq r1 0u64 into r2;
+    branch.eq r2 true to end;
+    mul r1 2u64 into r3;
+    increment account[r0] by r3;
+    position end;
+",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the program ID.
+        let program_id = program.id();
+        // Declare the mapping.
+        let mapping_name = Identifier::from_str("account").unwrap();
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let mut process = Process::load().unwrap();
+
+        // Initialize a new program store.
+        let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+
+        // Add the program to the process.
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+        // Check that the deployment verifies.
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+        // Finalize the deployment.
+        process.finalize_deployment(&store, &deployment).unwrap();
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+        let key = Plaintext::from(Literal::Address(caller));
+
+        // Execute and finalize the function with the given amount.
+        let mut execute_and_finalize = |amount: &str| {
+            // Declare the input value.
+            let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+            let r1 = Value::<CurrentNetwork>::from_str(amount).unwrap();
+
+            // Authorize the function call.
+            let authorization = process
+                .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+                .unwrap();
+            // Execute the request.
+            let (_response, execution, _inclusion, _metrics) =
+                process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+            // Verify the execution.
+            process.verify_execution::<true>(&execution).unwrap();
+            // Now, finalize the execution.
+            process.finalize_execution(FinalizeGlobalState::new(0, 0), &store, &execution).unwrap();
+        };
+
+        // Check that the increment is skipped when the amount is zero.
+        execute_and_finalize("0u64");
+        assert!(!store.contains_key(program_id, &mapping_name, &key).unwrap());
+
+        // Check that the increment is applied when the amount is nonzero.
+        execute_and_finalize("5u64");
+        let candidate = store.get_value(program_id, &mapping_name, &key).unwrap().unwrap();
+        assert_eq!(candidate, Value::from_str("10u64").unwrap());
+    }
+
+    #[test]
+    fn test_process_atomic_batch() {
+        // Initialize two programs.
+        let first = Program::<CurrentNetwork>::from_str(
+            r"
+program first.aleo;
+
+function compute:
+    input r0 as u64.public;
+    output r0 as u64.public;",
+        )
+        .unwrap();
+        let second = Program::<CurrentNetwork>::from_str(
+            r"
+program second.aleo;
+
+function compute:
+    input r0 as u64.public;
+    output r0 as u64.public;",
+        )
+        .unwrap();
+
+        // Initialize a new process.
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+
+        // Stage the first program, and discard the second program.
+        process.start_atomic();
+        process.add_program(&first).unwrap();
+        process.atomic_checkpoint();
+        process.add_program(&second).unwrap();
+        assert!(process.contains_program(second.id()));
+        process.atomic_rewind();
+        // Ensure only the first program is visible within the batch.
+        assert!(process.contains_program(first.id()));
+        assert!(!process.contains_program(second.id()));
+        assert_eq!(process.program_ids().last(), Some(first.id()));
+
+        // Abort the batch, and ensure the first program was discarded.
+        process.abort_atomic();
+        assert!(!process.contains_program(first.id()));
+
+        // Stage the first program again, and finish the batch.
+        process.start_atomic();
+        process.add_program(&first).unwrap();
+        process.finish_atomic();
+        // Ensure the first program was added to the process.
+        assert!(process.contains_program(first.id()));
+        assert_eq!(process.get_program(first.id()).unwrap(), &first);
+    }
+
+    #[test]
+    fn test_process_finalize_branch_must_jump_forward() {
+        // Initialize a program with a branch that jumps backward.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    position start;
+    increment account[r0] by r1;
+    branch.eq r1 r1 to start;
+",
+        )
+        .unwrap();
+
+        // Ensure the program is rejected.
+        let mut process = Process::load().unwrap();
+        assert!(process.add_program(&program).is_err());
+
+        // Initialize a program with a branch to an undefined position.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    branch.eq r1 0u64 to end;
+    increment account[r0] by r1;
+",
+        )
+        .unwrap();
+
+        // Ensure the program is rejected.
+        let mut process = Process::load().unwrap();
+        assert!(process.add_program(&program).is_err());
+    }
+
+    #[test]
+    fn test_process_finalize_branch_must_not_skip_assignments() {
+        // Initialize a program that reads a register assigned in a region the branch may skip.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    branch.eq r1 0u64 to end;
+    mul r1 2u64 into r2;
+    position end;
+    increment account[r0] by r2;
+",
+        )
+        .unwrap();
+
+        // Ensure the program is rejected.
+        let mut process = Process::load().unwrap();
+        assert!(process.add_program(&program).is_err());
+
+        // Initialize a program that only reads the register within the region the branch may skip.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    branch.eq r1 0u64 to end;
+    mul r1 2u64 into r2;
+    increment account[r0] by r2;
+    position end;
+",
+        )
+        .unwrap();
+
+        // Ensure the program is accepted.
+        let mut process = Process::load().unwrap();
+        assert!(process.add_program(&program).is_ok());
+    }
+
+    #[test]
+    fn test_process_execute_and_finalize_with_block_state() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program testing.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function compute:
+    input r0 as address.public;
+    finalize r0;
+
+finalize compute:
+    input r0 as address.public;
+    gt block.timestamp 0i64 into r1;
+    assert.eq r1 true;
+    gte block.height 10u32 into r2;
+    ternary r2 1u64 0u64 into r3;
+    increment account[r0] by r3;
+",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the program ID.
+        let program_id = program.id();
+        // Declare the mapping.
+        let mapping_name = Identifier::from_str("account").unwrap();
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let mut process = Process::load().unwrap();
+
+        // Initialize a new program store.
+        let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+
+        // Add the program to the process.
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+        // Check that the deployment verifies.
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+        // Finalize the deployment.
+        process.finalize_deployment(&store, &deployment).unwrap();
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+        let key = Plaintext::from(Literal::Address(caller));
+
+        // Authorize and execute the function call.
+        let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0].iter(), rng)
+            .unwrap();
+        let (_response, execution, _inclusion, _metrics) =
+            process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        // Verify the execution.
+        process.verify_execution::<true>(&execution).unwrap();
+
+        // Ensure finalize fails if the block timestamp is not positive.
+        assert!(process.finalize_execution(FinalizeGlobalState::new(10, 0), &store, &execution).is_err());
+
+        // Check that the account is not incremented before block height 10.
+        process.finalize_execution(FinalizeGlobalState::new(9, 1), &store, &execution).unwrap();
+        let candidate = store.get_value(program_id, &mapping_name, &key).unwrap().unwrap();
+        assert_eq!(candidate, Value::from_str("0u64").unwrap());
+
+        // Check that the account is incremented from block height 10.
+        process.finalize_execution(FinalizeGlobalState::new(10, 1), &store, &execution).unwrap();
+        let candidate = store.get_value(program_id, &mapping_name, &key).unwrap().unwrap();
+        assert_eq!(candidate, Value::from_str("1u64").unwrap());
+    }
+
+    #[test]
+    fn test_process_block_state_forbidden_in_function() {
+        // Initialize a program that uses the block height outside of finalize.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function compute:
+    input r0 as u32.public;
+    add r0 block.height into r1;
+    output r1 as u32.public;
+",
+        )
+        .unwrap();
+
+        // Ensure the program is rejected.
+        let mut process = Process::load().unwrap();
+        assert!(process.add_program(&program).is_err());
+    }
+
     #[test]
     fn test_process_execute_mint_public() {
         // Initialize a new program.
@@ -1382,7 +2775,7 @@ finalize mint_public:
         process.verify_execution::<true>(&execution).unwrap();
 
         // Now, finalize the execution.
-        process.finalize_execution(&store, &execution).unwrap();
+        process.finalize_execution(FinalizeGlobalState::new(0, 0), &store, &execution).unwrap();
 
         // Check the account balance.
         let candidate =
@@ -1519,7 +2912,7 @@ function mint:
         process.verify_execution::<true>(&execution).unwrap();
 
         // Now, finalize the execution.
-        process.finalize_execution(&store, &execution).unwrap();
+        process.finalize_execution(FinalizeGlobalState::new(0, 0), &store, &execution).unwrap();
 
         // Check the account balance.
         let candidate =
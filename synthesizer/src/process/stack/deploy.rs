@@ -50,7 +50,7 @@ impl<N: Network> Stack<N> {
         finish!(timer);
 
         // Return the deployment.
-        Deployment::new(N::EDITION, self.program.clone(), bundle)
+        Deployment::new(self.edition, self.program.clone(), bundle)
     }
 
     /// Checks each function in the program on the given verifying key and certificate.
@@ -74,7 +74,11 @@ impl<N: Network> Stack<N> {
         // Sanity Checks //
 
         // Ensure the edition matches.
-        ensure!(edition == N::EDITION, "Deployed the wrong edition (expected '{}', found '{edition}').", N::EDITION);
+        ensure!(
+            edition == self.edition,
+            "Deployed the wrong edition (expected '{}', found '{edition}').",
+            self.edition
+        );
         // Ensure the program matches.
         ensure!(program == deployment.program(), "The stack program does not match the deployment program");
         // Ensure the program network-level domain (NLD) is correct.
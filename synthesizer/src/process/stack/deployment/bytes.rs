@@ -22,7 +22,7 @@ impl<N: Network> FromBytes for Deployment<N> {
         // Read the version.
         let version = u16::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 0 {
+        if version > 1 {
             return Err(error("Invalid deployment version"));
         }
 
@@ -46,15 +46,38 @@ impl<N: Network> FromBytes for Deployment<N> {
             bundle.insert(identifier, (verifying_key, certificate));
         }
 
-        Ok(Self { edition, program, verifying_keys: bundle })
+        // Read the owner and their signature, if the deployment is signed.
+        let owner = match version {
+            0 => None,
+            _ => Some((Address::read_le(&mut reader)?, Signature::read_le(&mut reader)?)),
+        };
+
+        Ok(Self { edition, program, verifying_keys: bundle, owner })
     }
 }
 
 impl<N: Network> ToBytes for Deployment<N> {
     /// Writes the deployment to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        // Write the version.
-        0u16.write_le(&mut writer)?;
+        // Write the version, which is 1 if the deployment is signed.
+        match self.owner {
+            None => 0u16.write_le(&mut writer)?,
+            Some(_) => 1u16.write_le(&mut writer)?,
+        }
+        // Write the contents.
+        self.write_contents_le(&mut writer)?;
+        // Write the owner and their signature, if the deployment is signed.
+        if let Some((address, signature)) = &self.owner {
+            address.write_le(&mut writer)?;
+            signature.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> Deployment<N> {
+    /// Writes the edition, program, and verifying keys of the deployment to a buffer.
+    pub(super) fn write_contents_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the edition.
         self.edition.write_le(&mut writer)?;
         // Write the program.
@@ -86,6 +109,14 @@ mod tests {
         // Construct a new deployment.
         let expected = test_helpers::sample_deployment();
 
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, Deployment::read_le(&expected_bytes[..])?);
+        assert!(Deployment::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+
+        // Construct a new signed deployment.
+        let expected = test_helpers::sample_signed_deployment();
+
         // Check the byte representation.
         let expected_bytes = expected.to_bytes_le()?;
         assert_eq!(expected, Deployment::read_le(&expected_bytes[..])?);
@@ -20,8 +20,10 @@ mod string;
 
 use crate::{Certificate, Program, VerifyingKey};
 use console::{
+    account::{Address, PrivateKey, Signature},
     network::prelude::*,
     program::{Identifier, ProgramID},
+    types::Field,
 };
 
 use indexmap::IndexMap;
@@ -34,6 +36,8 @@ pub struct Deployment<N: Network> {
     program: Program<N>,
     /// The mapping of function names to their verifying key and certificate.
     verifying_keys: IndexMap<Identifier<N>, (VerifyingKey<N>, Certificate<N>)>,
+    /// The owner of the program, and their signature on the deployment, if the deployment is signed.
+    owner: Option<(Address<N>, Signature<N>)>,
 }
 
 impl<N: Network> Deployment<N> {
@@ -43,7 +47,21 @@ impl<N: Network> Deployment<N> {
         program: Program<N>,
         verifying_keys: IndexMap<Identifier<N>, (VerifyingKey<N>, Certificate<N>)>,
     ) -> Result<Self> {
-        Ok(Self { edition, program, verifying_keys })
+        Ok(Self { edition, program, verifying_keys, owner: None })
+    }
+
+    /// Returns the deployment, signed by the given private key as the owner of the program.
+    pub fn sign<R: Rng + CryptoRng>(mut self, private_key: &PrivateKey<N>, rng: &mut R) -> Result<Self> {
+        // Sign the deployment.
+        let signature = Signature::sign(private_key, &[self.to_owner_message()?], rng)?;
+        // Set the owner.
+        self.owner = Some((Address::try_from(private_key)?, signature));
+        Ok(self)
+    }
+
+    /// Restores the owner and their signature, for a deployment loaded from storage.
+    pub(crate) fn restore_owner(&mut self, owner: (Address<N>, Signature<N>)) {
+        self.owner = Some(owner);
     }
 
     /// Returns the edition.
@@ -65,6 +83,46 @@ impl<N: Network> Deployment<N> {
     pub const fn verifying_keys(&self) -> &IndexMap<Identifier<N>, (VerifyingKey<N>, Certificate<N>)> {
         &self.verifying_keys
     }
+
+    /// Returns the owner of the program and their signature, if the deployment is signed.
+    pub const fn owner(&self) -> Option<&(Address<N>, Signature<N>)> {
+        self.owner.as_ref()
+    }
+
+    /// Returns the address of the owner of the program, if the deployment is signed.
+    pub fn owner_address(&self) -> Option<Address<N>> {
+        self.owner.as_ref().map(|(address, _)| *address)
+    }
+
+    /// Ensures the owner signature is valid for the deployment, if the deployment is signed.
+    pub fn check_owner(&self) -> Result<()> {
+        if let Some((address, signature)) = &self.owner {
+            ensure!(
+                signature.verify(address, &[self.to_owner_message()?]),
+                "Invalid owner signature for the deployment of '{}'",
+                self.program_id()
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns the hash of the edition, and the owner and their signature, if the deployment is signed.
+    /// Note: The transaction ID commits to this hash, so the owner of a deployment can not be replaced.
+    pub fn to_owner_id(&self) -> Result<Field<N>> {
+        let mut bytes = self.edition.to_bytes_le()?;
+        if let Some((address, signature)) = &self.owner {
+            address.write_le(&mut bytes)?;
+            signature.write_le(&mut bytes)?;
+        }
+        N::hash_bhp1024(&bytes.to_bits_le())
+    }
+
+    /// Returns the message signed by the owner, which is the hash of the edition, program, and verifying keys.
+    fn to_owner_message(&self) -> Result<Field<N>> {
+        let mut bytes = Vec::new();
+        self.write_contents_le(&mut bytes)?;
+        N::hash_bhp1024(&bytes.to_bits_le())
+    }
 }
 
 #[cfg(test)]
@@ -106,4 +164,34 @@ function compute:
             })
             .clone()
     }
+
+    pub(crate) fn sample_signed_deployment() -> Deployment<CurrentNetwork> {
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+        // Sign the deployment.
+        sample_deployment().sign(&PrivateKey::new(rng).unwrap(), rng).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_owner() {
+        // Ensure an unsigned deployment has no owner.
+        let deployment = test_helpers::sample_deployment();
+        assert!(deployment.owner().is_none());
+        assert!(deployment.check_owner().is_ok());
+
+        // Ensure a signed deployment has a valid owner signature.
+        let deployment = test_helpers::sample_signed_deployment();
+        assert!(deployment.owner().is_some());
+        assert!(deployment.check_owner().is_ok());
+
+        // Ensure the signature does not carry over to another edition.
+        let mut tampered = deployment;
+        tampered.edition += 1;
+        assert!(tampered.check_owner().is_err());
+    }
 }
@@ -21,10 +21,13 @@ impl<N: Network> Serialize for Deployment<N> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
             true => {
-                let mut deployment = serializer.serialize_struct("Deployment", 3)?;
+                let mut deployment = serializer.serialize_struct("Deployment", 3 + self.owner.is_some() as usize)?;
                 deployment.serialize_field("edition", &self.edition)?;
                 deployment.serialize_field("program", &self.program)?;
                 deployment.serialize_field("verifying_keys", &self.verifying_keys)?;
+                if let Some(owner) = &self.owner {
+                    deployment.serialize_field("owner", owner)?;
+                }
                 deployment.end()
             }
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
@@ -40,6 +43,9 @@ impl<'de, N: Network> Deserialize<'de> for Deployment<N> {
                 // Parse the deployment from a string into a value.
                 let mut deployment = serde_json::Value::deserialize(deserializer)?;
 
+                // Retrieve the owner, if the deployment is signed.
+                let owner = serde_json::from_value(deployment["owner"].take()).map_err(de::Error::custom)?;
+
                 // Recover the deployment.
                 let deployment = Self::new(
                     // Retrieve the edition.
@@ -51,7 +57,7 @@ impl<'de, N: Network> Deserialize<'de> for Deployment<N> {
                 )
                 .map_err(de::Error::custom)?;
 
-                Ok(deployment)
+                Ok(Self { owner, ..deployment })
             }
             false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "deployment"),
         }
@@ -79,6 +85,21 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_serde_json_signed() -> Result<()> {
+        // Sample the signed deployment.
+        let expected = test_helpers::sample_signed_deployment();
+
+        // Serialize
+        let candidate_string = serde_json::to_string(&expected)?;
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+
+        // Deserialize
+        assert_eq!(expected, Deployment::from_str(&expected.to_string())?);
+
+        Ok(())
+    }
+
     #[test]
     fn test_bincode() -> Result<()> {
         // Sample the deployment.
@@ -83,7 +83,7 @@ impl<N: Network> Stack<N> {
 
         // Retrieve the next request, based on the call stack mode.
         let (request, call_stack) = match &call_stack {
-            CallStack::Evaluate(authorization) => (authorization.next()?, call_stack),
+            CallStack::Evaluate(authorization, ..) => (authorization.next()?, call_stack),
             CallStack::Execute(authorization, ..) => (authorization.peek_next()?, call_stack.replicate()),
             _ => bail!("Illegal operation: call stack must be `Evaluate` or `Execute` in `evaluate_function`."),
         };
@@ -157,6 +157,18 @@ impl<N: Network> Stack<N> {
             .collect::<Result<Vec<_>>>()?;
         lap!(timer, "Load the outputs");
 
+        // If the function has a finalize command, then store the inputs for finalize.
+        if let (Some(command), CallStack::Evaluate(_, finalize_inputs)) =
+            (function.finalize_command(), registers.call_stack())
+        {
+            // Load the inputs for finalize.
+            let inputs =
+                command.operands().iter().map(|operand| registers.load(self, operand)).collect::<Result<Vec<_>>>()?;
+            // Store the inputs for finalize.
+            finalize_inputs.write().push((*self.program.id(), *function.name(), inputs));
+            lap!(timer, "Load the inputs for finalize");
+        }
+
         finish!(timer);
 
         // Compute the response.
@@ -299,6 +299,13 @@ impl<N: Network> Stack<N> {
                                 function.name()
                             );
                         }
+                        circuit::Value::Plaintext(circuit::Plaintext::Array(..)) => {
+                            bail!(
+                                "'{}/{}' attempts to pass an 'array' into 'finalize'",
+                                self.program_id(),
+                                function.name()
+                            );
+                        }
                         circuit::Value::Record(..) => {
                             bail!(
                                 "'{}/{}' attempts to pass a 'record' into 'finalize'",
@@ -0,0 +1,41 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+/// The state of the block being finalized, which is accessible in the finalize scope.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FinalizeGlobalState {
+    /// The block height.
+    block_height: u32,
+    /// The block timestamp.
+    block_timestamp: i64,
+}
+
+impl FinalizeGlobalState {
+    /// Initializes a new finalize global state.
+    pub const fn new(block_height: u32, block_timestamp: i64) -> Self {
+        Self { block_height, block_timestamp }
+    }
+
+    /// Returns the block height.
+    pub const fn block_height(&self) -> u32 {
+        self.block_height
+    }
+
+    /// Returns the block timestamp.
+    pub const fn block_timestamp(&self) -> i64 {
+        self.block_timestamp
+    }
+}
@@ -16,43 +16,14 @@
 
 use super::*;
 
-impl<N: Network> FinalizeRegisters<N> {
-    /// Loads the literal of a given operand from the registers.
-    ///
-    /// # Errors
-    /// This method will halt if the given operand is not a literal.
-    /// This method will halt if the register locator is not found.
-    /// In the case of register members, this method will halt if the member is not found.
-    #[inline]
-    pub fn load_literal(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<Literal<N>> {
-        match self.load(stack, operand)? {
-            Value::Plaintext(Plaintext::Literal(literal, ..)) => Ok(literal),
-            Value::Plaintext(Plaintext::Struct(..)) => bail!("Operand must be a literal"),
-            Value::Record(..) => bail!("Operand must be a literal"),
-        }
-    }
-
-    /// Loads the plaintext of a given operand from the registers.
-    ///
-    /// # Errors
-    /// This method will halt if the given operand is not a plaintext.
-    /// This method will halt if the register locator is not found.
-    /// In the case of register members, this method will halt if the member is not found.
-    #[inline]
-    pub fn load_plaintext(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<Plaintext<N>> {
-        match self.load(stack, operand)? {
-            Value::Plaintext(plaintext) => Ok(plaintext),
-            Value::Record(..) => bail!("Operand must be a plaintext"),
-        }
-    }
-
+impl<N: Network> RegistersLoad<N> for FinalizeRegisters<N> {
     /// Loads the value of a given operand from the registers.
     ///
     /// # Errors
     /// This method will halt if the register locator is not found.
     /// In the case of register members, this method will halt if the member is not found.
     #[inline]
-    pub fn load(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<Value<N>> {
+    fn load(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<Value<N>> {
         // Retrieve the register.
         let register = match operand {
             // If the operand is a literal, return the literal.
@@ -65,6 +36,14 @@ impl<N: Network> FinalizeRegisters<N> {
             }
             // If the operand is the caller, load the value of the caller.
             Operand::Caller => bail!("Forbidden operation: Cannot use 'self.caller' in 'finalize'"),
+            // If the operand is the block height, load the block height.
+            Operand::BlockHeight => {
+                return Ok(Value::Plaintext(Plaintext::from(Literal::U32(U32::new(self.state.block_height())))));
+            }
+            // If the operand is the block timestamp, load the block timestamp.
+            Operand::BlockTimestamp => {
+                return Ok(Value::Plaintext(Plaintext::from(Literal::I64(I64::new(self.state.block_timestamp())))));
+            }
         };
 
         // Retrieve the stack value.
@@ -17,16 +17,19 @@
 mod load;
 mod store;
 
-use crate::{FinalizeTypes, Operand, Stack};
+use crate::{FinalizeGlobalState, FinalizeTypes, Operand, RegistersCaller, RegistersLoad, RegistersStore, Stack};
 use console::{
     network::prelude::*,
     program::{Entry, Literal, Plaintext, Register, Value},
+    types::{Address, Field, I64, U32},
 };
 
 use indexmap::IndexMap;
 
 #[derive(Clone)]
 pub struct FinalizeRegisters<N: Network> {
+    /// The global state for the finalize scope.
+    state: FinalizeGlobalState,
     /// The mapping of all registers to their defined types.
     finalize_types: FinalizeTypes<N>,
     /// The mapping of assigned registers to their values.
@@ -34,9 +37,29 @@ pub struct FinalizeRegisters<N: Network> {
 }
 
 impl<N: Network> FinalizeRegisters<N> {
-    /// Initializes a new set of registers, given the finalize types.
+    /// Initializes a new set of registers, given the finalize global state and finalize types.
     #[inline]
-    pub fn new(finalize_types: FinalizeTypes<N>) -> Self {
-        Self { finalize_types, registers: IndexMap::new() }
+    pub fn new(state: FinalizeGlobalState, finalize_types: FinalizeTypes<N>) -> Self {
+        Self { state, finalize_types, registers: IndexMap::new() }
+    }
+
+    /// Returns the global state for the finalize scope.
+    #[inline]
+    pub const fn state(&self) -> &FinalizeGlobalState {
+        &self.state
+    }
+}
+
+impl<N: Network> RegistersCaller<N> for FinalizeRegisters<N> {
+    /// Returns the transition caller.
+    #[inline]
+    fn caller(&self) -> Result<Address<N>> {
+        bail!("Forbidden operation: Cannot use 'self.caller' in 'finalize'")
+    }
+
+    /// Returns the transition view key.
+    #[inline]
+    fn tvk(&self) -> Result<Field<N>> {
+        bail!("Forbidden operation: Cannot use the transition view key in 'finalize'")
     }
 }
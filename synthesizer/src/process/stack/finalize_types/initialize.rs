@@ -31,9 +31,9 @@ impl<N: Network> FinalizeTypes<N> {
         }
 
         // Step 2. Check the commands are well-formed.
-        for command in finalize.commands() {
+        for (index, command) in finalize.commands().iter().enumerate() {
             // Check the command opcode, operands, and destinations.
-            finalize_types.check_command(stack, finalize.name(), command)?;
+            finalize_types.check_command(stack, finalize, index, command)?;
         }
 
         // Step 3. Check the outputs are well-formed.
@@ -42,6 +42,9 @@ impl<N: Network> FinalizeTypes<N> {
             finalize_types.check_output(stack, output.register(), &RegisterType::from(*output.finalize_type()))?;
         }
 
+        // Step 4. Check every register is assigned on all paths before it is read.
+        finalize_types.check_definite_assignment(stack, finalize)?;
+
         Ok(finalize_types)
     }
 }
@@ -109,6 +112,14 @@ impl<N: Network> FinalizeTypes<N> {
                     bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
                 }
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure the element struct is defined in the program.
+                if let PlaintextType::Struct(struct_name) = array_type.element_type() {
+                    if !stack.program().contains_struct(&struct_name) {
+                        bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
+                    }
+                }
+            }
             RegisterType::Record(identifier) => {
                 // Ensure the record type is defined in the program.
                 if !stack.program().contains_record(identifier) {
@@ -155,6 +166,14 @@ impl<N: Network> FinalizeTypes<N> {
                     bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
                 }
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure the element struct is defined in the program.
+                if let PlaintextType::Struct(struct_name) = array_type.element_type() {
+                    if !stack.program().contains_struct(&struct_name) {
+                        bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
+                    }
+                }
+            }
             RegisterType::Record(identifier) => {
                 // Ensure the record type is defined in the program.
                 if !stack.program().contains_record(identifier) {
@@ -182,11 +201,141 @@ impl<N: Network> FinalizeTypes<N> {
 
     /// Ensures the given command is well-formed.
     #[inline]
-    fn check_command(&mut self, stack: &Stack<N>, finalize_name: &Identifier<N>, command: &Command<N>) -> Result<()> {
+    fn check_command(
+        &mut self,
+        stack: &Stack<N>,
+        finalize: &Finalize<N>,
+        index: usize,
+        command: &Command<N>,
+    ) -> Result<()> {
+        // Retrieve the finalize name.
+        let finalize_name = finalize.name();
+
         match command {
             Command::Decrement(decrement) => self.check_decrement(stack, finalize_name, decrement)?,
             Command::Instruction(instruction) => self.check_instruction(stack, finalize_name, instruction)?,
             Command::Increment(increment) => self.check_increment(stack, finalize_name, increment)?,
+            Command::Get(get) => self.check_get(stack, finalize_name, get)?,
+            Command::GetOrInit(get_or_init) => self.check_get_or_init(stack, finalize_name, get_or_init)?,
+            Command::Set(set) => self.check_set(stack, finalize_name, set)?,
+            Command::Remove(remove) => self.check_remove(stack, finalize_name, remove)?,
+            Command::BranchEq(branch_eq) => self.check_branch(stack, finalize, index, branch_eq)?,
+            Command::BranchNeq(branch_neq) => self.check_branch(stack, finalize, index, branch_neq)?,
+            // Note: Positions are checked for uniqueness when they are added to the finalize.
+            Command::Position(..) => (),
+        }
+        Ok(())
+    }
+
+    /// Ensures the given branch command is well-formed.
+    /// Note: To guarantee termination, a branch may only jump forward to a position.
+    #[inline]
+    fn check_branch<const VARIANT: u8>(
+        &self,
+        stack: &Stack<N>,
+        finalize: &Finalize<N>,
+        index: usize,
+        branch: &Branch<N, VARIANT>,
+    ) -> Result<()> {
+        // Retrieve the index of the position.
+        let position = match finalize.positions().get(branch.position()) {
+            Some(position) => *position,
+            None => bail!(
+                "Position '{}' in '{}/{}' is not defined.",
+                branch.position(),
+                stack.program_id(),
+                finalize.name()
+            ),
+        };
+        // Ensure the branch jumps forward.
+        if position <= index {
+            bail!("Branch '{branch}' in '{}/{}' must jump forward to a position.", stack.program_id(), finalize.name())
+        }
+
+        // Retrieve the register types of the operands.
+        let first_type = self.get_type_from_operand(stack, branch.first())?;
+        let second_type = self.get_type_from_operand(stack, branch.second())?;
+        // Ensure the operands are of the same type.
+        if first_type != second_type {
+            bail!("Branch '{branch}' compares operands of different types: '{first_type}' and '{second_type}'")
+        }
+
+        Ok(())
+    }
+
+    /// Ensures every register read in the finalize is assigned on all paths that reach the read.
+    /// Note: As branches may only jump forward, a single pass over the commands suffices.
+    #[inline]
+    fn check_definite_assignment(&self, stack: &Stack<N>, finalize: &Finalize<N>) -> Result<()> {
+        // Initialize the registers that are assigned on every path to the current command.
+        let mut assigned: IndexSet<u64> = self.inputs.keys().copied().collect();
+        // Initialize a map of command indices to the registers assigned on every branch that jumps there.
+        let mut incoming: IndexMap<usize, IndexSet<u64>> = IndexMap::new();
+
+        // Ensures the given register is assigned on every path to the current command.
+        let ensure_assigned = |assigned: &IndexSet<u64>, register: &Register<N>| -> Result<()> {
+            match assigned.contains(&register.locator()) {
+                true => Ok(()),
+                false => bail!(
+                    "Register '{register}' in '{}/{}' is read before it is assigned on every path.",
+                    stack.program_id(),
+                    finalize.name()
+                ),
+            }
+        };
+
+        for (index, command) in finalize.commands().iter().enumerate() {
+            // If a branch jumps to this command, retain only the registers assigned on every incoming path.
+            if let Some(registers) = incoming.get(&index) {
+                assigned.retain(|locator| registers.contains(locator));
+            }
+
+            // Retrieve the operands and destinations of the command.
+            let (operands, destinations) = match command {
+                Command::Decrement(decrement) => (decrement.operands(), vec![]),
+                Command::Instruction(instruction) => (instruction.operands().to_vec(), instruction.destinations()),
+                Command::Increment(increment) => (increment.operands(), vec![]),
+                Command::Get(get) => (get.operands(), vec![get.destination().clone()]),
+                Command::GetOrInit(get_or_init) => (get_or_init.operands(), vec![get_or_init.destination().clone()]),
+                Command::Set(set) => (set.operands(), vec![]),
+                Command::Remove(remove) => (remove.operands(), vec![]),
+                Command::BranchEq(branch_eq) => (branch_eq.operands(), vec![]),
+                Command::BranchNeq(branch_neq) => (branch_neq.operands(), vec![]),
+                Command::Position(..) => (vec![], vec![]),
+            };
+
+            // Ensure the registers read by the command are assigned.
+            for operand in &operands {
+                if let Operand::Register(register) = operand {
+                    ensure_assigned(&assigned, register)?;
+                }
+            }
+
+            // Record the registers assigned on the path taken by a branch.
+            let target = match command {
+                Command::BranchEq(branch_eq) => Some(branch_eq.position()),
+                Command::BranchNeq(branch_neq) => Some(branch_neq.position()),
+                _ => None,
+            };
+            if let Some(position) = target {
+                // Note: The position is checked to exist in `check_branch`.
+                if let Some(position) = finalize.positions().get(position) {
+                    match incoming.get_mut(position) {
+                        Some(registers) => registers.retain(|locator| assigned.contains(locator)),
+                        None => {
+                            incoming.insert(*position, assigned.clone());
+                        }
+                    }
+                }
+            }
+
+            // Mark the destination registers as assigned.
+            assigned.extend(destinations.iter().map(|register| register.locator()));
+        }
+
+        // Ensure the registers read by the outputs are assigned.
+        for output in finalize.outputs() {
+            ensure_assigned(&assigned, output.register())?;
         }
         Ok(())
     }
@@ -216,7 +365,7 @@ impl<N: Network> FinalizeTypes<N> {
         match value_type {
             RegisterType::Plaintext(PlaintextType::Literal(literal_type)) => {
                 match literal_type {
-                    LiteralType::Address | LiteralType::Boolean | LiteralType::String => {
+                    LiteralType::Address | LiteralType::Boolean | LiteralType::Signature | LiteralType::String => {
                         bail!("Decrement cannot decrement by a(n) '{literal_type}' (found at '{decrement}')")
                     }
                     // These literal types are valid for the 'decrement' command.
@@ -238,6 +387,9 @@ impl<N: Network> FinalizeTypes<N> {
             RegisterType::Plaintext(PlaintextType::Struct(..)) => {
                 bail!("Decrement cannot decrement by an 'struct' (found at '{decrement}')")
             }
+            RegisterType::Plaintext(PlaintextType::Array(..)) => {
+                bail!("Decrement cannot decrement by an 'array' (found at '{decrement}')")
+            }
             RegisterType::Record(..) => bail!("Decrement cannot decrement by a 'record' (found at '{decrement}')"),
             RegisterType::ExternalRecord(..) => {
                 bail!("Decrement cannot decrement by an 'external record' (found at '{decrement}')")
@@ -272,7 +424,7 @@ impl<N: Network> FinalizeTypes<N> {
         match value_type {
             RegisterType::Plaintext(PlaintextType::Literal(literal_type)) => {
                 match literal_type {
-                    LiteralType::Address | LiteralType::Boolean | LiteralType::String => {
+                    LiteralType::Address | LiteralType::Boolean | LiteralType::Signature | LiteralType::String => {
                         bail!("Increment cannot increment by a(n) '{literal_type}' (found at '{increment}')")
                     }
                     // These literal types are valid for the 'increment' command.
@@ -294,6 +446,9 @@ impl<N: Network> FinalizeTypes<N> {
             RegisterType::Plaintext(PlaintextType::Struct(..)) => {
                 bail!("Increment cannot increment by an 'struct' (found at '{increment}')")
             }
+            RegisterType::Plaintext(PlaintextType::Array(..)) => {
+                bail!("Increment cannot increment by an 'array' (found at '{increment}')")
+            }
             RegisterType::Record(..) => bail!("Increment cannot increment by a 'record' (found at '{increment}')"),
             RegisterType::ExternalRecord(..) => {
                 bail!("Increment cannot increment by an 'external record' (found at '{increment}')")
@@ -303,6 +458,207 @@ impl<N: Network> FinalizeTypes<N> {
         Ok(())
     }
 
+    /// Ensures the given get command is well-formed.
+    #[inline]
+    fn check_get(&mut self, stack: &Stack<N>, finalize_name: &Identifier<N>, get: &Get<N>) -> Result<()> {
+        // Retrieve the mapping from the program, or from the imported program.
+        let mapping = match get.mapping() {
+            CallOperator::Locator(locator) => {
+                // Ensure the locator does not reference the program itself.
+                ensure!(
+                    locator.program_id() != stack.program_id(),
+                    "Mapping '{locator}' in '{}/{finalize_name}' must be referenced by name.",
+                    stack.program_id()
+                );
+                // Ensure the program is imported.
+                if !stack.program().contains_import(locator.program_id()) {
+                    bail!(
+                        "Program '{}' in '{}/{finalize_name}' is not imported.",
+                        locator.program_id(),
+                        stack.program_id()
+                    )
+                }
+                // Retrieve the imported program.
+                let external_program = stack.get_external_program(locator.program_id())?;
+                // Ensure the declared mapping in get is defined in the imported program.
+                if !external_program.contains_mapping(locator.resource()) {
+                    bail!("Mapping '{locator}' in '{}/{finalize_name}' is not defined.", stack.program_id())
+                }
+                // Retrieve the mapping from the imported program.
+                let mapping = external_program.get_mapping(locator.resource())?;
+                // Ensure the key and value types are defined identically in the program.
+                Self::check_external_type(stack, external_program, mapping.key().finalize_type())?;
+                Self::check_external_type(stack, external_program, mapping.value().finalize_type())?;
+                mapping
+            }
+            CallOperator::Resource(mapping_name) => {
+                // Ensure the declared mapping in get is defined in the program.
+                if !stack.program().contains_mapping(mapping_name) {
+                    bail!("Mapping '{mapping_name}' in '{}/{finalize_name}' is not defined.", stack.program_id())
+                }
+                // Retrieve the mapping from the program.
+                stack.program().get_mapping(mapping_name)?
+            }
+        };
+
+        // Retrieve the register type of the key.
+        let key_type = self.get_type_from_operand(stack, get.key())?;
+        // Ensure the key type matches the mapping key type.
+        let mapping_key_type = RegisterType::from(*mapping.key().finalize_type());
+        if key_type != mapping_key_type {
+            bail!("Key type in 'get' is incorrect. Expected '{mapping_key_type}', found '{key_type}' (at '{get}')")
+        }
+
+        // Insert the destination register, with the type of the mapping value.
+        self.add_destination(get.destination().clone(), RegisterType::from(*mapping.value().finalize_type()))
+    }
+
+    /// Ensures the given type, declared in the given imported program, has the same meaning in the program.
+    /// Literals are always valid, while structs must be defined identically in both programs.
+    fn check_external_type(
+        stack: &Stack<N>,
+        external_program: &Program<N>,
+        finalize_type: &FinalizeType<N>,
+    ) -> Result<()> {
+        match finalize_type {
+            FinalizeType::Public(plaintext_type) => {
+                Self::check_external_plaintext_type(stack, external_program, plaintext_type)
+            }
+            FinalizeType::Record(..) | FinalizeType::ExternalRecord(..) => {
+                bail!("Mapping type '{finalize_type}' in '{}' must be a plaintext type.", external_program.id())
+            }
+        }
+    }
+
+    /// Ensures the given plaintext type, declared in the given imported program, has the same meaning in the program.
+    fn check_external_plaintext_type(
+        stack: &Stack<N>,
+        external_program: &Program<N>,
+        plaintext_type: &PlaintextType<N>,
+    ) -> Result<()> {
+        match plaintext_type {
+            PlaintextType::Literal(..) => Ok(()),
+            PlaintextType::Struct(struct_name) => {
+                // Retrieve the struct from the imported program.
+                let external_struct = external_program.get_struct(struct_name)?;
+                // Ensure the struct is defined identically in the program.
+                match stack.program().get_struct(struct_name) {
+                    Ok(struct_) if struct_ == external_struct => (),
+                    _ => bail!(
+                        "Struct '{struct_name}' in '{}' is not defined identically in '{}'.",
+                        external_program.id(),
+                        stack.program_id()
+                    ),
+                }
+                // Ensure the struct members are defined identically in the program.
+                for member_type in external_struct.members().values() {
+                    Self::check_external_plaintext_type(stack, external_program, member_type)?;
+                }
+                Ok(())
+            }
+            PlaintextType::Array(array_type) => {
+                Self::check_external_plaintext_type(stack, external_program, &array_type.element_type())
+            }
+        }
+    }
+
+    /// Ensures the given get.or_init command is well-formed.
+    #[inline]
+    fn check_get_or_init(
+        &mut self,
+        stack: &Stack<N>,
+        finalize_name: &Identifier<N>,
+        get_or_init: &GetOrInit<N>,
+    ) -> Result<()> {
+        // Ensure the declared mapping in get.or_init is defined in the program.
+        if !stack.program().contains_mapping(get_or_init.mapping_name()) {
+            bail!(
+                "Mapping '{}' in '{}/{finalize_name}' is not defined.",
+                get_or_init.mapping_name(),
+                stack.program_id()
+            )
+        }
+        // Retrieve the mapping from the program.
+        let mapping = stack.program().get_mapping(get_or_init.mapping_name())?;
+
+        // Retrieve the register type of the key.
+        let key_type = self.get_type_from_operand(stack, get_or_init.key())?;
+        // Ensure the key type matches the mapping key type.
+        let mapping_key_type = RegisterType::from(*mapping.key().finalize_type());
+        if key_type != mapping_key_type {
+            bail!(
+                "Key type in 'get.or_init' is incorrect. Expected '{mapping_key_type}', found '{key_type}' (at '{get_or_init}')"
+            )
+        }
+
+        // Retrieve the register type of the default value.
+        let default_type = self.get_type_from_operand(stack, get_or_init.default())?;
+        // Ensure the default value type matches the mapping value type.
+        let mapping_value_type = RegisterType::from(*mapping.value().finalize_type());
+        if default_type != mapping_value_type {
+            bail!(
+                "Default value type in 'get.or_init' is incorrect. Expected '{mapping_value_type}', found '{default_type}' (at '{get_or_init}')"
+            )
+        }
+
+        // Insert the destination register, with the type of the mapping value.
+        self.add_destination(get_or_init.destination().clone(), mapping_value_type)
+    }
+
+    /// Ensures the given set command is well-formed.
+    #[inline]
+    fn check_set(&self, stack: &Stack<N>, finalize_name: &Identifier<N>, set: &Set<N>) -> Result<()> {
+        // Ensure the declared mapping in set is defined in the program.
+        if !stack.program().contains_mapping(set.mapping_name()) {
+            bail!("Mapping '{}' in '{}/{finalize_name}' is not defined.", set.mapping_name(), stack.program_id())
+        }
+        // Retrieve the mapping from the program.
+        let mapping = stack.program().get_mapping(set.mapping_name())?;
+
+        // Retrieve the register type of the key.
+        let key_type = self.get_type_from_operand(stack, set.key())?;
+        // Ensure the key type matches the mapping key type.
+        let mapping_key_type = RegisterType::from(*mapping.key().finalize_type());
+        if key_type != mapping_key_type {
+            bail!("Key type in 'set' is incorrect. Expected '{mapping_key_type}', found '{key_type}' (at '{set}')")
+        }
+
+        // Retrieve the register type of the value.
+        let value_type = self.get_type_from_operand(stack, set.value())?;
+        // Ensure the value type matches the mapping value type.
+        let mapping_value_type = RegisterType::from(*mapping.value().finalize_type());
+        if value_type != mapping_value_type {
+            bail!(
+                "Value type in 'set' is incorrect. Expected '{mapping_value_type}', found '{value_type}' (at '{set}')"
+            )
+        }
+
+        Ok(())
+    }
+
+    /// Ensures the given remove command is well-formed.
+    #[inline]
+    fn check_remove(&self, stack: &Stack<N>, finalize_name: &Identifier<N>, remove: &Remove<N>) -> Result<()> {
+        // Ensure the declared mapping in remove is defined in the program.
+        if !stack.program().contains_mapping(remove.mapping_name()) {
+            bail!("Mapping '{}' in '{}/{finalize_name}' is not defined.", remove.mapping_name(), stack.program_id())
+        }
+        // Retrieve the mapping from the program.
+        let mapping = stack.program().get_mapping(remove.mapping_name())?;
+
+        // Retrieve the register type of the key.
+        let key_type = self.get_type_from_operand(stack, remove.key())?;
+        // Ensure the key type matches the mapping key type.
+        let mapping_key_type = RegisterType::from(*mapping.key().finalize_type());
+        if key_type != mapping_key_type {
+            bail!(
+                "Key type in 'remove' is incorrect. Expected '{mapping_key_type}', found '{key_type}' (at '{remove}')"
+            )
+        }
+
+        Ok(())
+    }
+
     /// Ensures the given instruction is well-formed.
     #[inline]
     fn check_instruction(
@@ -351,7 +707,10 @@ impl<N: Network> FinalizeTypes<N> {
                 // Ensure the opcode **is** a reserved opcode.
                 ensure!(Program::<N>::is_reserved_opcode(opcode), "'{opcode}' is not an opcode.");
                 // Ensure the instruction is not the cast operation.
-                ensure!(!matches!(instruction, Instruction::Cast(..)), "Instruction '{instruction}' is a 'cast'.");
+                ensure!(
+                    !matches!(instruction, Instruction::Cast(..) | Instruction::CastLossy(..)),
+                    "Instruction '{instruction}' is a 'cast'."
+                );
                 // Ensure the instruction has one destination register.
                 ensure!(
                     instruction.destinations().len() == 1,
@@ -379,11 +738,12 @@ impl<N: Network> FinalizeTypes<N> {
             Opcode::Call => {
                 bail!("Instruction 'call' is not allowed in 'finalize'");
             }
-            Opcode::Cast => {
-                // Retrieve the cast operation.
-                let operation = match instruction {
-                    Instruction::Cast(operation) => operation,
-                    _ => bail!("Instruction '{instruction}' is not a cast operation."),
+            Opcode::Cast(opcode) => {
+                // Retrieve the casted register type.
+                let register_type = match instruction {
+                    Instruction::Cast(operation) if opcode == "cast" => operation.register_type(),
+                    Instruction::CastLossy(operation) if opcode == "cast.lossy" => operation.register_type(),
+                    _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 };
 
                 // Ensure the instruction has one destination register.
@@ -393,9 +753,13 @@ impl<N: Network> FinalizeTypes<N> {
                 );
 
                 // Ensure the casted register type is defined.
-                match operation.register_type() {
+                match register_type {
                     RegisterType::Plaintext(PlaintextType::Literal(..)) => {
-                        bail!("Casting to literal is currently unsupported")
+                        // Ensure the instruction has exactly one operand.
+                        ensure!(
+                            instruction.operands().len() == 1,
+                            "Instruction '{instruction}' must have exactly one operand."
+                        );
                     }
                     RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
                         // Ensure the struct name exists in the program.
@@ -407,6 +771,10 @@ impl<N: Network> FinalizeTypes<N> {
                         // Ensure the operand types match the struct.
                         self.matches_struct(stack, instruction.operands(), &struct_)?;
                     }
+                    RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                        // Ensure the operand types match the array type.
+                        self.matches_array(stack, instruction.operands(), array_type)?;
+                    }
                     RegisterType::Record(..) => {
                         bail!("Unsupported operation: Cannot cast to a record (yet).")
                         // // Ensure the record type is defined in the program.
@@ -484,6 +852,9 @@ impl<N: Network> FinalizeTypes<N> {
                     "hash.psd2",
                     "hash.psd4",
                     "hash.psd8",
+                    "hash.keccak256",
+                    "hash.sha3_256",
+                    "hash.sha3_512",
                 ]
                 .contains(&opcode)
                 {
@@ -527,6 +898,18 @@ impl<N: Network> FinalizeTypes<N> {
                         matches!(instruction, Instruction::HashPSD8(..)),
                         "Instruction '{instruction}' is not for opcode '{opcode}'."
                     ),
+                    "hash.keccak256" => ensure!(
+                        matches!(instruction, Instruction::HashKeccak256(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    "hash.sha3_256" => ensure!(
+                        matches!(instruction, Instruction::HashSha3_256(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    "hash.sha3_512" => ensure!(
+                        matches!(instruction, Instruction::HashSha3_512(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
                     _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 }
             }
@@ -548,6 +931,13 @@ impl<N: Network> FinalizeTypes<N> {
                     _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 }
             }
+            Opcode::Sign => {
+                // Ensure the instruction is the correct one.
+                ensure!(
+                    matches!(instruction, Instruction::SignVerify(..)),
+                    "Instruction '{instruction}' is not for opcode 'sign.verify'."
+                );
+            }
         }
         Ok(())
     }
@@ -84,11 +84,53 @@ impl<N: Network> FinalizeTypes<N> {
                         "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{caller_type}' in the operand '{operand}'.",
                     )
                 }
+                // Ensure the block operand type matches the member type.
+                Operand::BlockHeight | Operand::BlockTimestamp => {
+                    // Retrieve the block operand type.
+                    let block_type = self.get_type_from_operand(stack, operand)?;
+                    // Ensure the block operand type matches the member type.
+                    ensure!(
+                        block_type == RegisterType::Plaintext(*member_type),
+                        "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{block_type}' in the operand '{operand}'.",
+                    )
+                }
             }
         }
         Ok(())
     }
 
+    /// Checks that the given operands matches the layout of the array. The ordering of the operands matters.
+    pub fn matches_array(&self, stack: &Stack<N>, operands: &[Operand<N>], array_type: &ArrayType<N>) -> Result<()> {
+        // Ensure the number of elements does not exceed the maximum.
+        let num_elements = operands.len();
+        ensure!(num_elements <= N::MAX_DATA_ENTRIES, "'{array_type}' cannot exceed {} entries", N::MAX_DATA_ENTRIES);
+
+        // Ensure the number of elements match.
+        let expected_num_elements = array_type.length() as usize;
+        if expected_num_elements != num_elements {
+            bail!("'{array_type}' expected {expected_num_elements} elements, found {num_elements} elements")
+        }
+
+        // Retrieve the element type.
+        let element_type = array_type.element_type();
+        // Ensure the element struct is defined in the program.
+        if let PlaintextType::Struct(struct_name) = element_type {
+            ensure!(stack.program().contains_struct(&struct_name), "Struct '{struct_name}' is not defined.");
+        }
+
+        // Ensure the operand types match the element type.
+        for operand in operands {
+            // Retrieve the operand type.
+            let operand_type = self.get_type_from_operand(stack, operand)?;
+            // Ensure the operand type matches the element type.
+            ensure!(
+                operand_type == RegisterType::Plaintext(element_type),
+                "Array '{array_type}' expects elements of type {element_type}, but found '{operand_type}' in the operand '{operand}'.",
+            )
+        }
+        Ok(())
+    }
+
     /// Checks that the given record matches the layout of the record type.
     /// Note: Ordering for `owner` and `gates` **does** matter, however ordering
     /// for record data does **not** matter, as long as all defined members are present.
@@ -127,6 +169,10 @@ impl<N: Network> FinalizeTypes<N> {
                 bail!("Forbidden operation: Cannot cast a program ID ('{program_id}') as a record owner")
             }
             Operand::Caller => {}
+            // These operand types are never an `address` type.
+            Operand::BlockHeight | Operand::BlockTimestamp => {
+                bail!("Casting to a record requires the first operand to be an address")
+            }
         }
 
         // Ensure the second input type is a u64.
@@ -147,7 +193,7 @@ impl<N: Network> FinalizeTypes<N> {
                 )
             }
             // These operand types are never a `u64` type.
-            Operand::ProgramID(..) | Operand::Caller => {
+            Operand::ProgramID(..) | Operand::Caller | Operand::BlockHeight | Operand::BlockTimestamp => {
                 bail!("Casting to a record requires the second operand to be a u64")
             }
         }
@@ -212,6 +258,16 @@ impl<N: Network> FinalizeTypes<N> {
                                 "Record entry '{record_name}.{entry_name}' expects a '{plaintext_type}', but found '{caller_type}' in the operand '{operand}'.",
                             )
                         }
+                        // Ensure the block operand type matches the entry type.
+                        Operand::BlockHeight | Operand::BlockTimestamp => {
+                            // Retrieve the block operand type.
+                            let block_type = self.get_type_from_operand(stack, operand)?;
+                            // Ensure the block operand type matches the entry type.
+                            ensure!(
+                                block_type == RegisterType::Plaintext(*plaintext_type),
+                                "Record entry '{record_name}.{entry_name}' expects a '{plaintext_type}', but found '{block_type}' in the operand '{operand}'.",
+                            )
+                        }
                     }
                 }
             }
@@ -18,7 +18,8 @@ mod initialize;
 mod matches;
 
 use crate::{
-    finalize::{Command, Decrement, Finalize, Increment},
+    finalize::{Branch, Command, Decrement, Finalize, Get, GetOrInit, Increment, Remove, Set},
+    CallOperator,
     Instruction,
     Opcode,
     Operand,
@@ -27,10 +28,22 @@ use crate::{
 };
 use console::{
     network::prelude::*,
-    program::{EntryType, Identifier, LiteralType, PlaintextType, RecordType, Register, RegisterType, Struct},
+    program::{
+        Access,
+        ArrayType,
+        EntryType,
+        FinalizeType,
+        Identifier,
+        LiteralType,
+        PlaintextType,
+        RecordType,
+        Register,
+        RegisterType,
+        Struct,
+    },
 };
 
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 
 #[derive(Clone, Default, PartialEq, Eq)]
 pub struct FinalizeTypes<N: Network> {
@@ -68,7 +81,9 @@ impl<N: Network> FinalizeTypes<N> {
             Operand::Literal(literal) => RegisterType::Plaintext(PlaintextType::from(literal.to_type())),
             Operand::Register(register) => self.get_type(stack, register)?,
             Operand::ProgramID(_) => RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address)),
-            Operand::Caller => RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address)),
+            Operand::Caller => bail!("Forbidden operation: Cannot use 'self.caller' in 'finalize'"),
+            Operand::BlockHeight => RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U32)),
+            Operand::BlockTimestamp => RegisterType::Plaintext(PlaintextType::Literal(LiteralType::I64)),
         })
     }
 
@@ -100,11 +115,34 @@ impl<N: Network> FinalizeTypes<N> {
         };
 
         // Traverse the member path to find the register type.
-        for path_name in path.iter() {
+        for access in path.iter() {
+            // Retrieve the member name, or traverse into the array if the access is an index.
+            let path_name = match access {
+                Access::Member(path_name) => path_name,
+                Access::Index(index) => {
+                    // Update the register type to the element type.
+                    register_type = match &register_type {
+                        RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                            // Ensure the index is within bounds.
+                            ensure!(
+                                *index < array_type.length(),
+                                "'{register}' indexes out of bounds of '{array_type}'"
+                            );
+                            RegisterType::Plaintext(array_type.element_type())
+                        }
+                        _ => bail!("'{register}' indexes into a non-array type."),
+                    };
+                    continue;
+                }
+            };
             // Update the register type at each step.
             register_type = match &register_type {
                 // Ensure the plaintext type is not a literal, as the register references a member.
                 RegisterType::Plaintext(PlaintextType::Literal(..)) => bail!("'{register}' references a literal."),
+                // Ensure the plaintext type is not an array, as the register references a member.
+                RegisterType::Plaintext(PlaintextType::Array(..)) => {
+                    bail!("'{register}' references a member of an array.")
+                }
                 // Traverse the member path to output the register type.
                 RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
                     // Retrieve the member type from the struct.
@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Stack<N> {
+    /// Returns the cost (in gates) of the given function, including the base cost of its finalize commands.
+    /// Note: This does not include the cost of any functions called by the given function,
+    /// as each call produces its own transition, nor the storage cost of the mapping writes in finalize.
+    #[inline]
+    pub fn get_function_cost(&self, function_name: &Identifier<N>) -> Result<u64> {
+        // Retrieve the function.
+        let function = self.get_function(function_name)?;
+        // Compute the cost of the instructions.
+        let cost = self.get_instructions_cost(function.instructions())?;
+        // Compute the cost of the finalize commands, if they exist.
+        let commands = function.finalize().map(|(_, finalize)| finalize.commands()).unwrap_or_default();
+        commands
+            .iter()
+            .try_fold(cost, |cost, command| cost.checked_add(command.cost()))
+            .ok_or_else(|| anyhow!("The cost of '{function_name}' overflowed"))
+    }
+
+    /// Returns the cost (in gates) of the given instructions, including the instructions of any called closures.
+    fn get_instructions_cost(&self, instructions: &[Instruction<N>]) -> Result<u64> {
+        instructions.iter().try_fold(0u64, |cost, instruction| {
+            // Compute the cost of the instruction.
+            let instruction_cost = match instruction {
+                // If this is a closure call, include the cost of the closure.
+                Instruction::Call(call) if !call.is_function_call(self)? => {
+                    let closure_cost = match call.operator() {
+                        CallOperator::Locator(locator) => {
+                            let external_stack = self.get_external_stack(locator.program_id())?;
+                            let closure = external_stack.program().get_closure(locator.resource())?;
+                            external_stack.get_instructions_cost(closure.instructions())?
+                        }
+                        CallOperator::Resource(resource) => {
+                            self.get_instructions_cost(self.program.get_closure(resource)?.instructions())?
+                        }
+                    };
+                    match instruction.cost().checked_add(closure_cost) {
+                        Some(instruction_cost) => instruction_cost,
+                        None => bail!("The cost of the closure call overflowed"),
+                    }
+                }
+                _ => instruction.cost(),
+            };
+            cost.checked_add(instruction_cost).ok_or_else(|| anyhow!("The cost of the instructions overflowed"))
+        })
+    }
+}
@@ -29,6 +29,9 @@ impl<N: Network> Stack<N> {
             universal_srs: process.universal_srs().clone(),
             proving_keys: Default::default(),
             verifying_keys: Default::default(),
+            edition: N::EDITION,
+            owner: None,
+            retired_stacks: Default::default(),
         };
 
         // Add all of the imports into the stack.
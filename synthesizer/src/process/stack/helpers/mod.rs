@@ -16,7 +16,9 @@
 
 use super::*;
 
+mod cost;
 mod initialize;
 mod matches;
 mod sample;
 mod synthesize;
+mod upgrade;
@@ -0,0 +1,93 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Stack<N> {
+    /// Ensures the given program is a compatible upgrade of the program in this stack.
+    /// An upgrade must preserve all existing mappings, structs, records, and closures, as well as
+    /// the input and output types of all existing functions. New declarations may be added.
+    ///
+    /// Note: The closures are preserved, as the programs that import this program inline them into their circuits.
+    #[inline]
+    pub fn check_upgrade(&self, program: &Program<N>) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = self.program.id();
+
+        // Ensure the program ID matches.
+        ensure!(program.id() == program_id, "Cannot upgrade '{program_id}' into '{}'", program.id());
+        // Ensure the program has changed.
+        ensure!(program != &self.program, "Cannot upgrade '{program_id}' with an identical program");
+
+        // Ensure the existing mappings are preserved.
+        for (name, mapping) in self.program.mappings() {
+            match program.mappings().get(name) {
+                Some(candidate) if candidate == mapping => (),
+                Some(_) => bail!("Cannot upgrade '{program_id}' because mapping '{name}' has changed"),
+                None => bail!("Cannot upgrade '{program_id}' because mapping '{name}' is missing"),
+            }
+        }
+        // Ensure the existing structs are preserved.
+        for (name, struct_) in self.program.structs() {
+            match program.structs().get(name) {
+                Some(candidate) if candidate == struct_ => (),
+                Some(_) => bail!("Cannot upgrade '{program_id}' because struct '{name}' has changed"),
+                None => bail!("Cannot upgrade '{program_id}' because struct '{name}' is missing"),
+            }
+        }
+        // Ensure the existing records are preserved.
+        for (name, record) in self.program.records() {
+            match program.records().get(name) {
+                Some(candidate) if candidate == record => (),
+                Some(_) => bail!("Cannot upgrade '{program_id}' because record '{name}' has changed"),
+                None => bail!("Cannot upgrade '{program_id}' because record '{name}' is missing"),
+            }
+        }
+        // Ensure the existing closures are preserved.
+        for (name, closure) in self.program.closures() {
+            match program.closures().get(name) {
+                Some(candidate) if candidate == closure => (),
+                Some(_) => bail!("Cannot upgrade '{program_id}' because closure '{name}' has changed"),
+                None => bail!("Cannot upgrade '{program_id}' because closure '{name}' is missing"),
+            }
+        }
+        // Ensure the existing function signatures are preserved.
+        for (name, function) in self.program.functions() {
+            match program.functions().get(name) {
+                Some(candidate) => {
+                    // Ensure the input types are unchanged.
+                    if candidate.input_types() != function.input_types() {
+                        bail!("Cannot upgrade '{program_id}' because the inputs of function '{name}' have changed")
+                    }
+                    // Ensure the output types are unchanged.
+                    if candidate.output_types() != function.output_types() {
+                        bail!("Cannot upgrade '{program_id}' because the outputs of function '{name}' have changed")
+                    }
+                }
+                None => bail!("Cannot upgrade '{program_id}' because function '{name}' is missing"),
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if the stack imports the given program ID, directly or transitively.
+    #[inline]
+    pub(crate) fn imports_program(&self, program_id: &ProgramID<N>) -> bool {
+        self.external_stacks
+            .iter()
+            .any(|(import, external_stack)| import == program_id || external_stack.imports_program(program_id))
+    }
+}
@@ -26,6 +26,9 @@ pub use execution::*;
 mod fee;
 pub use fee::*;
 
+mod finalize_global_state;
+pub use finalize_global_state::*;
+
 mod finalize_registers;
 pub use finalize_registers::*;
 
@@ -41,6 +44,9 @@ pub use register_types::*;
 mod registers;
 pub use registers::*;
 
+mod traits;
+pub use traits::*;
+
 mod authorize;
 mod deploy;
 mod evaluate;
@@ -93,6 +99,9 @@ use std::sync::Arc;
 
 pub type Assignments<N> = Arc<RwLock<Vec<circuit::Assignment<<N as Environment>::Field>>>>;
 
+/// The inputs for finalize of each evaluated function call, as `(program ID, function name, inputs)`.
+pub type FinalizeInputs<N> = Arc<RwLock<Vec<(ProgramID<N>, Identifier<N>, Vec<Value<N>>)>>>;
+
 #[derive(Copy, Clone, Debug)]
 pub struct CallMetrics<N: Network> {
     pub program_id: ProgramID<N>,
@@ -108,14 +117,14 @@ pub enum CallStack<N: Network> {
     Authorize(Vec<Request<N>>, PrivateKey<N>, Authorization<N>),
     Synthesize(Vec<Request<N>>, PrivateKey<N>, Authorization<N>),
     CheckDeployment(Vec<Request<N>>, PrivateKey<N>, Assignments<N>),
-    Evaluate(Authorization<N>),
+    Evaluate(Authorization<N>, FinalizeInputs<N>),
     Execute(Authorization<N>, Arc<RwLock<Execution<N>>>, Arc<RwLock<Inclusion<N>>>, Arc<RwLock<Vec<CallMetrics<N>>>>),
 }
 
 impl<N: Network> CallStack<N> {
     /// Initializes a call stack as `Self::Evaluate`.
     pub fn evaluate(authorization: Authorization<N>) -> Result<Self> {
-        Ok(CallStack::Evaluate(authorization))
+        Ok(CallStack::Evaluate(authorization, Default::default()))
     }
 
     /// Initializes a call stack as `Self::Execute`.
@@ -144,7 +153,10 @@ impl<N: Network> CallStack<N> {
                 *private_key,
                 Arc::new(RwLock::new(assignments.read().clone())),
             ),
-            CallStack::Evaluate(authorization) => CallStack::Evaluate(authorization.replicate()),
+            CallStack::Evaluate(authorization, finalize_inputs) => CallStack::Evaluate(
+                authorization.replicate(),
+                Arc::new(RwLock::new(finalize_inputs.read().clone())),
+            ),
             CallStack::Execute(authorization, execution, inclusion, metrics) => CallStack::Execute(
                 authorization.replicate(),
                 Arc::new(RwLock::new(execution.read().clone())),
@@ -160,7 +172,7 @@ impl<N: Network> CallStack<N> {
             CallStack::Authorize(requests, ..) => requests.push(request),
             CallStack::Synthesize(requests, ..) => requests.push(request),
             CallStack::CheckDeployment(requests, ..) => requests.push(request),
-            CallStack::Evaluate(authorization) => authorization.push(request),
+            CallStack::Evaluate(authorization, ..) => authorization.push(request),
             CallStack::Execute(authorization, ..) => authorization.push(request),
         }
         Ok(())
@@ -174,7 +186,7 @@ impl<N: Network> CallStack<N> {
             | CallStack::CheckDeployment(requests, ..) => {
                 requests.pop().ok_or_else(|| anyhow!("No more requests on the stack"))
             }
-            CallStack::Evaluate(authorization) => authorization.next(),
+            CallStack::Evaluate(authorization, ..) => authorization.next(),
             CallStack::Execute(authorization, ..) => authorization.next(),
         }
     }
@@ -187,7 +199,7 @@ impl<N: Network> CallStack<N> {
             | CallStack::CheckDeployment(requests, ..) => {
                 requests.last().cloned().ok_or_else(|| anyhow!("No more requests on the stack"))
             }
-            CallStack::Evaluate(authorization) => authorization.peek_next(),
+            CallStack::Evaluate(authorization, ..) => authorization.peek_next(),
             CallStack::Execute(authorization, ..) => authorization.peek_next(),
         }
     }
@@ -209,6 +221,12 @@ pub struct Stack<N: Network> {
     proving_keys: Arc<RwLock<IndexMap<Identifier<N>, ProvingKey<N>>>>,
     /// The mapping of function name to verifying key.
     verifying_keys: Arc<RwLock<IndexMap<Identifier<N>, VerifyingKey<N>>>>,
+    /// The edition of the program.
+    edition: u16,
+    /// The owner of the program, who is authorized to upgrade it.
+    owner: Option<Address<N>>,
+    /// The mapping of previous editions to their stacks, which retain their programs and verifying keys.
+    retired_stacks: IndexMap<u16, Stack<N>>,
 }
 
 impl<N: Network> Stack<N> {
@@ -219,6 +237,55 @@ impl<N: Network> Stack<N> {
         let program_id = program.id();
         // Ensure the program does not already exist in the process.
         ensure!(!process.contains_program(program_id), "Program '{program_id}' already exists");
+        // Ensure the program is well-formed.
+        Self::check_program(program)?;
+        // Return the stack.
+        Stack::initialize(process, program)
+    }
+
+    /// Initializes a new stack for the next edition of an existing program, given the process and the program.
+    #[inline]
+    pub fn new_upgrade(process: &Process<N>, program: &Program<N>) -> Result<Self> {
+        // Retrieve the program ID.
+        let program_id = program.id();
+        // Ensure the program is not 'credits.aleo'.
+        ensure!(program_id != &ProgramID::from_str("credits.aleo")?, "Cannot upgrade 'credits.aleo'");
+        // Retrieve the existing stack.
+        let existing = match process.contains_program(program_id) {
+            true => process.get_stack(program_id)?,
+            false => bail!("Cannot upgrade program '{program_id}' because it does not exist"),
+        };
+        // Ensure the program is a compatible upgrade of the existing program.
+        existing.check_upgrade(program)?;
+        // Ensure none of the imports depend on the program being upgraded.
+        for import in program.imports().keys() {
+            if process.contains_program(import) && process.get_stack(import)?.imports_program(program_id) {
+                bail!("Cannot upgrade program '{program_id}' because its import '{import}' depends on it")
+            }
+        }
+        // Ensure the program is well-formed.
+        Self::check_program(program)?;
+
+        // Initialize the stack.
+        let mut stack = Stack::initialize(process, program)?;
+        // Increment the edition.
+        stack.edition = match existing.edition.checked_add(1) {
+            Some(edition) => edition,
+            None => bail!("Program '{program_id}' has reached the maximum number of editions"),
+        };
+        // Preserve the owner of the program.
+        stack.owner = existing.owner;
+        // Retire the existing edition, so the executions of previous editions can still be verified.
+        stack.retired_stacks = existing.retired_stacks.clone();
+        stack.retired_stacks.insert(existing.edition, existing.to_retired());
+        // Return the stack.
+        Ok(stack)
+    }
+
+    /// Ensures the given program is well-formed.
+    fn check_program(program: &Program<N>) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = program.id();
         // Ensure the program network-level domain (NLD) is correct.
         ensure!(program_id.is_aleo(), "Program '{program_id}' has an incorrect network-level domain (NLD)");
         // Ensure the program contains functions.
@@ -234,8 +301,32 @@ impl<N: Network> Stack<N> {
         // Ensure the program deserializes from a string correctly.
         ensure!(program == &Program::from_str(&program_string)?, "Program string serialization failed");
 
+        Ok(())
+    }
+
+    /// Returns a copy of the stack, with its external stacks refreshed from the given process.
+    /// This is used to propagate an upgraded program to the programs that import it.
+    #[inline]
+    pub(crate) fn reload(&self, process: &Process<N>) -> Result<Self> {
+        // Initialize the stack.
+        let mut stack = Stack::initialize(process, &self.program)?;
+        // Preserve the edition, owner, keys, and retired editions, as the function circuits are unchanged.
+        stack.edition = self.edition;
+        stack.owner = self.owner;
+        stack.proving_keys = self.proving_keys.clone();
+        stack.verifying_keys = self.verifying_keys.clone();
+        stack.retired_stacks = self.retired_stacks.clone();
         // Return the stack.
-        Stack::initialize(process, program)
+        Ok(stack)
+    }
+
+    /// Returns a copy of the stack for its retired edition, without its proving keys and retired stacks.
+    fn to_retired(&self) -> Self {
+        let mut stack = self.clone();
+        stack.proving_keys = Default::default();
+        stack.verifying_keys = Arc::new(RwLock::new(self.verifying_keys.read().clone()));
+        stack.retired_stacks = Default::default();
+        stack
     }
 
     /// Returns the program.
@@ -250,6 +341,37 @@ impl<N: Network> Stack<N> {
         self.program.id()
     }
 
+    /// Returns the edition of the program.
+    #[inline]
+    pub const fn edition(&self) -> u16 {
+        self.edition
+    }
+
+    /// Returns the stack of the given edition of the program.
+    #[inline]
+    pub fn get_stack_for_edition(&self, edition: u16) -> Result<&Stack<N>> {
+        match edition == self.edition {
+            true => Ok(self),
+            false => match self.retired_stacks.get(&edition) {
+                Some(stack) => Ok(stack),
+                None => bail!("Edition {edition} of program '{}' does not exist", self.program.id()),
+            },
+        }
+    }
+
+    /// Returns the owner of the program, if one was recorded when it was deployed.
+    #[inline]
+    pub const fn owner(&self) -> Option<&Address<N>> {
+        self.owner.as_ref()
+    }
+
+    /// Returns the stack, with the given owner recorded for the program.
+    #[inline]
+    pub(crate) fn with_owner(mut self, owner: Option<Address<N>>) -> Self {
+        self.owner = owner;
+        self
+    }
+
     /// Returns `true` if the stack contains the external record.
     #[inline]
     pub fn contains_external_record(&self, locator: &Locator<N>) -> bool {
@@ -84,11 +84,57 @@ impl<N: Network> RegisterTypes<N> {
                         "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{caller_type}' in the operand '{operand}'.",
                     )
                 }
+                // Ensure the block operands are not used outside of the finalize scope.
+                Operand::BlockHeight | Operand::BlockTimestamp => {
+                    bail!("Forbidden operation: Cannot use '{operand}' outside of 'finalize'")
+                }
             }
         }
         Ok(())
     }
 
+    /// Checks that the given operands matches the layout of the array. The ordering of the operands matters.
+    pub fn matches_array(&self, stack: &Stack<N>, operands: &[Operand<N>], array_type: &ArrayType<N>) -> Result<()> {
+        // Ensure the number of elements does not exceed the maximum.
+        let num_elements = operands.len();
+        ensure!(num_elements <= N::MAX_DATA_ENTRIES, "'{array_type}' cannot exceed {} entries", N::MAX_DATA_ENTRIES);
+
+        // Ensure the number of elements match.
+        let expected_num_elements = array_type.length() as usize;
+        if expected_num_elements != num_elements {
+            bail!("'{array_type}' expected {expected_num_elements} elements, found {num_elements} elements")
+        }
+
+        // Retrieve the element type.
+        let element_type = array_type.element_type();
+        // Ensure the element struct is defined in the program.
+        if let PlaintextType::Struct(struct_name) = element_type {
+            ensure!(stack.program().contains_struct(&struct_name), "Struct '{struct_name}' is not defined.");
+        }
+
+        // Ensure the operand types match the element type.
+        for operand in operands {
+            // Retrieve the operand type.
+            let operand_type = match operand {
+                Operand::Literal(literal) => RegisterType::Plaintext(PlaintextType::Literal(literal.to_type())),
+                Operand::Register(register) => self.get_type(stack, register)?,
+                Operand::ProgramID(..) | Operand::Caller => {
+                    RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address))
+                }
+                // Ensure the block operands are not used outside of the finalize scope.
+                Operand::BlockHeight | Operand::BlockTimestamp => {
+                    bail!("Forbidden operation: Cannot use '{operand}' outside of 'finalize'")
+                }
+            };
+            // Ensure the operand type matches the element type.
+            ensure!(
+                operand_type == RegisterType::Plaintext(element_type),
+                "Array '{array_type}' expects elements of type {element_type}, but found '{operand_type}' in the operand '{operand}'.",
+            )
+        }
+        Ok(())
+    }
+
     /// Checks that the given record matches the layout of the record type.
     /// Note: Ordering for `owner` and `gates` **does** matter, however ordering
     /// for record data does **not** matter, as long as all defined members are present.
@@ -127,6 +173,9 @@ impl<N: Network> RegisterTypes<N> {
                 bail!("Forbidden operation: Cannot cast a program ID ('{program_id}') as a record owner")
             }
             Operand::Caller => {}
+            Operand::BlockHeight | Operand::BlockTimestamp => {
+                bail!("Forbidden operation: Cannot use '{}' outside of 'finalize'", operands[0])
+            }
         }
 
         // Ensure the second input type is a u64.
@@ -150,6 +199,9 @@ impl<N: Network> RegisterTypes<N> {
             Operand::ProgramID(..) | Operand::Caller => {
                 bail!("Casting to a record requires the second operand to be a u64")
             }
+            Operand::BlockHeight | Operand::BlockTimestamp => {
+                bail!("Forbidden operation: Cannot use '{}' outside of 'finalize'", operands[1])
+            }
         }
 
         // Ensure the number of record entries does not exceed the maximum.
@@ -212,6 +264,10 @@ impl<N: Network> RegisterTypes<N> {
                                 "Record entry '{record_name}.{entry_name}' expects a '{plaintext_type}', but found '{caller_type}' in the operand '{operand}'.",
                             )
                         }
+                        // Ensure the block operands are not used outside of the finalize scope.
+                        Operand::BlockHeight | Operand::BlockTimestamp => {
+                            bail!("Forbidden operation: Cannot use '{operand}' outside of 'finalize'")
+                        }
                     }
                 }
             }
@@ -21,6 +21,8 @@ use crate::{CallOperator, Closure, Function, Instruction, Opcode, Operand, Progr
 use console::{
     network::prelude::*,
     program::{
+        Access,
+        ArrayType,
         EntryType,
         Identifier,
         LiteralType,
@@ -79,6 +81,9 @@ impl<N: Network> RegisterTypes<N> {
             Operand::Register(register) => self.get_type(stack, register)?,
             Operand::ProgramID(_) => RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address)),
             Operand::Caller => RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address)),
+            Operand::BlockHeight | Operand::BlockTimestamp => {
+                bail!("Forbidden operation: Cannot use '{operand}' outside of 'finalize'")
+            }
         })
     }
 
@@ -110,11 +115,34 @@ impl<N: Network> RegisterTypes<N> {
         };
 
         // Traverse the member path to find the register type.
-        for path_name in path.iter() {
+        for access in path.iter() {
+            // Retrieve the member name, or traverse into the array if the access is an index.
+            let path_name = match access {
+                Access::Member(path_name) => path_name,
+                Access::Index(index) => {
+                    // Update the register type to the element type.
+                    register_type = match &register_type {
+                        RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                            // Ensure the index is within bounds.
+                            ensure!(
+                                *index < array_type.length(),
+                                "'{register}' indexes out of bounds of '{array_type}'"
+                            );
+                            RegisterType::Plaintext(array_type.element_type())
+                        }
+                        _ => bail!("'{register}' indexes into a non-array type."),
+                    };
+                    continue;
+                }
+            };
             // Update the register type at each step.
             register_type = match &register_type {
                 // Ensure the plaintext type is not a literal, as the register references a member.
                 RegisterType::Plaintext(PlaintextType::Literal(..)) => bail!("'{register}' references a literal."),
+                // Ensure the plaintext type is not an array, as the register references a member.
+                RegisterType::Plaintext(PlaintextType::Array(..)) => {
+                    bail!("'{register}' references a member of an array.")
+                }
                 // Traverse the member path to output the register type.
                 RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
                     // Retrieve the member type from the struct.
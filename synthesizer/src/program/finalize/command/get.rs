@@ -0,0 +1,257 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    CallOperator,
+    FinalizeRegisters,
+    Opcode,
+    Operand,
+    ProgramStorage,
+    ProgramStore,
+    RegistersLoad,
+    RegistersStore,
+    Stack,
+};
+use console::{
+    network::prelude::*,
+    program::{Identifier, ProgramID, Register},
+};
+
+/// A get command, e.g. `get accounts[r0] into r1;` or `get oracle.aleo/prices[r0] into r1;`.
+/// Gets the value stored at the `key` operand in `mapping` and stores the result in `destination`.
+/// The mapping may belong to an imported program, in which case it is only read from.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Get<N: Network> {
+    /// The mapping, as a mapping name or as a locator into an imported program.
+    mapping: CallOperator<N>,
+    /// The key to access the mapping.
+    key: Operand<N>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network> Get<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Command("get")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> Vec<Operand<N>> {
+        vec![self.key.clone()]
+    }
+
+    /// Returns the mapping, as a mapping name or as a locator into an imported program.
+    #[inline]
+    pub const fn mapping(&self) -> &CallOperator<N> {
+        &self.mapping
+    }
+
+    /// Returns the mapping name.
+    #[inline]
+    pub const fn mapping_name(&self) -> &Identifier<N> {
+        match &self.mapping {
+            CallOperator::Locator(locator) => locator.resource(),
+            CallOperator::Resource(mapping_name) => mapping_name,
+        }
+    }
+
+    /// Returns the program ID of the mapping, defaulting to the given program ID for a local mapping.
+    #[inline]
+    pub fn mapping_program_id<'a>(&'a self, program_id: &'a ProgramID<N>) -> &'a ProgramID<N> {
+        match &self.mapping {
+            CallOperator::Locator(locator) => locator.program_id(),
+            CallOperator::Resource(..) => program_id,
+        }
+    }
+
+    /// Returns the operand containing the key.
+    #[inline]
+    pub const fn key(&self) -> &Operand<N> {
+        &self.key
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub const fn destination(&self) -> &Register<N> {
+        &self.destination
+    }
+}
+
+impl<N: Network> Get<N> {
+    /// Evaluates the command.
+    #[inline]
+    pub fn evaluate_finalize<P: ProgramStorage<N>>(
+        &self,
+        stack: &Stack<N>,
+        store: &ProgramStore<N, P>,
+        registers: &mut FinalizeRegisters<N>,
+    ) -> Result<()> {
+        // Retrieve the program ID and name of the mapping.
+        let program_id = self.mapping_program_id(stack.program_id());
+        let mapping_name = self.mapping_name();
+
+        // Ensure the mapping exists in storage.
+        if !store.contains_mapping(program_id, mapping_name)? {
+            bail!("Mapping '{program_id}/{mapping_name}' does not exist in storage");
+        }
+
+        // Load the operand as a plaintext.
+        let key = registers.load_plaintext(stack, &self.key)?;
+
+        // Retrieve the value from storage.
+        let value = match store.get_value(program_id, mapping_name, &key)? {
+            Some(value) => value,
+            None => bail!("Key '{key}' does not exist in mapping '{program_id}/{mapping_name}'"),
+        };
+
+        // Assign the value to the destination register.
+        registers.store(stack, &self.destination, value)
+    }
+}
+
+impl<N: Network> Parser for Get<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Parse the mapping from the string.
+        let (string, mapping) = CallOperator::parse(string)?;
+        // Parse the "[" from the string.
+        let (string, _) = tag("[")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the key operand from the string.
+        let (string, key) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "]" from the string.
+        let (string, _) = tag("]")(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" keyword from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the ";" from the string.
+        let (string, _) = tag(";")(string)?;
+
+        Ok((string, Self { mapping, key, destination }))
+    }
+}
+
+impl<N: Network> FromStr for Get<N> {
+    type Err = Error;
+
+    /// Parses a string into the command.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Get<N> {
+    /// Prints the command as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Get<N> {
+    /// Prints the command to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Print the command.
+        write!(f, "{} ", Self::opcode())?;
+        // Print the mapping and key operand.
+        write!(f, "{}[{}] into ", self.mapping, self.key)?;
+        // Print the destination register.
+        write!(f, "{};", self.destination)
+    }
+}
+
+impl<N: Network> FromBytes for Get<N> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the mapping.
+        let mapping = CallOperator::read_le(&mut reader)?;
+        // Read the key operand.
+        let key = Operand::read_le(&mut reader)?;
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+        // Return the command.
+        Ok(Self { mapping, key, destination })
+    }
+}
+
+impl<N: Network> ToBytes for Get<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the mapping.
+        self.mapping.write_le(&mut writer)?;
+        // Write the key operand.
+        self.key.write_le(&mut writer)?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, get) = Get::<CurrentNetwork>::parse("get account[r0] into r1;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(get.mapping, CallOperator::from_str("account").unwrap());
+        assert_eq!(get.operands().len(), 1, "The number of operands is incorrect");
+        assert_eq!(get.key, Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(get.destination, Register::Locator(1), "The destination register is incorrect");
+
+        let (string, get) = Get::<CurrentNetwork>::parse("get oracle.aleo/prices[r0] into r1;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(get.mapping, CallOperator::from_str("oracle.aleo/prices").unwrap());
+        assert_eq!(get.mapping_name(), &Identifier::from_str("prices").unwrap());
+        assert_eq!(get.key, Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(get.destination, Register::Locator(1), "The destination register is incorrect");
+        assert_eq!(get.to_string(), "get oracle.aleo/prices[r0] into r1;");
+    }
+}
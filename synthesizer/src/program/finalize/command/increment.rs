@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{FinalizeRegisters, Opcode, Operand, ProgramStorage, ProgramStore, Stack};
+use crate::{FinalizeRegisters, Opcode, Operand, ProgramStorage, ProgramStore, RegistersLoad, Stack};
 use console::{
     network::prelude::*,
     program::{Identifier, Literal, Plaintext, Value},
@@ -86,6 +86,7 @@ impl<N: Network> Increment<N> {
         let start = match store.get_value(stack.program_id(), &self.mapping, &key)? {
             Some(Value::Plaintext(Plaintext::Literal(literal, _))) => literal,
             Some(Value::Plaintext(Plaintext::Struct(..))) => bail!("Cannot 'increment' by an 'struct'"),
+            Some(Value::Plaintext(Plaintext::Array(..))) => bail!("Cannot 'increment' by an 'array'"),
             Some(Value::Record(..)) => bail!("Cannot 'increment' by a 'record'"),
             // If the key does not exist, set the starting value to 0.
             // Infer the starting type from the increment type.
@@ -105,6 +106,7 @@ impl<N: Network> Increment<N> {
                 Literal::U64(..) => Literal::U64(Zero::zero()),
                 Literal::U128(..) => Literal::U128(Zero::zero()),
                 Literal::Scalar(..) => Literal::Scalar(Zero::zero()),
+                Literal::Signature(..) => bail!("Cannot 'increment' by a 'signature'"),
                 Literal::String(..) => bail!("Cannot 'increment' by a 'string'"),
             },
         };
@@ -14,16 +14,34 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod branch;
+pub use branch::*;
+
 mod decrement;
 pub use decrement::*;
 
 mod finalize;
 pub use finalize::*;
 
+mod get;
+pub use get::*;
+
+mod get_or_init;
+pub use get_or_init::*;
+
 mod increment;
 pub use increment::*;
 
-use crate::{program::Instruction, FinalizeRegisters, ProgramStorage, ProgramStore, Stack};
+mod position;
+pub use position::*;
+
+mod remove;
+pub use remove::*;
+
+mod set;
+pub use set::*;
+
+use crate::{program::Instruction, FinalizeRegisters, ProgramStorage, ProgramStore, RegistersLoad, Stack};
 use console::network::prelude::*;
 
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -34,6 +52,21 @@ pub enum Command<N: Network> {
     Instruction(Instruction<N>),
     /// Increments the value stored at the `first` operand in `mapping` by the amount in the `second` operand.
     Increment(Increment<N>),
+    /// Gets the value stored at the `key` operand in `mapping` and stores the result into `destination`.
+    Get(Get<N>),
+    /// Gets the value stored at the `key` operand in `mapping` and stores the result into `destination`.
+    /// If the key is not present, `default` is stored at the `key` operand in `mapping` and in `destination`.
+    GetOrInit(GetOrInit<N>),
+    /// Sets the value stored at the `key` operand in `mapping` to `value`.
+    Set(Set<N>),
+    /// Removes the (`key`, `value`) entry from the `mapping`.
+    Remove(Remove<N>),
+    /// Jumps to `position`, if `first` equals `second`.
+    BranchEq(BranchEq<N>),
+    /// Jumps to `position`, if `first` does **not** equal `second`.
+    BranchNeq(BranchNeq<N>),
+    /// Indicates a position to which the program can branch to.
+    Position(Position<N>),
 }
 
 impl<N: Network> Command<N> {
@@ -47,12 +80,55 @@ impl<N: Network> Command<N> {
     ) -> Result<()> {
         match self {
             Command::Decrement(decrement) => decrement.evaluate_finalize(stack, store, registers),
-            // TODO (howardwu): Implement support for instructions (consider using a trait for `Registers::load/store`).
-            // Command::Instruction(instruction) => instruction.evaluate_finalize(stack, registers),
-            Command::Instruction(_) => bail!("Instructions in 'finalize' are not supported (yet)."),
+            Command::Instruction(instruction) => instruction.finalize(stack, registers),
             Command::Increment(increment) => increment.evaluate_finalize(stack, store, registers),
+            Command::Get(get) => get.evaluate_finalize(stack, store, registers),
+            Command::GetOrInit(get_or_init) => get_or_init.evaluate_finalize(stack, store, registers),
+            Command::Set(set) => set.evaluate_finalize(stack, store, registers),
+            Command::Remove(remove) => remove.evaluate_finalize(stack, store, registers),
+            // Note: Branch commands are evaluated by the caller, as they require the positions in the finalize scope.
+            Command::BranchEq(..) | Command::BranchNeq(..) => {
+                bail!("Forbidden operation: Cannot evaluate '{self}' outside of the finalize scope")
+            }
+            // Note: A position command does not modify any state.
+            Command::Position(..) => Ok(()),
+        }
+    }
+
+    /// Returns the base cost (in gates) of the command.
+    /// Note: The bytes written to a mapping are charged separately, in `Command::storage_cost`.
+    #[inline]
+    pub const fn cost(&self) -> u64 {
+        match self {
+            Command::Instruction(instruction) => instruction.cost(),
+            Command::Get(..) | Command::Remove(..) => N::MAPPING_READ_COST,
+            Command::Decrement(..) | Command::Increment(..) | Command::GetOrInit(..) | Command::Set(..) => {
+                N::MAPPING_WRITE_COST
+            }
+            Command::BranchEq(..) | Command::BranchNeq(..) => N::INSTRUCTION_COST,
+            // A position only marks a location, so it is free.
+            Command::Position(..) => 0,
         }
     }
+
+    /// Returns the storage cost (in gates) of the key and value written to a mapping by the command.
+    /// If the command does not write to a mapping, this method returns `0`.
+    #[inline]
+    pub fn storage_cost(&self, stack: &Stack<N>, registers: &FinalizeRegisters<N>) -> Result<u64> {
+        // Retrieve the operands of the key and value.
+        let (key, value) = match self {
+            Command::Decrement(decrement) => (decrement.key(), decrement.value()),
+            Command::Increment(increment) => (increment.key(), increment.value()),
+            Command::GetOrInit(get_or_init) => (get_or_init.key(), get_or_init.default()),
+            Command::Set(set) => (set.key(), set.value()),
+            _ => return Ok(0),
+        };
+        // Compute the number of bytes in the key and value.
+        let num_bytes =
+            registers.load(stack, key)?.to_bytes_le()?.len() + registers.load(stack, value)?.to_bytes_le()?.len();
+        // Compute the storage cost.
+        (num_bytes as u64).checked_mul(N::MAPPING_BYTE_COST).ok_or_else(|| anyhow!("Storage cost overflowed"))
+    }
 }
 
 impl<N: Network> FromBytes for Command<N> {
@@ -67,8 +143,22 @@ impl<N: Network> FromBytes for Command<N> {
             1 => Ok(Self::Instruction(Instruction::read_le(&mut reader)?)),
             // Read the increment.
             2 => Ok(Self::Increment(Increment::read_le(&mut reader)?)),
+            // Read the get.
+            3 => Ok(Self::Get(Get::read_le(&mut reader)?)),
+            // Read the get.or_init.
+            4 => Ok(Self::GetOrInit(GetOrInit::read_le(&mut reader)?)),
+            // Read the set.
+            5 => Ok(Self::Set(Set::read_le(&mut reader)?)),
+            // Read the remove.
+            6 => Ok(Self::Remove(Remove::read_le(&mut reader)?)),
+            // Read the branch.eq.
+            7 => Ok(Self::BranchEq(BranchEq::read_le(&mut reader)?)),
+            // Read the branch.neq.
+            8 => Ok(Self::BranchNeq(BranchNeq::read_le(&mut reader)?)),
+            // Read the position.
+            9 => Ok(Self::Position(Position::read_le(&mut reader)?)),
             // Invalid variant.
-            3.. => Err(error(format!("Invalid command variant: {}", variant))),
+            10.. => Err(error(format!("Invalid command variant: {}", variant))),
         }
     }
 }
@@ -95,6 +185,48 @@ impl<N: Network> ToBytes for Command<N> {
                 // Write the increment.
                 increment.write_le(&mut writer)
             }
+            Self::Get(get) => {
+                // Write the variant.
+                3u8.write_le(&mut writer)?;
+                // Write the get.
+                get.write_le(&mut writer)
+            }
+            Self::GetOrInit(get_or_init) => {
+                // Write the variant.
+                4u8.write_le(&mut writer)?;
+                // Write the get.or_init.
+                get_or_init.write_le(&mut writer)
+            }
+            Self::Set(set) => {
+                // Write the variant.
+                5u8.write_le(&mut writer)?;
+                // Write the set.
+                set.write_le(&mut writer)
+            }
+            Self::Remove(remove) => {
+                // Write the variant.
+                6u8.write_le(&mut writer)?;
+                // Write the remove.
+                remove.write_le(&mut writer)
+            }
+            Self::BranchEq(branch_eq) => {
+                // Write the variant.
+                7u8.write_le(&mut writer)?;
+                // Write the branch.eq.
+                branch_eq.write_le(&mut writer)
+            }
+            Self::BranchNeq(branch_neq) => {
+                // Write the variant.
+                8u8.write_le(&mut writer)?;
+                // Write the branch.neq.
+                branch_neq.write_le(&mut writer)
+            }
+            Self::Position(position) => {
+                // Write the variant.
+                9u8.write_le(&mut writer)?;
+                // Write the position.
+                position.write_le(&mut writer)
+            }
         }
     }
 }
@@ -105,6 +237,14 @@ impl<N: Network> Parser for Command<N> {
     fn parse(string: &str) -> ParserResult<Self> {
         alt((
             map(Decrement::parse, |decrement| Self::Decrement(decrement)),
+            // Note that `GetOrInit` must be parsed before `Get`, as `get` is a prefix of `get.or_init`.
+            map(GetOrInit::parse, |get_or_init| Self::GetOrInit(get_or_init)),
+            map(Get::parse, |get| Self::Get(get)),
+            map(Set::parse, |set| Self::Set(set)),
+            map(Remove::parse, |remove| Self::Remove(remove)),
+            map(BranchEq::parse, |branch_eq| Self::BranchEq(branch_eq)),
+            map(BranchNeq::parse, |branch_neq| Self::BranchNeq(branch_neq)),
+            map(Position::parse, |position| Self::Position(position)),
             map(Instruction::parse, |instruction| Self::Instruction(instruction)),
             map(Increment::parse, |increment| Self::Increment(increment)),
         ))(string)
@@ -143,6 +283,13 @@ impl<N: Network> Display for Command<N> {
             Self::Decrement(decrement) => Display::fmt(decrement, f),
             Self::Instruction(instruction) => Display::fmt(instruction, f),
             Self::Increment(increment) => Display::fmt(increment, f),
+            Self::Get(get) => Display::fmt(get, f),
+            Self::GetOrInit(get_or_init) => Display::fmt(get_or_init, f),
+            Self::Set(set) => Display::fmt(set, f),
+            Self::Remove(remove) => Display::fmt(remove, f),
+            Self::BranchEq(branch_eq) => Display::fmt(branch_eq, f),
+            Self::BranchNeq(branch_neq) => Display::fmt(branch_neq, f),
+            Self::Position(position) => Display::fmt(position, f),
         }
     }
 }
@@ -173,6 +320,54 @@ mod tests {
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
         let bytes = command.to_bytes_le().unwrap();
         assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // Get
+        let expected = "get object[r0] into r1;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // Get (external)
+        let expected = "get oracle.aleo/prices[r0] into r1;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // GetOrInit
+        let expected = "get.or_init object[r0] r1 into r2;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // Set
+        let expected = "set r0 into object[r1];";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // Remove
+        let expected = "remove object[r0];";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // BranchEq
+        let expected = "branch.eq r0 r1 to exit;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // BranchNeq
+        let expected = "branch.neq r2 r3 to exit;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // Position
+        let expected = "position exit;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
     }
 
     #[test]
@@ -194,5 +389,53 @@ mod tests {
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
         assert_eq!(Command::Increment(Increment::from_str(expected).unwrap()), command);
         assert_eq!(expected, command.to_string());
+
+        // Get
+        let expected = "get object[r0] into r1;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Get(Get::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // Get (external)
+        let expected = "get oracle.aleo/prices[r0] into r1;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Get(Get::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // GetOrInit
+        let expected = "get.or_init object[r0] r1 into r2;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::GetOrInit(GetOrInit::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // Set
+        let expected = "set r0 into object[r1];";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Set(Set::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // Remove
+        let expected = "remove object[r0];";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Remove(Remove::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // BranchEq
+        let expected = "branch.eq r0 r1 to exit;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::BranchEq(BranchEq::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // BranchNeq
+        let expected = "branch.neq r2 r3 to exit;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::BranchNeq(BranchNeq::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // Position
+        let expected = "position exit;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Position(Position::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
     }
 }
@@ -32,7 +32,7 @@ use console::{
     program::{FinalizeType, Identifier, Register},
 };
 
-use indexmap::IndexSet;
+use indexmap::{IndexMap, IndexSet};
 
 #[derive(Clone, PartialEq, Eq)]
 pub struct Finalize<N: Network> {
@@ -43,6 +43,8 @@ pub struct Finalize<N: Network> {
     inputs: IndexSet<Input<N>>,
     /// The commands, in order of execution.
     commands: Vec<Command<N>>,
+    /// A mapping from `Position`s to their index in `commands`.
+    positions: IndexMap<Identifier<N>, usize>,
     /// The output statements, in order of the desired output.
     outputs: IndexSet<Output<N>>,
 }
@@ -50,7 +52,13 @@ pub struct Finalize<N: Network> {
 impl<N: Network> Finalize<N> {
     /// Initializes a new finalize with the given name.
     pub fn new(name: Identifier<N>) -> Self {
-        Self { name, inputs: IndexSet::new(), commands: Vec::new(), outputs: IndexSet::new() }
+        Self {
+            name,
+            inputs: IndexSet::new(),
+            commands: Vec::new(),
+            positions: IndexMap::new(),
+            outputs: IndexSet::new(),
+        }
     }
 
     /// Returns the name of the associated function.
@@ -73,6 +81,11 @@ impl<N: Network> Finalize<N> {
         &self.commands
     }
 
+    /// Returns the mapping of positions to their index in the finalize commands.
+    pub const fn positions(&self) -> &IndexMap<Identifier<N>, usize> {
+        &self.positions
+    }
+
     /// Returns the finalize outputs.
     pub const fn outputs(&self) -> &IndexSet<Output<N>> {
         &self.outputs
@@ -114,23 +127,45 @@ impl<N: Network> Finalize<N> {
     ///
     /// # Errors
     /// This method will halt if the maximum number of commands has been reached.
+    /// This method will halt if the command is a position that was previously added.
     #[inline]
     pub fn add_command(&mut self, command: Command<N>) -> Result<()> {
         // Ensure the maximum number of commands has not been exceeded.
         ensure!(self.commands.len() <= N::MAX_COMMANDS, "Cannot add more than {} commands", N::MAX_COMMANDS);
 
-        // If the command is an instruction, perform additional checks.
-        if let Command::Instruction(instruction) = &command {
-            // Ensure the instruction is not a `call`.
-            ensure!(
-                !matches!(instruction, Instruction::Call(..)),
-                "Forbidden operation: Finalize cannot invoke a 'call'"
-            );
-
-            // Ensure the destination register is a locator.
-            for register in instruction.destinations() {
-                ensure!(matches!(register, Register::Locator(..)), "Destination register must be a locator");
+        match &command {
+            // If the command is an instruction, perform additional checks.
+            Command::Instruction(instruction) => {
+                // Ensure the instruction is not a `call`.
+                ensure!(
+                    !matches!(instruction, Instruction::Call(..)),
+                    "Forbidden operation: Finalize cannot invoke a 'call'"
+                );
+
+                // Ensure the destination register is a locator.
+                for register in instruction.destinations() {
+                    ensure!(matches!(register, Register::Locator(..)), "Destination register must be a locator");
+                }
+            }
+            // Ensure the destination register of a `get` is a locator.
+            Command::Get(get) => {
+                ensure!(matches!(get.destination(), Register::Locator(..)), "Destination register must be a locator")
+            }
+            // Ensure the destination register of a `get.or_init` is a locator.
+            Command::GetOrInit(get_or_init) => ensure!(
+                matches!(get_or_init.destination(), Register::Locator(..)),
+                "Destination register must be a locator"
+            ),
+            // Ensure the position is unique, and record its index in the commands.
+            Command::Position(position) => {
+                ensure!(
+                    !self.positions.contains_key(position.name()),
+                    "Cannot redefine position '{}'",
+                    position.name()
+                );
+                self.positions.insert(*position.name(), self.commands.len());
             }
+            _ => (),
         }
 
         // Insert the command.
@@ -151,6 +151,34 @@ finalize foo:
         assert_eq!(1, finalize.outputs.len());
     }
 
+    #[test]
+    fn test_finalize_parse_positions() {
+        let finalize = Finalize::<CurrentNetwork>::parse(
+            r"
+finalize foo:
+    input r0 as u8.public;
+    branch.eq r0 0u8 to skip;
+    add r0 r0 into r1;
+    position skip;",
+        )
+        .unwrap()
+        .1;
+        assert_eq!(3, finalize.commands.len());
+        assert_eq!(1, finalize.positions.len());
+        assert_eq!(Some(&2), finalize.positions.get(&Identifier::from_str("skip").unwrap()));
+
+        // Ensure a position cannot be redefined.
+        let result = Finalize::<CurrentNetwork>::parse(
+            r"
+finalize foo:
+    input r0 as u8.public;
+    position skip;
+    add r0 r0 into r1;
+    position skip;",
+        );
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_finalize_display() {
         let expected = r"finalize foo:
@@ -26,7 +26,7 @@ pub use operation::*;
 mod bytes;
 mod parse;
 
-use crate::{Registers, Stack};
+use crate::{FinalizeRegisters, Registers, Stack};
 use console::{
     network::{
         prelude::{
@@ -171,6 +171,16 @@ pub enum Instruction<N: Network> {
     Ternary(Ternary<N>),
     /// Performs a bitwise `xor` on `first` and `second`, storing the outcome in `destination`.
     Xor(Xor<N>),
+    /// Casts the operand into the declared literal type, truncating the value if necessary.
+    CastLossy(CastLossy<N>),
+    /// Computes whether `signature` is valid for the given `address` and `message`, storing the outcome in `destination`.
+    SignVerify(SignVerify<N>),
+    /// Performs a Keccak hash with a 256-bit output, as used in Ethereum.
+    HashKeccak256(HashKeccak256<N>),
+    /// Performs a SHA-3 hash with a 256-bit output.
+    HashSha3_256(HashSha3_256<N>),
+    /// Performs a SHA-3 hash with a 512-bit output.
+    HashSha3_512(HashSha3_512<N>),
 }
 
 /// Creates a match statement that applies the given operation for each instruction.
@@ -258,6 +268,12 @@ macro_rules! instruction {
             SubWrapped,
             Ternary,
             Xor,
+            // New instructions are appended below, as the opcode index is the position in this list.
+            CastLossy,
+            SignVerify,
+            HashKeccak256,
+            HashSha3_256,
+            HashSha3_512,
         }}
     };
     // A variant **without** curly braces:
@@ -359,7 +375,7 @@ impl<N: Network> Instruction<N> {
         stack: &Stack<N>,
         registers: &mut Registers<N, A>,
     ) -> Result<()> {
-        instruction!(self, |instruction| instruction.evaluate::<A>(stack, registers))
+        instruction!(self, |instruction| instruction.evaluate(stack, registers))
     }
 
     /// Executes the instruction.
@@ -372,11 +388,57 @@ impl<N: Network> Instruction<N> {
         instruction!(self, |instruction| instruction.execute::<A>(stack, registers))
     }
 
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(&self, stack: &Stack<N>, registers: &mut FinalizeRegisters<N>) -> Result<()> {
+        instruction!(self, |instruction| instruction.finalize(stack, registers))
+    }
+
     /// Returns the output type from the given input types.
     #[inline]
     pub fn output_types(&self, stack: &Stack<N>, input_types: &[RegisterType<N>]) -> Result<Vec<RegisterType<N>>> {
         instruction!(self, |instruction| instruction.output_types(stack, input_types))
     }
+
+    /// Returns the cost (in gates) of the instruction.
+    /// Note: The cost of a call does not include the cost of the callee.
+    #[inline]
+    pub const fn cost(&self) -> u64 {
+        match self {
+            // Hashes and commitments over the BHP and Pedersen gadgets.
+            Self::CommitBHP256(..)
+            | Self::CommitBHP512(..)
+            | Self::CommitBHP768(..)
+            | Self::CommitBHP1024(..)
+            | Self::HashBHP256(..)
+            | Self::HashBHP512(..)
+            | Self::HashBHP768(..)
+            | Self::HashBHP1024(..) => N::BHP_COST,
+            Self::CommitPED64(..) | Self::CommitPED128(..) | Self::HashPED64(..) | Self::HashPED128(..) => {
+                N::PEDERSEN_COST
+            }
+            // Hashes over the Poseidon gadget.
+            Self::HashPSD2(..) | Self::HashPSD4(..) | Self::HashPSD8(..) => N::POSEIDON_COST,
+            // Hashes over bit-oriented gadgets.
+            Self::HashKeccak256(..) | Self::HashSha3_256(..) | Self::HashSha3_512(..) => N::KECCAK_COST,
+            // Signature verification.
+            Self::SignVerify(..) => N::SIGNATURE_VERIFICATION_COST,
+            // Arithmetic that is expensive to constrain.
+            Self::Div(..)
+            | Self::DivWrapped(..)
+            | Self::Inv(..)
+            | Self::Modulo(..)
+            | Self::Pow(..)
+            | Self::PowWrapped(..)
+            | Self::Rem(..)
+            | Self::RemWrapped(..)
+            | Self::SquareRoot(..) => N::COMPLEX_ARITHMETIC_COST,
+            // Casts between types.
+            Self::Cast(..) | Self::CastLossy(..) => N::CAST_COST,
+            // All other instructions.
+            _ => N::INSTRUCTION_COST,
+        }
+    }
 }
 
 impl<N: Network> Debug for Instruction<N> {
@@ -404,7 +466,7 @@ mod tests {
     fn test_opcodes() {
         // Sanity check the number of instructions is unchanged.
         assert_eq!(
-            56,
+            61,
             Instruction::<CurrentNetwork>::OPCODES.len(),
             "Update me if the number of instructions changes."
         );
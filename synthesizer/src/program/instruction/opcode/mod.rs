@@ -24,7 +24,7 @@ pub enum Opcode {
     /// The opcode is for a call operation (i.e. `call`).
     Call,
     /// The opcode is for a cast operation (i.e. `cast`).
-    Cast,
+    Cast(&'static str),
     /// The opcode is for a finalize command (i.e. `increment`).
     Command(&'static str),
     /// The opcode is for a commit operation (i.e. `commit.psd4`).
@@ -37,6 +37,8 @@ pub enum Opcode {
     Is(&'static str),
     /// The opcode is for a literal operation (i.e. `add`).
     Literal(&'static str),
+    /// The opcode is for a signature verification operation (i.e. `sign.verify`).
+    Sign,
 }
 
 impl Deref for Opcode {
@@ -47,13 +49,14 @@ impl Deref for Opcode {
         match self {
             Opcode::Assert(opcode) => opcode,
             Opcode::Call => &"call",
-            Opcode::Cast => &"cast",
+            Opcode::Cast(opcode) => opcode,
             Opcode::Command(opcode) => opcode,
             Opcode::Commit(opcode) => opcode,
             Opcode::Finalize(opcode) => opcode,
             Opcode::Hash(opcode) => opcode,
             Opcode::Is(opcode) => opcode,
             Opcode::Literal(opcode) => opcode,
+            Opcode::Sign => &"sign.verify",
         }
     }
 }
@@ -72,13 +75,14 @@ impl Display for Opcode {
         match self {
             Self::Assert(opcode) => write!(f, "{opcode}"),
             Self::Call => write!(f, "{}", self.deref()),
-            Self::Cast => write!(f, "{}", self.deref()),
+            Self::Cast(opcode) => write!(f, "{opcode}"),
             Self::Command(opcode) => write!(f, "{opcode}"),
             Self::Commit(opcode) => write!(f, "{opcode}"),
             Self::Finalize(opcode) => write!(f, "{opcode}"),
             Self::Hash(opcode) => write!(f, "{opcode}"),
             Self::Is(opcode) => write!(f, "{opcode}"),
             Self::Literal(opcode) => write!(f, "{opcode}"),
+            Self::Sign => write!(f, "{}", self.deref()),
         }
     }
 }
@@ -34,6 +34,12 @@ pub enum Operand<N: Network> {
     ProgramID(ProgramID<N>),
     /// The operand is the caller address.
     Caller,
+    /// The operand is the block height.
+    /// Note: This operand is only accessible in the finalize scope.
+    BlockHeight,
+    /// The operand is the block timestamp.
+    /// Note: This operand is only accessible in the finalize scope.
+    BlockTimestamp,
 }
 
 impl<N: Network> From<Literal<N>> for Operand<N> {
@@ -14,10 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Opcode, Operand, Registers, Stack};
+use crate::{Opcode, Operand, Registers, RegistersLoad, RegistersStore, Stack};
 use console::{
     network::prelude::*,
-    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    program::{ArrayType, Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::U128,
 };
 
 /// BHP256 is a collision-resistant hash function that processes inputs in 256-bit chunks.
@@ -41,6 +42,17 @@ pub type HashPSD4<N> = HashInstruction<N, { Hasher::PSD4 as u8 }>;
 /// Poseidon8 is a cryptographic hash function that processes inputs in 8-field chunks.
 pub type HashPSD8<N> = HashInstruction<N, { Hasher::PSD8 as u8 }>;
 
+/// Keccak256 is the Keccak hash function with a 256-bit output, as used in Ethereum.
+pub type HashKeccak256<N> = HashInstruction<N, { Hasher::Keccak256 as u8 }>;
+/// SHA3-256 is the SHA-3 hash function (FIPS 202) with a 256-bit output.
+pub type HashSha3_256<N> = HashInstruction<N, { Hasher::Sha3_256 as u8 }>;
+/// SHA3-512 is the SHA-3 hash function (FIPS 202) with a 512-bit output.
+pub type HashSha3_512<N> = HashInstruction<N, { Hasher::Sha3_512 as u8 }>;
+
+/// The byte that precedes the destination type in the byte encoding of a hash instruction.
+/// It is distinct from every operand variant, so a hash into a field element keeps its original encoding.
+const DESTINATION_TYPE_MARKER: u8 = u8::MAX;
+
 enum Hasher {
     BHP256,
     BHP512,
@@ -51,15 +63,23 @@ enum Hasher {
     PSD2,
     PSD4,
     PSD8,
+    Keccak256,
+    Sha3_256,
+    Sha3_512,
 }
 
 /// Hashes the operand into the declared type.
+/// If no destination type is declared, the operand is hashed into a field element.
+/// The Keccak and SHA-3 variants hash the raw bits of a literal, or of an array of literals,
+/// and output the full digest as an array of `u128` words, each holding 16 bytes of the digest in big-endian order.
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct HashInstruction<N: Network, const VARIANT: u8> {
     /// The operand as `input`.
     operands: Vec<Operand<N>>,
     /// The destination register.
     destination: Register<N>,
+    /// The destination type.
+    destination_type: LiteralType,
 }
 
 impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
@@ -76,6 +96,9 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             6 => Opcode::Hash("hash.psd2"),
             7 => Opcode::Hash("hash.psd4"),
             8 => Opcode::Hash("hash.psd8"),
+            9 => Opcode::Hash("hash.keccak256"),
+            10 => Opcode::Hash("hash.sha3_256"),
+            11 => Opcode::Hash("hash.sha3_512"),
             _ => panic!("Invalid 'hash' instruction opcode"),
         }
     }
@@ -94,37 +117,145 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
     pub fn destinations(&self) -> Vec<Register<N>> {
         vec![self.destination.clone()]
     }
+
+    /// Returns the destination type.
+    #[inline]
+    pub const fn destination_type(&self) -> LiteralType {
+        self.destination_type
+    }
+
+    /// Ensures the destination type is supported by the hash function.
+    fn check_destination_type(&self) -> Result<()> {
+        match (VARIANT, self.destination_type) {
+            // Ensure the destination type is not a signature or a string.
+            (_, LiteralType::Signature | LiteralType::String)
+            // Ensure only Poseidon hashes into an address or a group element.
+            | (0..=5, LiteralType::Address | LiteralType::Group) => {
+                bail!("Instruction '{}' cannot hash into a '{}'", Self::opcode(), self.destination_type)
+            }
+            // Ensure Keccak and SHA-3 only output their digest.
+            (9..=11, destination_type) if destination_type != LiteralType::Field => {
+                bail!("Instruction '{}' cannot hash into a '{destination_type}'", Self::opcode())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the number of `u128` words in the digest, for the Keccak and SHA-3 variants.
+    const fn num_digest_words() -> Option<u32> {
+        match VARIANT {
+            9 | 10 => Some(2),
+            11 => Some(4),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the raw little-endian bits of the given literal, or the concatenated bits of an array of literals.
+fn raw_bits_le<N: Network>(input: &Value<N>) -> Result<Vec<bool>> {
+    match input {
+        Value::Plaintext(Plaintext::Literal(literal, ..)) => Ok(literal.to_bits_le()),
+        Value::Plaintext(Plaintext::Array(elements, ..)) => {
+            elements.iter().try_fold(Vec::new(), |mut bits_le, element| match element {
+                Plaintext::Literal(literal, ..) => {
+                    bits_le.extend(literal.to_bits_le());
+                    Ok(bits_le)
+                }
+                _ => bail!("Expected an array of literals, found '{input}'"),
+            })
+        }
+        _ => bail!("Expected a literal or an array of literals, found '{input}'"),
+    }
+}
+
+/// Returns the raw little-endian bits of the given literal, or the concatenated bits of an array of literals.
+fn raw_bits_le_circuit<A: circuit::Aleo>(input: &circuit::Value<A>) -> Result<Vec<circuit::Boolean<A>>> {
+    use circuit::ToBits;
+
+    match input {
+        circuit::Value::Plaintext(circuit::Plaintext::Literal(literal, ..)) => Ok(literal.to_bits_le()),
+        circuit::Value::Plaintext(circuit::Plaintext::Array(elements, ..)) => {
+            elements.iter().try_fold(Vec::new(), |mut bits_le, element| match element {
+                circuit::Plaintext::Literal(literal, ..) => {
+                    bits_le.extend(literal.to_bits_le());
+                    Ok(bits_le)
+                }
+                _ => bail!("Expected an array of literals"),
+            })
+        }
+        _ => bail!("Expected a literal or an array of literals"),
+    }
+}
+
+/// Splits the given little-endian digest bits into the little-endian bits of 128-bit words,
+/// where each word holds 16 bytes of the digest in big-endian order.
+fn to_words_le<T: Clone>(digest: &[T]) -> Vec<Vec<T>> {
+    digest.chunks(128).map(|word| word.chunks(8).rev().flatten().cloned().collect()).collect()
 }
 
 impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
     /// Evaluates the instruction.
     #[inline]
-    pub fn evaluate<A: circuit::Aleo<Network = N>>(
+    pub fn evaluate(
         &self,
         stack: &Stack<N>,
-        registers: &mut Registers<N, A>,
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
     ) -> Result<()> {
         // Ensure the number of operands is correct.
         if self.operands.len() != 1 {
             bail!("Instruction '{}' expects 1 operands, found {} operands", Self::opcode(), self.operands.len())
         }
+        // Ensure the destination type is supported.
+        self.check_destination_type()?;
         // Load the operand.
         let input = registers.load(stack, &self.operands[0])?;
+
+        // Hash the raw bits of the input, if the hash function is Keccak or SHA-3.
+        let digest = match VARIANT {
+            9 => Some(N::hash_keccak256(&raw_bits_le(&input)?)?),
+            10 => Some(N::hash_sha3_256(&raw_bits_le(&input)?)?),
+            11 => Some(N::hash_sha3_512(&raw_bits_le(&input)?)?),
+            _ => None,
+        };
+        // Store the full digest as an array of `u128` words.
+        if let Some(digest) = digest {
+            let words = to_words_le(&digest)
+                .iter()
+                .map(|word| Ok(Plaintext::from(Literal::U128(U128::from_bits_le(word)?))))
+                .collect::<Result<Vec<_>>>()?;
+            let output = Value::Plaintext(Plaintext::Array(words, Default::default()));
+            return registers.store(stack, &self.destination, output);
+        }
+
         // Hash the input.
-        let output = match VARIANT {
-            0 => N::hash_bhp256(&input.to_bits_le())?,
-            1 => N::hash_bhp512(&input.to_bits_le())?,
-            2 => N::hash_bhp768(&input.to_bits_le())?,
-            3 => N::hash_bhp1024(&input.to_bits_le())?,
-            4 => N::hash_ped64(&input.to_bits_le())?,
-            5 => N::hash_ped128(&input.to_bits_le())?,
-            6 => N::hash_psd2(&input.to_fields()?)?,
-            7 => N::hash_psd4(&input.to_fields()?)?,
-            8 => N::hash_psd8(&input.to_fields()?)?,
+        let output = match (VARIANT, self.destination_type) {
+            (0, _) => Literal::Field(N::hash_bhp256(&input.to_bits_le())?),
+            (1, _) => Literal::Field(N::hash_bhp512(&input.to_bits_le())?),
+            (2, _) => Literal::Field(N::hash_bhp768(&input.to_bits_le())?),
+            (3, _) => Literal::Field(N::hash_bhp1024(&input.to_bits_le())?),
+            (4, _) => Literal::Field(N::hash_ped64(&input.to_bits_le())?),
+            (5, _) => Literal::Field(N::hash_ped128(&input.to_bits_le())?),
+            (6, LiteralType::Address | LiteralType::Group) => {
+                Literal::Group(N::hash_to_group_psd2(&input.to_fields()?)?)
+            }
+            (6, LiteralType::Scalar) => Literal::Scalar(N::hash_to_scalar_psd2(&input.to_fields()?)?),
+            (6, _) => Literal::Field(N::hash_psd2(&input.to_fields()?)?),
+            (7, LiteralType::Address | LiteralType::Group) => {
+                Literal::Group(N::hash_to_group_psd4(&input.to_fields()?)?)
+            }
+            (7, LiteralType::Scalar) => Literal::Scalar(N::hash_to_scalar_psd4(&input.to_fields()?)?),
+            (7, _) => Literal::Field(N::hash_psd4(&input.to_fields()?)?),
+            (8, LiteralType::Address | LiteralType::Group) => {
+                Literal::Group(N::hash_to_group_psd8(&input.to_fields()?)?)
+            }
+            (8, LiteralType::Scalar) => Literal::Scalar(N::hash_to_scalar_psd8(&input.to_fields()?)?),
+            (8, _) => Literal::Field(N::hash_psd8(&input.to_fields()?)?),
             _ => bail!("Invalid 'hash' variant: {VARIANT}"),
         };
+        // Cast the output into the destination type, keeping the lower bits if necessary.
+        let output = output.cast_lossy(self.destination_type)?;
         // Store the output.
-        registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(Literal::Field(output))))
+        registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(output)))
     }
 
     /// Executes the instruction.
@@ -134,34 +265,80 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         stack: &Stack<N>,
         registers: &mut Registers<N, A>,
     ) -> Result<()> {
-        use circuit::{ToBits, ToFields};
+        use circuit::{FromBits, ToBits, ToFields};
 
         // Ensure the number of operands is correct.
         if self.operands.len() != 1 {
             bail!("Instruction '{}' expects 1 operands, found {} operands", Self::opcode(), self.operands.len())
         }
+        // Ensure the destination type is supported.
+        self.check_destination_type()?;
         // Load the operand.
         let input = registers.load_circuit(stack, &self.operands[0])?;
+
+        // Hash the raw bits of the input, if the hash function is Keccak or SHA-3.
+        let digest = match VARIANT {
+            9 => Some(A::hash_keccak256(&raw_bits_le_circuit(&input)?)),
+            10 => Some(A::hash_sha3_256(&raw_bits_le_circuit(&input)?)),
+            11 => Some(A::hash_sha3_512(&raw_bits_le_circuit(&input)?)),
+            _ => None,
+        };
+        // Store the full digest as an array of `u128` words.
+        if let Some(digest) = digest {
+            let words = to_words_le(&digest)
+                .iter()
+                .map(|word| {
+                    let word = circuit::Literal::U128(circuit::U128::from_bits_le(word));
+                    circuit::Plaintext::Literal(word, Default::default())
+                })
+                .collect();
+            let output = circuit::Value::Plaintext(circuit::Plaintext::Array(words, Default::default()));
+            return registers.store_circuit(stack, &self.destination, output);
+        }
+
         // Hash the input.
-        let output = match VARIANT {
-            0 => A::hash_bhp256(&input.to_bits_le()),
-            1 => A::hash_bhp512(&input.to_bits_le()),
-            2 => A::hash_bhp768(&input.to_bits_le()),
-            3 => A::hash_bhp1024(&input.to_bits_le()),
-            4 => A::hash_ped64(&input.to_bits_le()),
-            5 => A::hash_ped128(&input.to_bits_le()),
-            6 => A::hash_psd2(&input.to_fields()),
-            7 => A::hash_psd4(&input.to_fields()),
-            8 => A::hash_psd8(&input.to_fields()),
+        let output = match (VARIANT, self.destination_type) {
+            (0, _) => circuit::Literal::Field(A::hash_bhp256(&input.to_bits_le())),
+            (1, _) => circuit::Literal::Field(A::hash_bhp512(&input.to_bits_le())),
+            (2, _) => circuit::Literal::Field(A::hash_bhp768(&input.to_bits_le())),
+            (3, _) => circuit::Literal::Field(A::hash_bhp1024(&input.to_bits_le())),
+            (4, _) => circuit::Literal::Field(A::hash_ped64(&input.to_bits_le())),
+            (5, _) => circuit::Literal::Field(A::hash_ped128(&input.to_bits_le())),
+            (6, LiteralType::Address | LiteralType::Group) => {
+                circuit::Literal::Group(A::hash_to_group_psd2(&input.to_fields()))
+            }
+            (6, LiteralType::Scalar) => circuit::Literal::Scalar(A::hash_to_scalar_psd2(&input.to_fields())),
+            (6, _) => circuit::Literal::Field(A::hash_psd2(&input.to_fields())),
+            (7, LiteralType::Address | LiteralType::Group) => {
+                circuit::Literal::Group(A::hash_to_group_psd4(&input.to_fields()))
+            }
+            (7, LiteralType::Scalar) => circuit::Literal::Scalar(A::hash_to_scalar_psd4(&input.to_fields())),
+            (7, _) => circuit::Literal::Field(A::hash_psd4(&input.to_fields())),
+            (8, LiteralType::Address | LiteralType::Group) => {
+                circuit::Literal::Group(A::hash_to_group_psd8(&input.to_fields()))
+            }
+            (8, LiteralType::Scalar) => circuit::Literal::Scalar(A::hash_to_scalar_psd8(&input.to_fields())),
+            (8, _) => circuit::Literal::Field(A::hash_psd8(&input.to_fields())),
             _ => bail!("Invalid 'hash' variant: {VARIANT}"),
         };
+        // Cast the output into the destination type, keeping the lower bits if necessary.
+        let output = output.cast_lossy(self.destination_type)?;
         // Convert the output to a stack value.
-        let output =
-            circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::Field(output), Default::default()));
+        let output = circuit::Value::Plaintext(circuit::Plaintext::Literal(output, Default::default()));
         // Store the output.
         registers.store_circuit(stack, &self.destination, output)
     }
 
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &Stack<N>,
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
     /// Returns the output type from the given program and input types.
     #[inline]
     pub fn output_types(&self, _stack: &Stack<N>, input_types: &[RegisterType<N>]) -> Result<Vec<RegisterType<N>>> {
@@ -174,11 +351,29 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             bail!("Instruction '{}' expects 1 operands, found {} operands", Self::opcode(), self.operands.len())
         }
 
+        // Ensure the destination type is supported.
+        self.check_destination_type()?;
+
         // TODO (howardwu): If the operation is Pedersen, check that it is within the number of bits.
 
+        // Ensure Keccak and SHA-3 hash a literal or an array of literals, and output the full digest.
+        if let Some(num_words) = Self::num_digest_words() {
+            match &input_types[0] {
+                RegisterType::Plaintext(PlaintextType::Literal(..)) => (),
+                RegisterType::Plaintext(PlaintextType::Array(array_type))
+                    if matches!(array_type.element_type(), PlaintextType::Literal(..)) => {}
+                input_type => {
+                    let opcode = Self::opcode();
+                    bail!("Instruction '{opcode}' expects a literal or an array of literals, found '{input_type}'")
+                }
+            }
+            let digest_type = ArrayType::new(PlaintextType::Literal(LiteralType::U128), num_words)?;
+            return Ok(vec![RegisterType::Plaintext(PlaintextType::Array(digest_type))]);
+        }
+
         match VARIANT {
             0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 => {
-                Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field))])
+                Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(self.destination_type))])
             }
             _ => bail!("Invalid 'hash' variant: {VARIANT}"),
         }
@@ -204,7 +399,20 @@ impl<N: Network, const VARIANT: u8> Parser for HashInstruction<N, VARIANT> {
         // Parse the destination register from the string.
         let (string, destination) = Register::parse(string)?;
 
-        Ok((string, Self { operands: vec![operand], destination }))
+        // Optionally parse the "as" from the string.
+        let (string, destination_type) = match opt(pair(Sanitizer::parse_whitespaces, tag("as")))(string)? {
+            // If the "as" was not parsed, the destination type is a field element.
+            (string, None) => (string, LiteralType::Field),
+            // If the "as" was parsed, parse the destination type from the string.
+            (string, Some(_)) => {
+                // Parse the whitespace from the string.
+                let (string, _) = Sanitizer::parse_whitespaces(string)?;
+                // Parse the destination type from the string.
+                LiteralType::parse(string)?
+            }
+        };
+
+        Ok((string, Self { operands: vec![operand], destination, destination_type }))
     }
 }
 
@@ -244,19 +452,38 @@ impl<N: Network, const VARIANT: u8> Display for HashInstruction<N, VARIANT> {
         // Print the operation.
         write!(f, "{} ", Self::opcode())?;
         self.operands.iter().try_for_each(|operand| write!(f, "{} ", operand))?;
-        write!(f, "into {}", self.destination)
+        write!(f, "into {}", self.destination)?;
+        // Print the destination type, if it is not a field element.
+        match self.destination_type {
+            LiteralType::Field => Ok(()),
+            destination_type => write!(f, " as {destination_type}"),
+        }
     }
 }
 
 impl<N: Network, const VARIANT: u8> FromBytes for HashInstruction<N, VARIANT> {
     /// Reads the operation from a buffer.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
-        // Read the operand.
-        let operands = vec![Operand::read_le(&mut reader)?];
+        // Read the first byte, which is either the destination type marker or the operand variant.
+        let (destination_type, operand) = match u8::read_le(&mut reader)? {
+            DESTINATION_TYPE_MARKER => {
+                // Read the destination type.
+                let destination_type = LiteralType::read_le(&mut reader)?;
+                // Ensure the destination type is not a field element, which is encoded without the marker.
+                if destination_type == LiteralType::Field {
+                    return Err(error("Failed to deserialize a hash instruction: the default type is marked"));
+                }
+                // Read the operand.
+                (destination_type, Operand::read_le(&mut reader)?)
+            }
+            // Read the operand, starting from its variant.
+            variant => (LiteralType::Field, Operand::read_le([variant].as_slice().chain(&mut reader))?),
+        };
+        let operands = vec![operand];
         // Read the destination register.
         let destination = Register::read_le(&mut reader)?;
         // Return the operation.
-        Ok(Self { operands, destination })
+        Ok(Self { operands, destination, destination_type })
     }
 }
 
@@ -267,6 +494,11 @@ impl<N: Network, const VARIANT: u8> ToBytes for HashInstruction<N, VARIANT> {
         if self.operands.len() != 1 {
             return Err(error(format!("The number of operands must be 1, found {}", self.operands.len())));
         }
+        // Write the destination type, if it is not a field element.
+        if self.destination_type != LiteralType::Field {
+            DESTINATION_TYPE_MARKER.write_le(&mut writer)?;
+            self.destination_type.write_le(&mut writer)?;
+        }
         // Write the operand.
         self.operands[0].write_le(&mut writer)?;
         // Write the destination register.
@@ -288,5 +520,79 @@ mod tests {
         assert_eq!(hash.operands.len(), 1, "The number of operands is incorrect");
         assert_eq!(hash.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
         assert_eq!(hash.destination, Register::Locator(1), "The destination register is incorrect");
+
+        let (string, hash) = HashKeccak256::<CurrentNetwork>::parse("hash.keccak256 r2 into r3").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(hash.operands.len(), 1, "The number of operands is incorrect");
+        assert_eq!(hash.operands[0], Operand::Register(Register::Locator(2)), "The first operand is incorrect");
+        assert_eq!(hash.destination, Register::Locator(3), "The destination register is incorrect");
+        assert_eq!(hash.destination_type, LiteralType::Field, "The destination type is incorrect");
+
+        let (string, hash) = HashPSD2::<CurrentNetwork>::parse("hash.psd2 r0 into r1 as u64").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(hash.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(hash.destination, Register::Locator(1), "The destination register is incorrect");
+        assert_eq!(hash.destination_type, LiteralType::U64, "The destination type is incorrect");
+    }
+
+    #[test]
+    fn test_display_and_bytes() {
+        let expected = HashBHP256::<CurrentNetwork>::from_str("hash.bhp256 r0 into r1").unwrap();
+        assert_eq!("hash.bhp256 r0 into r1", expected.to_string());
+        assert_eq!(expected, HashBHP256::from_bytes_le(&expected.to_bytes_le().unwrap()).unwrap());
+
+        let expected = HashPSD4::<CurrentNetwork>::from_str("hash.psd4 r0 into r1 as scalar").unwrap();
+        assert_eq!("hash.psd4 r0 into r1 as scalar", expected.to_string());
+        assert_eq!(expected, HashPSD4::from_bytes_le(&expected.to_bytes_le().unwrap()).unwrap());
+
+        let expected = HashKeccak256::<CurrentNetwork>::from_str("hash.keccak256 r0 into r1").unwrap();
+        assert_eq!("hash.keccak256 r0 into r1", expected.to_string());
+        assert_eq!(expected, HashKeccak256::from_bytes_le(&expected.to_bytes_le().unwrap()).unwrap());
+    }
+
+    #[test]
+    fn test_bytes_without_destination_type() {
+        // Ensure a hash into a field element keeps the encoding of the operand and the destination register.
+        let hash = HashBHP256::<CurrentNetwork>::from_str("hash.bhp256 r0 into r1").unwrap();
+        let expected = [hash.operands[0].to_bytes_le().unwrap(), hash.destination.to_bytes_le().unwrap()].concat();
+        assert_eq!(expected, hash.to_bytes_le().unwrap());
+
+        // Ensure a hash into another type is prefixed with the marker and the destination type.
+        let hash = HashPSD2::<CurrentNetwork>::from_str("hash.psd2 r0 into r1 as u64").unwrap();
+        let bytes = hash.to_bytes_le().unwrap();
+        assert_eq!(DESTINATION_TYPE_MARKER, bytes[0]);
+        assert_eq!(hash, HashPSD2::from_bytes_le(&bytes).unwrap());
+
+        // Ensure a marked field element is rejected, so that the encoding is unique.
+        let mut bytes = vec![DESTINATION_TYPE_MARKER];
+        bytes.extend(LiteralType::Field.to_bytes_le().unwrap());
+        bytes.extend(HashBHP256::<CurrentNetwork>::from_str("hash.bhp256 r0 into r1").unwrap().to_bytes_le().unwrap());
+        assert!(HashBHP256::<CurrentNetwork>::from_bytes_le(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_check_destination_type() {
+        let hash = HashBHP256::<CurrentNetwork>::from_str("hash.bhp256 r0 into r1 as u8").unwrap();
+        assert!(hash.check_destination_type().is_ok());
+        let hash = HashPSD2::<CurrentNetwork>::from_str("hash.psd2 r0 into r1 as group").unwrap();
+        assert!(hash.check_destination_type().is_ok());
+        let hash = HashBHP256::<CurrentNetwork>::from_str("hash.bhp256 r0 into r1 as group").unwrap();
+        assert!(hash.check_destination_type().is_err());
+        let hash = HashPSD2::<CurrentNetwork>::from_str("hash.psd2 r0 into r1 as string").unwrap();
+        assert!(hash.check_destination_type().is_err());
+        let hash = HashKeccak256::<CurrentNetwork>::from_str("hash.keccak256 r0 into r1").unwrap();
+        assert!(hash.check_destination_type().is_ok());
+        let hash = HashKeccak256::<CurrentNetwork>::from_str("hash.keccak256 r0 into r1 as u128").unwrap();
+        assert!(hash.check_destination_type().is_err());
+    }
+
+    #[test]
+    fn test_to_words_le() {
+        // Ensure each word holds 16 bytes of the digest in big-endian order.
+        let digest = (0..32u8).collect::<Vec<_>>();
+        let words = to_words_le(&digest.to_bits_le());
+        assert_eq!(words.len(), 2);
+        assert_eq!(u128::from_bits_le(&words[0]).unwrap(), u128::from_be_bytes(digest[..16].try_into().unwrap()));
+        assert_eq!(u128::from_bits_le(&words[1]).unwrap(), u128::from_be_bytes(digest[16..].try_into().unwrap()));
     }
 }
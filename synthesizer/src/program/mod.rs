@@ -165,6 +165,16 @@ function fee:
         &self.mappings
     }
 
+    /// Returns the structs in the program.
+    pub const fn structs(&self) -> &IndexMap<Identifier<N>, Struct<N>> {
+        &self.structs
+    }
+
+    /// Returns the records in the program.
+    pub const fn records(&self) -> &IndexMap<Identifier<N>, RecordType<N>> {
+        &self.records
+    }
+
     /// Returns the closures in the program.
     pub const fn closures(&self) -> &IndexMap<Identifier<N>, Closure<N>> {
         &self.closures
@@ -367,6 +377,14 @@ impl<N: Network> Program<N> {
                         bail!("'{member_identifier}' in struct '{}' is not defined.", struct_name)
                     }
                 }
+                PlaintextType::Array(array_type) => {
+                    // Ensure the element struct name exists in the program.
+                    if let PlaintextType::Struct(element_identifier) = array_type.element_type() {
+                        if !self.structs.contains_key(&element_identifier) {
+                            bail!("'{element_identifier}' in struct '{}' is not defined.", struct_name)
+                        }
+                    }
+                }
             }
         }
 
@@ -420,6 +438,13 @@ impl<N: Network> Program<N> {
                             bail!("Struct '{identifier}' in record '{record_name}' is not defined.")
                         }
                     }
+                    PlaintextType::Array(array_type) => {
+                        if let PlaintextType::Struct(identifier) = array_type.element_type() {
+                            if !self.structs.contains_key(&identifier) {
+                                bail!("Struct '{identifier}' in record '{record_name}' is not defined.")
+                            }
+                        }
+                    }
                 },
             }
         }
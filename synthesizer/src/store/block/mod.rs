@@ -16,13 +16,14 @@
 
 use crate::{
     atomic_write_batch,
-    block::{Block, Header, Transactions},
+    block::{Block, Header, Transaction, Transactions},
     coinbase_puzzle::{CoinbaseSolution, PuzzleCommitment},
     cow_to_cloned,
     cow_to_copied,
     store::{
         helpers::{memory_map::MemoryMap, Map, MapRead},
         TransactionMemory,
+        TransactionStatus,
         TransactionStorage,
         TransactionStore,
         TransitionMemory,
@@ -440,6 +441,14 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         }
     }
 
+    /// Returns the transaction IDs, in the order of the block transactions, for the given `block hash`.
+    fn get_block_transaction_ids(&self, block_hash: &N::BlockHash) -> Result<Option<Vec<N::TransactionID>>> {
+        match self.transactions_map().get(block_hash)? {
+            Some(transaction_ids) => Ok(Some(cow_to_cloned!(transaction_ids))),
+            None => Ok(None),
+        }
+    }
+
     /// Returns the block transactions for the given `block hash`.
     fn get_block_transactions(&self, block_hash: &N::BlockHash) -> Result<Option<Transactions<N>>> {
         // Retrieve the transaction IDs.
@@ -460,6 +469,31 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         Ok(Some(Transactions::from(&transactions)))
     }
 
+    /// Returns the block transactions, along with their finalize status, for the given `block hash`.
+    fn get_confirmed_transactions(
+        &self,
+        block_hash: &N::BlockHash,
+    ) -> Result<Option<Vec<(Transaction<N>, TransactionStatus)>>> {
+        // Retrieve the transactions.
+        let transactions = match self.get_block_transactions(block_hash)? {
+            Some(transactions) => transactions,
+            None => return Ok(None),
+        };
+        // Retrieve the status of each transaction.
+        let confirmed = transactions
+            .values()
+            .map(|transaction| {
+                let transaction_id = transaction.id();
+                match self.transaction_store().get_status(&transaction_id)? {
+                    Some(status) => Ok((transaction.clone(), status)),
+                    None => bail_with_block!("Missing status for transaction '{transaction_id}'", self, block_hash),
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Return the confirmed transactions.
+        Ok(Some(confirmed))
+    }
+
     /// Returns the block coinbase solution for the given `block hash`.
     fn get_block_coinbase(&self, block_hash: &N::BlockHash) -> Result<Option<CoinbaseSolution<N>>> {
         match self.coinbase_solution_map().get(block_hash)? {
@@ -518,6 +552,22 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         // Return the block.
         Ok(Some(Block::from(previous_hash, header, transactions, coinbase, signature)?))
     }
+
+    /// Returns the blocks with a height in the range `start..end`, in ascending order of height.
+    /// Note: The range may extend past the latest block, in which case only the existing blocks are returned.
+    fn get_blocks_in_range(&self, start: u32, end: u32) -> Result<Vec<Block<N>>> {
+        // Ensure the range is well-formed.
+        ensure!(start <= end, "Invalid block range: the start ({start}) must not exceed the end ({end})");
+
+        // Retrieve the block hashes in the range, and the block for each block hash.
+        self.id_map()
+            .iter_range(start..end)
+            .map(|(height, block_hash)| match self.get_block(&block_hash)? {
+                Some(block) => Ok(block),
+                None => bail!("Missing block {height} ('{block_hash}')"),
+            })
+            .collect()
+    }
 }
 
 /// An in-memory block storage.
@@ -807,6 +857,11 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         (*self.tree.read().root()).into()
     }
 
+    /// Returns the current block height, or `None` if there are no blocks in storage.
+    pub fn current_block_height(&self) -> Result<Option<u32>> {
+        Ok(u32::try_from(self.tree.read().number_of_leaves())?.checked_sub(1))
+    }
+
     /// Returns the state root that contains the given `block height`.
     pub fn get_state_root(&self, block_height: u32) -> Result<Option<N::StateRoot>> {
         self.storage.get_state_root(block_height)
@@ -837,11 +892,24 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         self.storage.get_block_header(block_hash)
     }
 
+    /// Returns the transaction IDs, in the order of the block transactions, for the given `block hash`.
+    pub fn get_block_transaction_ids(&self, block_hash: &N::BlockHash) -> Result<Option<Vec<N::TransactionID>>> {
+        self.storage.get_block_transaction_ids(block_hash)
+    }
+
     /// Returns the block transactions for the given `block hash`.
     pub fn get_block_transactions(&self, block_hash: &N::BlockHash) -> Result<Option<Transactions<N>>> {
         self.storage.get_block_transactions(block_hash)
     }
 
+    /// Returns the block transactions, along with their finalize status, for the given `block hash`.
+    pub fn get_confirmed_transactions(
+        &self,
+        block_hash: &N::BlockHash,
+    ) -> Result<Option<Vec<(Transaction<N>, TransactionStatus)>>> {
+        self.storage.get_confirmed_transactions(block_hash)
+    }
+
     /// Returns the block coinbase solution for the given `block hash`.
     pub fn get_block_coinbase(&self, block_hash: &N::BlockHash) -> Result<Option<CoinbaseSolution<N>>> {
         self.storage.get_block_coinbase(block_hash)
@@ -857,6 +925,11 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         self.storage.get_block(block_hash)
     }
 
+    /// Returns the blocks with a height in the range `start..end`, in ascending order of height.
+    pub fn get_blocks_in_range(&self, start: u32, end: u32) -> Result<Vec<Block<N>>> {
+        self.storage.get_blocks_in_range(start, end)
+    }
+
     /// Returns the program for the given `program ID`.
     pub fn get_program(&self, program_id: &ProgramID<N>) -> Result<Option<Program<N>>> {
         self.storage.transaction_store().get_program(program_id)
@@ -911,17 +984,10 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_insert_get_remove() {
-        let mut rng = TestRng::default();
-
-        // Sample the block.
-        let block = crate::vm::test_helpers::sample_genesis_block(&mut rng);
+    /// Checks `insert`, `get_block`, and `remove_last_n` on the given block store.
+    fn check_insert_get_remove<N: Network, B: BlockStorage<N>>(block_store: BlockStore<N, B>, block: Block<N>) {
         let block_hash = block.hash();
 
-        // Initialize a new block store.
-        let block_store = BlockStore::<_, BlockMemory<_>>::open(None).unwrap();
-
         // Ensure the block does not exist.
         let candidate = block_store.get_block(&block_hash).unwrap();
         assert_eq!(None, candidate);
@@ -941,6 +1007,57 @@ mod tests {
         assert_eq!(None, candidate);
     }
 
+    #[test]
+    fn test_insert_get_remove() {
+        let mut rng = TestRng::default();
+
+        // Sample the block.
+        let block = crate::vm::test_helpers::sample_genesis_block(&mut rng);
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<_, BlockMemory<_>>::open(None).unwrap();
+        // Check the operations.
+        check_insert_get_remove(block_store, block);
+    }
+
+    #[test]
+    #[cfg(feature = "rocks")]
+    fn test_insert_get_remove_rocksdb() {
+        use crate::store::helpers::rocksdb::{sample_dev, BlockDB};
+
+        let mut rng = TestRng::default();
+
+        // Sample the block.
+        let block = crate::vm::test_helpers::sample_genesis_block(&mut rng);
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<_, BlockDB<_>>::open(sample_dev()).unwrap();
+        // Check the operations.
+        check_insert_get_remove(block_store, block);
+    }
+
+    #[test]
+    fn test_get_blocks_in_range() {
+        let mut rng = TestRng::default();
+
+        // Sample the block.
+        let block = crate::vm::test_helpers::sample_genesis_block(&mut rng);
+
+        // Initialize a new block store, and insert the block.
+        let block_store = BlockStore::<_, BlockMemory<_>>::open(None).unwrap();
+        assert!(block_store.get_blocks_in_range(0, 1).unwrap().is_empty());
+        block_store.insert(&block).unwrap();
+
+        // Ensure the block is returned for the ranges that contain it.
+        assert_eq!(block_store.get_blocks_in_range(0, 1).unwrap(), vec![block.clone()]);
+        assert_eq!(block_store.get_blocks_in_range(0, 10).unwrap(), vec![block]);
+        // Ensure no blocks are returned for the ranges that do not contain it.
+        assert!(block_store.get_blocks_in_range(0, 0).unwrap().is_empty());
+        assert!(block_store.get_blocks_in_range(1, 10).unwrap().is_empty());
+        // Ensure a malformed range fails.
+        assert!(block_store.get_blocks_in_range(1, 0).is_err());
+    }
+
     #[test]
     fn test_find_block_hash() {
         let mut rng = TestRng::default();
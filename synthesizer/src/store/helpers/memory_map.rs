@@ -14,11 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::store::helpers::{Map, MapRead};
+use crate::store::helpers::{serialize_key, Map, MapRead};
 use console::network::prelude::*;
 use indexmap::IndexMap;
 
-use core::{borrow::Borrow, hash::Hash};
+use core::{borrow::Borrow, hash::Hash, ops::RangeBounds};
 use indexmap::map;
 use parking_lot::{Mutex, RwLock};
 use std::{
@@ -37,6 +37,7 @@ pub struct MemoryMap<
     map: Arc<RwLock<IndexMap<K, V>>>,
     batch_in_progress: Arc<AtomicBool>,
     atomic_batch: Arc<Mutex<IndexMap<K, Option<V>>>>,
+    checkpoints: Arc<Mutex<Vec<Vec<(K, Option<Option<V>>)>>>>,
 }
 
 impl<
@@ -45,7 +46,12 @@ impl<
 > Default for MemoryMap<K, V>
 {
     fn default() -> Self {
-        Self { map: Default::default(), batch_in_progress: Default::default(), atomic_batch: Default::default() }
+        Self {
+            map: Default::default(),
+            batch_in_progress: Default::default(),
+            atomic_batch: Default::default(),
+            checkpoints: Default::default(),
+        }
     }
 }
 
@@ -60,6 +66,22 @@ impl<
             map: Arc::new(RwLock::new(IndexMap::from_iter(iter))),
             batch_in_progress: Default::default(),
             atomic_batch: Default::default(),
+            checkpoints: Default::default(),
+        }
+    }
+}
+
+impl<
+    K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> MemoryMap<K, V>
+{
+    /// Queues the given operation in the atomic batch, and records the operation it replaces
+    /// in the latest checkpoint, so that `atomic_rewind` can restore it.
+    fn queue(&self, key: K, operation: Option<V>) {
+        let replaced = self.atomic_batch.lock().insert(key, operation);
+        if let Some(checkpoint) = self.checkpoints.lock().last_mut() {
+            checkpoint.push((key, replaced));
         }
     }
 }
@@ -80,7 +102,7 @@ impl<
         match is_batch {
             // If a batch is in progress, add the key-value pair to the batch.
             true => {
-                self.atomic_batch.lock().insert(key, Some(value));
+                self.queue(key, Some(value));
             }
             // Otherwise, insert the key-value pair directly into the map.
             false => {
@@ -100,7 +122,7 @@ impl<
         match is_batch {
             // If a batch is in progress, add the key-None pair to the batch.
             true => {
-                self.atomic_batch.lock().insert(*key, None);
+                self.queue(*key, None);
             }
             // Otherwise, remove the key-value pair directly from the map.
             false => {
@@ -119,6 +141,8 @@ impl<
         self.batch_in_progress.store(true, Ordering::SeqCst);
         // Ensure that the atomic batch is empty.
         assert!(self.atomic_batch.lock().is_empty());
+        // Ensure that there are no checkpoints.
+        assert!(self.checkpoints.lock().is_empty());
     }
 
     ///
@@ -130,12 +154,60 @@ impl<
         self.batch_in_progress.load(Ordering::SeqCst)
     }
 
+    ///
+    /// Saves the current list of pending operations, so that if `atomic_rewind` is called,
+    /// all operations queued after this checkpoint are discarded.
+    ///
+    fn atomic_checkpoint(&self) {
+        // Save a checkpoint, which records the operations it replaces in the atomic batch.
+        self.checkpoints.lock().push(Vec::new());
+    }
+
+    ///
+    /// Removes the latest atomic checkpoint, keeping the operations queued after it.
+    ///
+    fn clear_latest_checkpoint(&self) {
+        let mut checkpoints = self.checkpoints.lock();
+        // Move the replaced operations of the latest checkpoint into the previous checkpoint, if it exists,
+        // so that rewinding to the previous checkpoint still discards the operations queued after the latest one.
+        if let Some(replaced) = checkpoints.pop() {
+            if let Some(previous) = checkpoints.last_mut() {
+                previous.extend(replaced);
+            }
+        }
+    }
+
+    ///
+    /// Discards all pending operations queued after the latest `atomic_checkpoint`
+    /// (or after `start_atomic`, if no checkpoints have been created).
+    ///
+    fn atomic_rewind(&self) {
+        // Retrieve the latest checkpoint.
+        let checkpoint = self.checkpoints.lock().pop();
+        let mut atomic_batch = self.atomic_batch.lock();
+        match checkpoint {
+            // Restore the operations replaced after the checkpoint, in reverse order.
+            Some(replaced) => {
+                for (key, operation) in replaced.into_iter().rev() {
+                    match operation {
+                        Some(operation) => atomic_batch.insert(key, operation),
+                        None => atomic_batch.shift_remove(&key),
+                    };
+                }
+            }
+            // If there are no checkpoints, discard all the queued operations.
+            None => atomic_batch.clear(),
+        }
+    }
+
     ///
     /// Aborts the current atomic operation.
     ///
     fn abort_atomic(&self) {
         // Clear the atomic batch.
         *self.atomic_batch.lock() = Default::default();
+        // Clear the checkpoints.
+        *self.checkpoints.lock() = Default::default();
         // Set the atomic batch flag to `false`.
         self.batch_in_progress.store(false, Ordering::SeqCst);
     }
@@ -146,6 +218,8 @@ impl<
     fn finish_atomic(&self) -> Result<()> {
         // Retrieve the atomic batch.
         let operations = core::mem::take(&mut *self.atomic_batch.lock());
+        // Clear the checkpoints.
+        *self.checkpoints.lock() = Default::default();
 
         if !operations.is_empty() {
             // Acquire a write lock on the map.
@@ -212,7 +286,11 @@ impl<
         Q: PartialEq + Eq + Hash + Serialize + ?Sized,
     {
         // Return early if there is no atomic batch in progress.
-        if self.batch_in_progress.load(Ordering::SeqCst) { self.atomic_batch.lock().get(key).cloned() } else { None }
+        if !self.batch_in_progress.load(Ordering::SeqCst) {
+            return None;
+        }
+        // Return the queued operation for the key, if it exists.
+        self.atomic_batch.lock().get(key).cloned()
     }
 
     ///
@@ -235,6 +313,43 @@ impl<
     fn values(&'a self) -> Self::Values {
         self.map.read().clone().into_values().map(Cow::Owned)
     }
+
+    ///
+    /// Returns an iterator visiting each key-value pair in the map whose key is in the given range,
+    /// in ascending order of the keys.
+    ///
+    fn iter_range<R: RangeBounds<K>>(&'a self, range: R) -> Self::Iterator
+    where
+        K: Ord,
+    {
+        // Retrieve the entries in the range.
+        let mut entries: IndexMap<K, V> =
+            self.map.read().iter().filter(|(k, _)| range.contains(k)).map(|(k, v)| (*k, v.clone())).collect();
+        // Sort the entries by key.
+        entries.sort_keys();
+        entries.into_iter().map(|(k, v)| (Cow::Owned(k), Cow::Owned(v)))
+    }
+
+    ///
+    /// Returns an iterator visiting each key-value pair in the map whose serialized key starts with
+    /// the serialized prefix, in ascending order of the serialized keys.
+    ///
+    fn iter_prefix<P: Serialize + ?Sized>(&'a self, prefix: &P) -> Result<Self::Iterator> {
+        // Serialize the prefix.
+        let prefix = serialize_key(prefix)?;
+        // Retrieve the entries whose serialized key starts with the prefix.
+        let mut entries = Vec::new();
+        for (k, v) in self.map.read().iter() {
+            let raw_key = serialize_key(k)?;
+            if raw_key.starts_with(&prefix) {
+                entries.push((raw_key, *k, v.clone()));
+            }
+        }
+        // Sort the entries by serialized key.
+        entries.sort_unstable_by(|(a, ..), (b, ..)| a.cmp(b));
+        let entries: IndexMap<K, V> = entries.into_iter().map(|(_, k, v)| (k, v)).collect();
+        Ok(entries.into_iter().map(|(k, v)| (Cow::Owned(k), Cow::Owned(v))))
+    }
 }
 
 impl<
@@ -479,4 +594,96 @@ mod tests {
         // The map should contain NUM_ITEMS items now.
         assert_eq!(map.iter().count(), NUM_ITEMS);
     }
+
+    #[test]
+    fn test_atomic_writes_can_be_rewound() {
+        // Initialize a map.
+        let map: MemoryMap<usize, String> = Default::default();
+
+        // Start an atomic write batch.
+        map.start_atomic();
+
+        // Queue an insertion, and save a checkpoint.
+        map.insert(0, "0".to_string()).unwrap();
+        map.atomic_checkpoint();
+
+        // Queue an update and an insertion after the checkpoint.
+        map.insert(0, "updated".to_string()).unwrap();
+        map.insert(1, "1".to_string()).unwrap();
+        assert_eq!(map.get_batched(&0), Some(Some("updated".to_string())));
+        assert_eq!(map.get_batched(&1), Some(Some("1".to_string())));
+
+        // Rewind to the checkpoint.
+        map.atomic_rewind();
+
+        // Ensure only the operations before the checkpoint remain.
+        assert_eq!(map.get_batched(&0), Some(Some("0".to_string())));
+        assert_eq!(map.get_batched(&1), None);
+
+        // Save a checkpoint, queue a removal, and keep it.
+        map.atomic_checkpoint();
+        map.remove(&0).unwrap();
+        map.clear_latest_checkpoint();
+        assert_eq!(map.get_batched(&0), Some(None));
+
+        // Queue another insertion, and finish the atomic write batch.
+        map.insert(2, "2".to_string()).unwrap();
+        map.finish_atomic().unwrap();
+
+        // Ensure the map reflects the operations that were not rewound.
+        assert_eq!(map.get(&0).unwrap(), None);
+        assert_eq!(map.get(&1).unwrap(), None);
+        assert_eq!(map.get(&2).unwrap(), Some(Cow::Borrowed(&"2".to_string())));
+    }
+
+    #[test]
+    fn test_atomic_writes_can_be_rewound_past_a_cleared_checkpoint() {
+        // Initialize a map.
+        let map: MemoryMap<usize, String> = Default::default();
+
+        // Start an atomic write batch, queue an insertion, and save a checkpoint.
+        map.start_atomic();
+        map.insert(0, "0".to_string()).unwrap();
+        map.atomic_checkpoint();
+
+        // Queue an update, save a nested checkpoint, and queue a removal and an insertion.
+        map.insert(0, "updated".to_string()).unwrap();
+        map.atomic_checkpoint();
+        map.remove(&0).unwrap();
+        map.insert(1, "1".to_string()).unwrap();
+        assert_eq!(map.get_batched(&0), Some(None));
+
+        // Keep the operations after the nested checkpoint.
+        map.clear_latest_checkpoint();
+        assert_eq!(map.get_batched(&0), Some(None));
+        assert_eq!(map.get_batched(&1), Some(Some("1".to_string())));
+
+        // Rewind to the first checkpoint, and ensure every operation after it is discarded.
+        map.atomic_rewind();
+        assert_eq!(map.get_batched(&0), Some(Some("0".to_string())));
+        assert_eq!(map.get_batched(&1), None);
+
+        // Finish the atomic write batch, and ensure the map reflects the remaining operation.
+        map.finish_atomic().unwrap();
+        assert_eq!(map.get(&0).unwrap(), Some(Cow::Borrowed(&"0".to_string())));
+        assert_eq!(map.iter().count(), 1);
+    }
+
+    #[test]
+    fn test_iter_range_and_prefix() {
+        // Initialize a map, with keys inserted out of order.
+        let map: MemoryMap<(u32, u32), String> = Default::default();
+        for (a, b) in [(2, 1), (1, 2), (2, 0), (3, 0), (1, 1)] {
+            map.insert((a, b), format!("{a}.{b}")).unwrap();
+        }
+
+        // Ensure the range is iterated in ascending order.
+        let keys = map.iter_range((1, 2)..(3, 0)).map(|(k, _)| *k).collect::<Vec<_>>();
+        assert_eq!(keys, vec![(1, 2), (2, 0), (2, 1)]);
+
+        // Ensure the prefix is iterated in ascending order.
+        let entries = map.iter_prefix(&2u32).unwrap().map(|(k, v)| (*k, v.into_owned())).collect::<Vec<_>>();
+        assert_eq!(entries, vec![((2, 0), "2.0".to_string()), ((2, 1), "2.1".to_string())]);
+        assert!(map.iter_prefix(&4u32).unwrap().next().is_none());
+    }
 }
@@ -15,10 +15,14 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 pub mod memory_map;
+pub mod overlay_map;
+
+#[cfg(feature = "rocks")]
+pub mod rocksdb;
 
 use console::network::prelude::*;
 
-use core::{borrow::Borrow, hash::Hash};
+use core::{borrow::Borrow, hash::Hash, ops::RangeBounds};
 use std::borrow::Cow;
 
 /// A trait representing map-like storage operations with read-write capabilities.
@@ -51,6 +55,23 @@ pub trait Map<
     ///
     fn is_atomic_in_progress(&self) -> bool;
 
+    ///
+    /// Saves the current list of pending operations, so that if `atomic_rewind` is called,
+    /// all operations queued after this checkpoint are discarded.
+    ///
+    fn atomic_checkpoint(&self);
+
+    ///
+    /// Removes the latest atomic checkpoint, keeping the operations queued after it.
+    ///
+    fn clear_latest_checkpoint(&self);
+
+    ///
+    /// Discards all pending operations queued after the latest `atomic_checkpoint`
+    /// (or after `start_atomic`, if no checkpoints have been created).
+    ///
+    fn atomic_rewind(&self);
+
     ///
     /// Aborts the current atomic operation.
     ///
@@ -139,6 +160,37 @@ pub trait MapRead<
     /// Returns an iterator over each value in the map.
     ///
     fn values(&'a self) -> Self::Values;
+
+    ///
+    /// Returns an iterator visiting each key-value pair in the map whose key is in the given range,
+    /// in ascending order of the keys.
+    ///
+    fn iter_range<R: RangeBounds<K>>(&'a self, range: R) -> Self::Iterator
+    where
+        K: Ord;
+
+    ///
+    /// Returns an iterator visiting each key-value pair in the map whose serialized key starts with
+    /// the serialized prefix, in ascending order of the serialized keys (see `serialize_key`).
+    ///
+    /// For example, the entries of a map keyed by `(ProgramID, Identifier)` for one program
+    /// are retrieved with the program ID as the prefix.
+    ///
+    fn iter_prefix<P: Serialize + ?Sized>(&'a self, prefix: &P) -> Result<Self::Iterator>;
+}
+
+/// Serializes the given key (or key prefix) of a map, in big-endian byte order.
+/// As a result, the serialized keys of unsigned integers, and of tuples of them, are in ascending order of the keys.
+pub(crate) fn serialize_key<Q: Serialize + ?Sized>(key: &Q) -> Result<Vec<u8>> {
+    use bincode::Options;
+    Ok(bincode::DefaultOptions::new().with_fixint_encoding().with_big_endian().serialize(key)?)
+}
+
+/// Deserializes the given key of a map, from its serialization by `serialize_key`.
+#[cfg(feature = "rocks")]
+pub(crate) fn deserialize_key<K: for<'de> Deserialize<'de>>(bytes: &[u8]) -> Result<K> {
+    use bincode::Options;
+    Ok(bincode::DefaultOptions::new().with_fixint_encoding().with_big_endian().deserialize(bytes)?)
 }
 
 /// This macro executes the given block of operations as a new atomic write batch IFF there is no
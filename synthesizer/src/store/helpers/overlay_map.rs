@@ -0,0 +1,320 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::store::helpers::{memory_map::MemoryMap, serialize_key, Map, MapRead};
+use console::network::prelude::*;
+use indexmap::IndexMap;
+
+use core::{borrow::Borrow, hash::Hash, ops::RangeBounds};
+use std::borrow::Cow;
+
+/// A map that reads through to a base map, and keeps its own writes in memory.
+/// The base map is never written to, so the overlay may be discarded at any time.
+///
+/// The reads from the base map are speculative, so they include the pending atomic batch of the base map.
+#[derive(Clone)]
+pub struct OverlayMap<
+    K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    M: for<'a> Map<'a, K, V>,
+> {
+    /// The base map.
+    base: M,
+    /// The writes to the overlay, where `None` marks a removed key.
+    layer: MemoryMap<K, Option<V>>,
+}
+
+impl<
+    K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    M: for<'a> Map<'a, K, V>,
+> OverlayMap<K, V, M>
+{
+    /// Initializes a new overlay over the given base map.
+    pub fn new(base: M) -> Self {
+        Self { base, layer: Default::default() }
+    }
+
+    /// Returns the base map.
+    pub const fn base(&self) -> &M {
+        &self.base
+    }
+
+    /// Returns the entries of the base map, with the writes to the overlay applied.
+    /// Note: Like the base map, this iterates over the base entries outside of its atomic batch.
+    fn entries(&self) -> IndexMap<K, V> {
+        // Retrieve the entries of the base map.
+        let mut entries: IndexMap<K, V> = self.base.iter().map(|(k, v)| (k.into_owned(), v.into_owned())).collect();
+        // Apply the writes to the overlay.
+        for (k, v) in self.layer.iter() {
+            match v.into_owned() {
+                Some(v) => {
+                    entries.insert(k.into_owned(), v);
+                }
+                None => {
+                    entries.shift_remove(&*k);
+                }
+            }
+        }
+        entries
+    }
+}
+
+impl<
+    'a,
+    K: 'a + Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: 'a + Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    M: for<'b> Map<'b, K, V>,
+> Map<'a, K, V> for OverlayMap<K, V, M>
+{
+    ///
+    /// Inserts the given key-value pair into the overlay.
+    ///
+    fn insert(&self, key: K, value: V) -> Result<()> {
+        self.layer.insert(key, Some(value))
+    }
+
+    ///
+    /// Removes the key-value pair for the given key from the overlay.
+    ///
+    fn remove(&self, key: &K) -> Result<()> {
+        self.layer.insert(*key, None)
+    }
+
+    ///
+    /// Begins an atomic operation on the overlay.
+    ///
+    fn start_atomic(&self) {
+        self.layer.start_atomic()
+    }
+
+    ///
+    /// Checks whether an atomic operation is currently in progress on the overlay.
+    ///
+    fn is_atomic_in_progress(&self) -> bool {
+        self.layer.is_atomic_in_progress()
+    }
+
+    ///
+    /// Saves the current list of pending operations on the overlay.
+    ///
+    fn atomic_checkpoint(&self) {
+        self.layer.atomic_checkpoint()
+    }
+
+    ///
+    /// Removes the latest atomic checkpoint on the overlay.
+    ///
+    fn clear_latest_checkpoint(&self) {
+        self.layer.clear_latest_checkpoint()
+    }
+
+    ///
+    /// Discards all pending operations on the overlay queued after the latest `atomic_checkpoint`.
+    ///
+    fn atomic_rewind(&self) {
+        self.layer.atomic_rewind()
+    }
+
+    ///
+    /// Aborts the current atomic operation on the overlay.
+    ///
+    fn abort_atomic(&self) {
+        self.layer.abort_atomic()
+    }
+
+    ///
+    /// Finishes an atomic operation, performing all the queued writes on the overlay.
+    ///
+    fn finish_atomic(&self) -> Result<()> {
+        self.layer.finish_atomic()
+    }
+}
+
+impl<
+    'a,
+    K: 'a + Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: 'a + Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    M: for<'b> Map<'b, K, V>,
+> MapRead<'a, K, V> for OverlayMap<K, V, M>
+{
+    type Iterator = std::vec::IntoIter<(Cow<'a, K>, Cow<'a, V>)>;
+    type Keys = std::vec::IntoIter<Cow<'a, K>>;
+    type Values = std::vec::IntoIter<Cow<'a, V>>;
+
+    ///
+    /// Returns `true` if the given key exists in the overlay, or in the base map if it was not written.
+    ///
+    fn contains_key<Q>(&self, key: &Q) -> Result<bool>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        // Return the written value, if it exists.
+        if let Some(value) = self.layer.get(key)? {
+            return Ok(value.is_some());
+        }
+        // Return the speculative value from the base map.
+        match self.base.get_batched(key) {
+            Some(value) => Ok(value.is_some()),
+            None => self.base.contains_key(key),
+        }
+    }
+
+    ///
+    /// Returns the value for the given key from the overlay, or from the base map if it was not written.
+    ///
+    fn get<Q>(&'a self, key: &Q) -> Result<Option<Cow<'a, V>>>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        match self.layer.get(key)? {
+            Some(value) => Ok(value.into_owned().map(Cow::Owned)),
+            None => self.base.get_speculative(key),
+        }
+    }
+
+    ///
+    /// Returns the current value for the given key if it is scheduled
+    /// to be written to the overlay as part of an atomic batch.
+    ///
+    /// If the key does not exist, returns `None`.
+    /// If the key is removed in the batch, returns `Some(None)`.
+    /// If the key is inserted in the batch, returns `Some(Some(value))`.
+    ///
+    fn get_batched<Q>(&self, key: &Q) -> Option<Option<V>>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        self.layer.get_batched(key).map(Option::flatten)
+    }
+
+    ///
+    /// Returns an iterator visiting each key-value pair in the overlay.
+    ///
+    fn iter(&'a self) -> Self::Iterator {
+        self.entries().into_iter().map(|(k, v)| (Cow::Owned(k), Cow::Owned(v))).collect::<Vec<_>>().into_iter()
+    }
+
+    ///
+    /// Returns an iterator over each key in the overlay.
+    ///
+    fn keys(&'a self) -> Self::Keys {
+        self.entries().into_keys().map(Cow::Owned).collect::<Vec<_>>().into_iter()
+    }
+
+    ///
+    /// Returns an iterator over each value in the overlay.
+    ///
+    fn values(&'a self) -> Self::Values {
+        self.entries().into_values().map(Cow::Owned).collect::<Vec<_>>().into_iter()
+    }
+
+    ///
+    /// Returns an iterator visiting each key-value pair in the overlay whose key is in the given range,
+    /// in ascending order of the keys.
+    ///
+    fn iter_range<R: RangeBounds<K>>(&'a self, range: R) -> Self::Iterator
+    where
+        K: Ord,
+    {
+        // Retrieve the entries in the range.
+        let mut entries = self.entries();
+        entries.retain(|k, _| range.contains(k));
+        // Sort the entries by key.
+        entries.sort_keys();
+        entries.into_iter().map(|(k, v)| (Cow::Owned(k), Cow::Owned(v))).collect::<Vec<_>>().into_iter()
+    }
+
+    ///
+    /// Returns an iterator visiting each key-value pair in the overlay whose serialized key starts with
+    /// the serialized prefix, in ascending order of the serialized keys.
+    ///
+    fn iter_prefix<P: Serialize + ?Sized>(&'a self, prefix: &P) -> Result<Self::Iterator> {
+        // Serialize the prefix.
+        let prefix = serialize_key(prefix)?;
+        // Retrieve the entries whose serialized key starts with the prefix.
+        let mut entries = Vec::new();
+        for (k, v) in self.entries() {
+            let raw_key = serialize_key(&k)?;
+            if raw_key.starts_with(&prefix) {
+                entries.push((raw_key, k, v));
+            }
+        }
+        // Sort the entries by serialized key.
+        entries.sort_unstable_by(|(a, ..), (b, ..)| a.cmp(b));
+        Ok(entries.into_iter().map(|(_, k, v)| (Cow::Owned(k), Cow::Owned(v))).collect::<Vec<_>>().into_iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overlay_reads_through_and_keeps_writes() {
+        // Initialize a base map.
+        let base: MemoryMap<usize, String> = [(0, "0".to_string()), (1, "1".to_string())].into_iter().collect();
+        // Initialize an overlay over the base map.
+        let overlay = OverlayMap::new(base.clone());
+
+        // Ensure the overlay reads through to the base map.
+        assert_eq!(overlay.get(&0).unwrap().unwrap().into_owned(), "0");
+        assert!(overlay.contains_key(&1).unwrap());
+
+        // Update, remove, and insert a key in the overlay.
+        overlay.insert(0, "updated".to_string()).unwrap();
+        overlay.remove(&1).unwrap();
+        overlay.insert(2, "2".to_string()).unwrap();
+
+        // Ensure the overlay reflects the writes.
+        assert_eq!(overlay.get(&0).unwrap().unwrap().into_owned(), "updated");
+        assert!(!overlay.contains_key(&1).unwrap());
+        assert_eq!(overlay.get(&2).unwrap().unwrap().into_owned(), "2");
+        let entries = overlay.iter().map(|(k, v)| (*k, v.into_owned())).collect::<Vec<_>>();
+        assert_eq!(entries, vec![(0, "updated".to_string()), (2, "2".to_string())]);
+
+        // Ensure the base map is untouched.
+        let entries = base.iter().map(|(k, v)| (*k, v.into_owned())).collect::<Vec<_>>();
+        assert_eq!(entries, vec![(0, "0".to_string()), (1, "1".to_string())]);
+    }
+
+    #[test]
+    fn test_overlay_reads_the_base_atomic_batch() {
+        // Initialize a base map, with a pending atomic batch.
+        let base: MemoryMap<usize, String> = [(0, "0".to_string())].into_iter().collect();
+        base.start_atomic();
+        base.insert(1, "1".to_string()).unwrap();
+        base.remove(&0).unwrap();
+
+        // Initialize an overlay over the base map.
+        let overlay = OverlayMap::new(base.clone());
+        // Ensure the overlay reads the pending writes of the base map.
+        assert!(!overlay.contains_key(&0).unwrap());
+        assert_eq!(overlay.get(&1).unwrap().unwrap().into_owned(), "1");
+
+        // Ensure an atomic batch on the overlay can be rewound, without affecting the base map.
+        overlay.start_atomic();
+        overlay.insert(1, "updated".to_string()).unwrap();
+        assert_eq!(overlay.get_speculative(&1).unwrap().unwrap().into_owned(), "updated");
+        overlay.abort_atomic();
+        assert_eq!(overlay.get_speculative(&1).unwrap().unwrap().into_owned(), "1");
+        assert!(base.is_atomic_in_progress());
+        assert_eq!(base.get_batched(&1), Some(Some("1".to_string())));
+    }
+}
@@ -0,0 +1,140 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::store::{
+    helpers::rocksdb::{BlockDB, ProgramDB, TransactionDB, TransitionDB},
+    BlockStore,
+    ConsensusStorage,
+    ProgramStore,
+};
+use console::network::prelude::*;
+
+/// A RocksDB consensus storage.
+#[derive(Clone)]
+pub struct ConsensusDB<N: Network> {
+    /// The program store.
+    program_store: ProgramStore<N, ProgramDB<N>>,
+    /// The block store.
+    block_store: BlockStore<N, BlockDB<N>>,
+}
+
+#[rustfmt::skip]
+impl<N: Network> ConsensusStorage<N> for ConsensusDB<N> {
+    type ProgramStorage = ProgramDB<N>;
+    type BlockStorage = BlockDB<N>;
+    type TransactionStorage = TransactionDB<N>;
+    type TransitionStorage = TransitionDB<N>;
+
+    /// Initializes the consensus storage.
+    fn open(dev: Option<u16>) -> Result<Self> {
+        // Initialize the program store.
+        let program_store = ProgramStore::<N, ProgramDB<N>>::open(dev)?;
+        // Initialize the block store.
+        let block_store = BlockStore::<N, BlockDB<N>>::open(dev)?;
+        // Return the consensus storage.
+        Ok(Self {
+            program_store,
+            block_store,
+        })
+    }
+
+    /// Returns the program store.
+    fn program_store(&self) -> &ProgramStore<N, Self::ProgramStorage> {
+        &self.program_store
+    }
+
+    /// Returns the block store.
+    fn block_store(&self) -> &BlockStore<N, Self::BlockStorage> {
+        &self.block_store
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        program::Program,
+        store::{helpers::rocksdb::sample_dev, ConsensusStore},
+        vm::test_helpers::{self, CurrentNetwork},
+        Fee,
+        Transaction,
+        VM,
+    };
+    use console::account::{Address, ViewKey};
+    use indexmap::IndexMap;
+    use snarkvm_utilities::TestRng;
+
+    #[test]
+    fn test_reopen_and_rebuild_vm() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a caller.
+        let caller_private_key = test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Initialize a VM on a new database, and add the genesis block.
+        let dev = sample_dev();
+        let vm = VM::from(ConsensusStore::<CurrentNetwork, ConsensusDB<CurrentNetwork>>::open(dev).unwrap()).unwrap();
+        vm.add_next_block(&test_helpers::sample_genesis_block(rng)).unwrap();
+
+        // Mint a second record, to pay the fee of the second deployment.
+        let inputs = [caller.to_string(), "1_000_000_000_u64".to_string()];
+        let authorization = vm.authorize(&caller_private_key, "credits.aleo", "mint", inputs, rng).unwrap();
+        let transaction = Transaction::execute_authorization(&vm, authorization, None, rng).unwrap();
+        let mint_block = test_helpers::sample_next_block(&vm, &caller_private_key, &[transaction], rng).unwrap();
+        vm.add_next_block(&mint_block).unwrap();
+
+        // Deploy a program.
+        let program = test_helpers::sample_program();
+        let transaction = test_helpers::sample_deployment_transaction(rng);
+        let block = test_helpers::sample_next_block(&vm, &caller_private_key, &[transaction], rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+
+        // Deploy a program that imports the first program, and pay the fee with the minted record.
+        let importer = Program::<CurrentNetwork>::from_str(&format!(
+            r"
+import {};
+
+program importer.aleo;
+
+function noop:
+    input r0 as u64.public;
+    output r0 as u64.public;",
+            program.id()
+        ))
+        .unwrap();
+        let deployment = vm.deploy(&importer, rng).unwrap();
+        let records = mint_block.records().collect::<IndexMap<_, _>>();
+        let credits = records.values().next().unwrap().decrypt(&caller_view_key).unwrap();
+        let cost = |fee: Option<&Fee<_>>| vm.process().read().deployment_cost(&deployment, fee);
+        let fee = test_helpers::sample_fee_for_cost(&vm, &caller_private_key, credits, cost, rng);
+        let transaction = Transaction::from_deployment(deployment, fee).unwrap();
+        let block = test_helpers::sample_next_block(&vm, &caller_private_key, &[transaction], rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+        assert!(vm.contains_program(importer.id()));
+
+        // Reopen the consensus storage, and rebuild the VM.
+        drop(vm);
+        let vm = VM::from(ConsensusStore::<CurrentNetwork, ConsensusDB<CurrentNetwork>>::open(dev).unwrap()).unwrap();
+
+        // Ensure the blocks and the programs are restored, with each program loaded after its imports.
+        assert_eq!(vm.block_store().current_block_height().unwrap(), Some(3));
+        assert_eq!(vm.block_store().get_block_hash(3).unwrap(), Some(block.hash()));
+        assert!(vm.contains_program(program.id()));
+        assert!(vm.contains_program(importer.id()));
+    }
+}
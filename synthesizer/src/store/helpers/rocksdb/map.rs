@@ -0,0 +1,601 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::RocksDB;
+use crate::store::helpers::{deserialize_key, serialize_key, Map, MapRead};
+use console::network::prelude::*;
+use indexmap::IndexMap;
+
+use core::{
+    borrow::Borrow,
+    hash::Hash,
+    ops::{Bound, RangeBounds},
+};
+use parking_lot::Mutex;
+use std::{
+    borrow::Cow,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+/// A map in a RocksDB database, whose keys are prefixed with the context of the map.
+#[derive(Clone)]
+pub struct DataMap<
+    K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> {
+    database: RocksDB,
+    context: Vec<u8>,
+    batch_in_progress: Arc<AtomicBool>,
+    atomic_batch: Arc<Mutex<IndexMap<K, Option<V>>>>,
+    checkpoints: Arc<Mutex<Vec<Vec<(K, Option<Option<V>>)>>>>,
+}
+
+impl<
+    K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> DataMap<K, V>
+{
+    /// Initializes a new map in the given database, with the given context.
+    pub(super) fn new(database: RocksDB, context: Vec<u8>) -> Self {
+        Self {
+            database,
+            context,
+            batch_in_progress: Default::default(),
+            atomic_batch: Default::default(),
+            checkpoints: Default::default(),
+        }
+    }
+
+    /// Queues the given operation in the atomic batch, and records the operation it replaces
+    /// in the latest checkpoint, so that `atomic_rewind` can restore it.
+    fn queue(&self, key: K, operation: Option<V>) {
+        let replaced = self.atomic_batch.lock().insert(key, operation);
+        if let Some(checkpoint) = self.checkpoints.lock().last_mut() {
+            checkpoint.push((key, replaced));
+        }
+    }
+
+    /// Returns the database key for the given key (or key prefix), which is prefixed with the context of the map.
+    fn create_key<Q: Serialize + ?Sized>(&self, key: &Q) -> Result<Vec<u8>> {
+        Ok([self.context.as_slice(), &serialize_key(key)?].concat())
+    }
+
+    /// Returns the value for the given database key, if it exists.
+    fn get_raw(&self, raw_key: &[u8]) -> Result<Option<V>> {
+        match self.database.get_pinned(raw_key)? {
+            Some(bytes) => Ok(Some(bincode::deserialize(&bytes)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the entries of the map, in the order of their database keys.
+    fn entries(&self) -> Vec<(K, V)> {
+        self.entries_with_prefix(&self.context)
+    }
+
+    /// Returns the entries of the map whose database key starts with the given prefix,
+    /// in the order of their database keys.
+    fn entries_with_prefix(&self, raw_prefix: &[u8]) -> Vec<(K, V)> {
+        self.entries_from(raw_prefix, raw_prefix.to_vec()).collect()
+    }
+
+    /// Returns an iterator over the entries of the map whose database key starts with the given prefix,
+    /// starting from the given database key, in the order of their database keys.
+    fn entries_from(&self, raw_start: &[u8], raw_prefix: Vec<u8>) -> impl '_ + Iterator<Item = (K, V)> {
+        self.database
+            .iterator(::rocksdb::IteratorMode::From(raw_start, ::rocksdb::Direction::Forward))
+            .map_while(|entry| match entry {
+                Ok(entry) => Some(entry),
+                Err(error) => {
+                    error!("Failed to iterate over the database: {error}");
+                    None
+                }
+            })
+            .take_while(move |(raw_key, _)| raw_key.starts_with(&raw_prefix))
+            .filter_map(|(raw_key, raw_value)| {
+                match (deserialize_key(&raw_key[self.context.len()..]), bincode::deserialize(&raw_value)) {
+                    (Ok(key), Ok(value)) => Some((key, value)),
+                    _ => {
+                        error!("Failed to deserialize an entry in the database (possible corruption)");
+                        None
+                    }
+                }
+            })
+    }
+}
+
+impl<
+    'a,
+    K: 'a + Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: 'a + Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> Map<'a, K, V> for DataMap<K, V>
+{
+    ///
+    /// Inserts the given key-value pair into the map.
+    ///
+    fn insert(&self, key: K, value: V) -> Result<()> {
+        // Determine if an atomic batch is in progress.
+        let is_batch = self.batch_in_progress.load(Ordering::SeqCst);
+
+        match is_batch {
+            // If a batch is in progress, add the key-value pair to the batch.
+            true => self.queue(key, Some(value)),
+            // Otherwise, insert the key-value pair directly into the database.
+            false => self.database.put(self.create_key(&key)?, bincode::serialize(&value)?)?,
+        }
+        Ok(())
+    }
+
+    ///
+    /// Removes the key-value pair for the given key from the map.
+    ///
+    fn remove(&self, key: &K) -> Result<()> {
+        // Determine if an atomic batch is in progress.
+        let is_batch = self.batch_in_progress.load(Ordering::SeqCst);
+
+        match is_batch {
+            // If a batch is in progress, add the key-None pair to the batch.
+            true => self.queue(*key, None),
+            // Otherwise, remove the key-value pair directly from the database.
+            false => self.database.delete(self.create_key(key)?)?,
+        }
+        Ok(())
+    }
+
+    ///
+    /// Begins an atomic operation. Any further calls to `insert` and `remove` will be queued
+    /// without an actual write taking place until `finish_atomic` is called.
+    ///
+    fn start_atomic(&self) {
+        // Set the atomic batch flag to `true`, and join the atomic batch of the database.
+        if !self.batch_in_progress.swap(true, Ordering::SeqCst) {
+            self.database.atomic_depth.fetch_add(1, Ordering::SeqCst);
+        }
+        // Ensure that the atomic batch is empty.
+        assert!(self.atomic_batch.lock().is_empty());
+        // Ensure that there are no checkpoints.
+        assert!(self.checkpoints.lock().is_empty());
+    }
+
+    ///
+    /// Checks whether an atomic operation is currently in progress. This can be done to ensure
+    /// that lower-level operations don't start and finish their individual atomic write batch
+    /// if they are already part of a larger one.
+    ///
+    fn is_atomic_in_progress(&self) -> bool {
+        self.batch_in_progress.load(Ordering::SeqCst)
+    }
+
+    ///
+    /// Saves the current list of pending operations, so that if `atomic_rewind` is called,
+    /// all operations queued after this checkpoint are discarded.
+    ///
+    fn atomic_checkpoint(&self) {
+        // Save a checkpoint, which records the operations it replaces in the atomic batch.
+        self.checkpoints.lock().push(Vec::new());
+    }
+
+    ///
+    /// Removes the latest atomic checkpoint, keeping the operations queued after it.
+    ///
+    fn clear_latest_checkpoint(&self) {
+        let mut checkpoints = self.checkpoints.lock();
+        // Move the replaced operations of the latest checkpoint into the previous checkpoint, if it exists,
+        // so that rewinding to the previous checkpoint still discards the operations queued after the latest one.
+        if let Some(replaced) = checkpoints.pop() {
+            if let Some(previous) = checkpoints.last_mut() {
+                previous.extend(replaced);
+            }
+        }
+    }
+
+    ///
+    /// Discards all pending operations queued after the latest `atomic_checkpoint`
+    /// (or after `start_atomic`, if no checkpoints have been created).
+    ///
+    fn atomic_rewind(&self) {
+        // Retrieve the latest checkpoint.
+        let checkpoint = self.checkpoints.lock().pop();
+        let mut atomic_batch = self.atomic_batch.lock();
+        match checkpoint {
+            // Restore the operations replaced after the checkpoint, in reverse order.
+            Some(replaced) => {
+                for (key, operation) in replaced.into_iter().rev() {
+                    match operation {
+                        Some(operation) => atomic_batch.insert(key, operation),
+                        None => atomic_batch.shift_remove(&key),
+                    };
+                }
+            }
+            // If there are no checkpoints, discard all the queued operations.
+            None => atomic_batch.clear(),
+        }
+    }
+
+    ///
+    /// Aborts the current atomic operation, including the operations staged by the other maps of the database.
+    ///
+    fn abort_atomic(&self) {
+        // Clear the atomic batch.
+        *self.atomic_batch.lock() = Default::default();
+        // Clear the checkpoints.
+        *self.checkpoints.lock() = Default::default();
+        // Discard the operations staged by the other maps, as the atomic batch is aborted as a whole.
+        self.database.atomic_batch.lock().clear();
+        // Set the atomic batch flag to `false`, and leave the atomic batch of the database.
+        if self.batch_in_progress.swap(false, Ordering::SeqCst) {
+            self.database.atomic_depth.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+
+    ///
+    /// Finishes an atomic operation, staging all the queued writes in the write batch of the database.
+    /// The write batch of the database is written once the last map in the atomic batch finishes it.
+    ///
+    fn finish_atomic(&self) -> Result<()> {
+        // Retrieve the atomic batch.
+        let operations = core::mem::take(&mut *self.atomic_batch.lock());
+        // Clear the checkpoints.
+        *self.checkpoints.lock() = Default::default();
+
+        // Serialize the queued operations, before staging any of them.
+        let operations = operations
+            .into_iter()
+            .map(|(key, value)| {
+                Ok((self.create_key(&key)?, value.map(|value| bincode::serialize(&value)).transpose()?))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Stage the queued operations in the write batch of the database.
+        {
+            let mut batch = self.database.atomic_batch.lock();
+            for (raw_key, raw_value) in operations {
+                match raw_value {
+                    Some(raw_value) => batch.put(raw_key, raw_value),
+                    None => batch.delete(raw_key),
+                }
+            }
+        }
+
+        // Set the atomic batch flag to `false`, and leave the atomic batch of the database.
+        if self.batch_in_progress.swap(false, Ordering::SeqCst)
+            && self.database.atomic_depth.fetch_sub(1, Ordering::SeqCst) == 1
+        {
+            // If this was the last map in the atomic batch, perform all the staged operations atomically.
+            let batch = core::mem::take(&mut *self.database.atomic_batch.lock());
+            if !batch.is_empty() {
+                self.database.write(batch)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<
+    'a,
+    K: 'a + Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: 'a + Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> MapRead<'a, K, V> for DataMap<K, V>
+{
+    type Iterator = core::iter::Map<std::vec::IntoIter<(K, V)>, fn((K, V)) -> (Cow<'a, K>, Cow<'a, V>)>;
+    type Keys = core::iter::Map<std::vec::IntoIter<(K, V)>, fn((K, V)) -> Cow<'a, K>>;
+    type Values = core::iter::Map<std::vec::IntoIter<(K, V)>, fn((K, V)) -> Cow<'a, V>>;
+
+    ///
+    /// Returns `true` if the given key exists in the map.
+    ///
+    fn contains_key<Q>(&self, key: &Q) -> Result<bool>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        Ok(self.database.get_pinned(self.create_key(key)?)?.is_some())
+    }
+
+    ///
+    /// Returns the value for the given key from the map, if it exists.
+    ///
+    fn get<Q>(&'a self, key: &Q) -> Result<Option<Cow<'a, V>>>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        Ok(self.get_raw(&self.create_key(key)?)?.map(Cow::Owned))
+    }
+
+    ///
+    /// Returns the current value for the given key if it is scheduled
+    /// to be inserted as part of an atomic batch.
+    ///
+    /// If the key does not exist, returns `None`.
+    /// If the key is removed in the batch, returns `Some(None)`.
+    /// If the key is inserted in the batch, returns `Some(Some(value))`.
+    ///
+    fn get_batched<Q>(&self, key: &Q) -> Option<Option<V>>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        // Return early if there is no atomic batch in progress.
+        if !self.batch_in_progress.load(Ordering::SeqCst) {
+            return None;
+        }
+        // Return the queued operation for the key, if it exists.
+        self.atomic_batch.lock().get(key).cloned()
+    }
+
+    ///
+    /// Returns an iterator visiting each key-value pair in the map.
+    ///
+    fn iter(&'a self) -> Self::Iterator {
+        self.entries().into_iter().map(|(k, v)| (Cow::Owned(k), Cow::Owned(v)))
+    }
+
+    ///
+    /// Returns an iterator over each key in the map.
+    ///
+    fn keys(&'a self) -> Self::Keys {
+        self.entries().into_iter().map(|(k, _)| Cow::Owned(k))
+    }
+
+    ///
+    /// Returns an iterator over each value in the map.
+    ///
+    fn values(&'a self) -> Self::Values {
+        self.entries().into_iter().map(|(_, v)| Cow::Owned(v))
+    }
+
+    ///
+    /// Returns an iterator visiting each key-value pair in the map whose key is in the given range,
+    /// in ascending order of the keys.
+    ///
+    /// Note: The database keys are in ascending order of the keys, for keys whose big-endian serialization
+    /// preserves their order, such as unsigned integers. This seeks to the start of the range,
+    /// and stops at the first key past the range.
+    ///
+    fn iter_range<R: RangeBounds<K>>(&'a self, range: R) -> Self::Iterator
+    where
+        K: Ord,
+    {
+        // Retrieve the database key to seek to.
+        let raw_start = match range.start_bound() {
+            Bound::Included(start) | Bound::Excluded(start) => self.create_key(start),
+            Bound::Unbounded => Ok(self.context.clone()),
+        };
+        let entries = match raw_start {
+            // Retrieve the entries from the start of the range, until the first key past the range.
+            Ok(raw_start) => self
+                .entries_from(&raw_start, self.context.clone())
+                .skip_while(|(k, _)| matches!(range.start_bound(), Bound::Excluded(start) if k == start))
+                .take_while(|(k, _)| range.contains(k))
+                .collect(),
+            Err(error) => {
+                error!("Failed to serialize the start of the range: {error}");
+                Vec::new()
+            }
+        };
+        entries.into_iter().map(|(k, v)| (Cow::Owned(k), Cow::Owned(v)))
+    }
+
+    ///
+    /// Returns an iterator visiting each key-value pair in the map whose serialized key starts with
+    /// the serialized prefix, in ascending order of the serialized keys.
+    ///
+    fn iter_prefix<P: Serialize + ?Sized>(&'a self, prefix: &P) -> Result<Self::Iterator> {
+        // Seek to the prefix, as the entries are stored in the order of their database keys.
+        let entries = self.entries_with_prefix(&self.create_key(prefix)?);
+        Ok(entries.into_iter().map(|(k, v)| (Cow::Owned(k), Cow::Owned(v))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::helpers::rocksdb::{sample_dev, MapID};
+    use console::network::{Network, Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    /// Opens a new map in a new database.
+    fn open_map() -> DataMap<usize, String> {
+        RocksDB::open_map(CurrentNetwork::ID, sample_dev(), MapID::BlockID).unwrap()
+    }
+
+    #[test]
+    fn test_insert_and_get_speculative() {
+        // Initialize a map.
+        let map = open_map();
+
+        // Sanity check.
+        assert!(map.iter().next().is_none());
+
+        // Start an atomic write batch.
+        map.start_atomic();
+
+        // Insert an item into the map.
+        map.insert(0, "0".to_string()).unwrap();
+
+        // Check that the item is not yet in the map.
+        assert!(map.get(&0).unwrap().is_none());
+        // Check that the item is in the batch.
+        assert_eq!(map.get_batched(&0), Some(Some("0".to_string())));
+        // Check that the item can be speculatively retrieved.
+        assert_eq!(map.get_speculative(&0).unwrap(), Some(Cow::Owned("0".to_string())));
+
+        // Finish the current atomic write batch.
+        map.finish_atomic().unwrap();
+
+        // Check that the item is present in the map now.
+        assert_eq!(map.get(&0).unwrap(), Some(Cow::Owned("0".to_string())));
+        // Check that the item is not in the batch.
+        assert_eq!(map.get_batched(&0), None);
+        // Check that the item can be speculatively retrieved.
+        assert_eq!(map.get_speculative(&0).unwrap(), Some(Cow::Owned("0".to_string())));
+    }
+
+    #[test]
+    fn test_atomic_writes_are_batched() {
+        // The number of items that will be inserted into the map.
+        const NUM_ITEMS: usize = 10;
+
+        // Initialize a map.
+        let map = open_map();
+
+        // Start an atomic write batch.
+        map.start_atomic();
+
+        // Queue (since a batch is in progress) NUM_ITEMS insertions.
+        for i in 0..NUM_ITEMS {
+            map.insert(i, i.to_string()).unwrap();
+            // Ensure that the item is queued for insertion.
+            assert_eq!(map.get_batched(&i), Some(Some(i.to_string())));
+        }
+
+        // The map should still contain no items.
+        assert!(map.iter().next().is_none());
+
+        // Finish the current atomic write batch.
+        map.finish_atomic().unwrap();
+
+        // Check that the items are present in the map now.
+        for i in 0..NUM_ITEMS {
+            assert_eq!(map.get(&i).unwrap(), Some(Cow::Borrowed(&i.to_string())));
+        }
+        assert_eq!(map.iter().count(), NUM_ITEMS);
+
+        // Start an atomic write batch.
+        map.start_atomic();
+
+        // Queue (since a batch is in progress) NUM_ITEMS removals.
+        for i in 0..NUM_ITEMS {
+            map.remove(&i).unwrap();
+            // Ensure that the item is queued for removal.
+            assert_eq!(map.get_batched(&i), Some(None));
+        }
+
+        // The map should still contain all the items.
+        assert_eq!(map.iter().count(), NUM_ITEMS);
+
+        // Finish the current atomic write batch.
+        map.finish_atomic().unwrap();
+
+        // Check that the map is empty now.
+        assert!(map.iter().next().is_none());
+    }
+
+    #[test]
+    fn test_atomic_writes_can_be_aborted_and_rewound() {
+        // Initialize a map.
+        let map = open_map();
+
+        // Queue an insertion, and abort it.
+        map.start_atomic();
+        map.insert(0, "0".to_string()).unwrap();
+        map.abort_atomic();
+        assert!(map.iter().next().is_none());
+
+        // Start an atomic write batch.
+        map.start_atomic();
+
+        // Queue an insertion, and save a checkpoint.
+        map.insert(0, "0".to_string()).unwrap();
+        map.atomic_checkpoint();
+
+        // Queue an insertion after the checkpoint, and rewind it.
+        map.insert(1, "1".to_string()).unwrap();
+        map.atomic_rewind();
+
+        // Finish the atomic write batch.
+        map.finish_atomic().unwrap();
+
+        // Ensure the map reflects the operations that were not rewound.
+        assert_eq!(map.get(&0).unwrap(), Some(Cow::Borrowed(&"0".to_string())));
+        assert_eq!(map.get(&1).unwrap(), None);
+    }
+
+    #[test]
+    fn test_maps_are_persistent_and_isolated() {
+        // Open a new database.
+        let dev = sample_dev();
+        let map = RocksDB::open_map::<usize, String>(CurrentNetwork::ID, dev, MapID::BlockID).unwrap();
+        map.insert(0, "0".to_string()).unwrap();
+
+        // Ensure a map with another ID in the same database does not see the item.
+        let other_map = RocksDB::open_map::<usize, String>(CurrentNetwork::ID, dev, MapID::BlockReverseID).unwrap();
+        assert!(other_map.iter().next().is_none());
+
+        // Ensure the item is found when reopening the map.
+        drop(map);
+        let map = RocksDB::open_map::<usize, String>(CurrentNetwork::ID, dev, MapID::BlockID).unwrap();
+        assert_eq!(map.get(&0).unwrap(), Some(Cow::Borrowed(&"0".to_string())));
+    }
+
+    #[test]
+    fn test_atomic_writes_are_shared_across_maps() {
+        // Open two maps in the same database.
+        let dev = sample_dev();
+        let map = RocksDB::open_map::<usize, String>(CurrentNetwork::ID, dev, MapID::BlockID).unwrap();
+        let other_map = RocksDB::open_map::<usize, String>(CurrentNetwork::ID, dev, MapID::BlockReverseID).unwrap();
+
+        // Start an atomic write batch on both maps, and queue an insertion in each.
+        map.start_atomic();
+        other_map.start_atomic();
+        map.insert(0, "0".to_string()).unwrap();
+        other_map.insert(1, "1".to_string()).unwrap();
+
+        // Ensure the writes of the first map are not performed until the other map finishes its batch.
+        map.finish_atomic().unwrap();
+        assert!(map.get(&0).unwrap().is_none());
+        other_map.finish_atomic().unwrap();
+        assert_eq!(map.get(&0).unwrap(), Some(Cow::Borrowed(&"0".to_string())));
+        assert_eq!(other_map.get(&1).unwrap(), Some(Cow::Borrowed(&"1".to_string())));
+
+        // Ensure the writes of a finished map are discarded if the other map aborts its batch.
+        map.start_atomic();
+        other_map.start_atomic();
+        map.insert(2, "2".to_string()).unwrap();
+        map.finish_atomic().unwrap();
+        other_map.abort_atomic();
+        assert!(map.get(&2).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_iter_range_and_prefix() {
+        // Initialize a map, with keys inserted out of order.
+        let map = RocksDB::open_map::<(u32, u32), String>(CurrentNetwork::ID, sample_dev(), MapID::BlockID).unwrap();
+        for (a, b) in [(2, 1), (1, 2), (2, 0), (3, 0), (1, 1), (256, 0)] {
+            map.insert((a, b), format!("{a}.{b}")).unwrap();
+        }
+
+        // Ensure the range is iterated in ascending order.
+        let keys = map.iter_range((1, 2)..(3, 0)).map(|(k, _)| *k).collect::<Vec<_>>();
+        assert_eq!(keys, vec![(1, 2), (2, 0), (2, 1)]);
+        let keys = map.iter_range((2, 1)..).map(|(k, _)| *k).collect::<Vec<_>>();
+        assert_eq!(keys, vec![(2, 1), (3, 0), (256, 0)]);
+        let keys =
+            map.iter_range((Bound::Excluded((1, 1)), Bound::Included((2, 0)))).map(|(k, _)| *k).collect::<Vec<_>>();
+        assert_eq!(keys, vec![(1, 2), (2, 0)]);
+        assert!(map.iter_range((3, 1)..(256, 0)).next().is_none());
+
+        // Ensure the prefix is iterated in ascending order.
+        let entries = map.iter_prefix(&2u32).unwrap().map(|(k, v)| (*k, v.into_owned())).collect::<Vec<_>>();
+        assert_eq!(entries, vec![((2, 0), "2.0".to_string()), ((2, 1), "2.1".to_string())]);
+        assert!(map.iter_prefix(&4u32).unwrap().next().is_none());
+    }
+}
@@ -0,0 +1,223 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod map;
+pub use map::*;
+
+mod block;
+pub use block::*;
+
+mod consensus;
+pub use consensus::*;
+
+mod program;
+pub use program::*;
+
+mod transaction;
+pub use transaction::*;
+
+mod transition;
+pub use transition::*;
+
+use console::network::prelude::*;
+
+use core::hash::Hash;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::{atomic::AtomicUsize, Arc},
+};
+
+/// The open databases, keyed by their storage directory.
+/// Note: RocksDB does not allow a database to be opened more than once per process.
+static DATABASES: Lazy<Mutex<HashMap<PathBuf, RocksDB>>> = Lazy::new(Default::default);
+
+/// The ID of each map in the database. The ID of a map must never change, as it prefixes the keys of the map.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum MapID {
+    BlockStateRoot = 0,
+    BlockReverseStateRoot = 1,
+    BlockID = 2,
+    BlockReverseID = 3,
+    BlockHeader = 4,
+    BlockTransactions = 5,
+    BlockReverseTransactions = 6,
+    BlockCoinbaseSolution = 7,
+    BlockCoinbasePuzzleCommitment = 8,
+    BlockSignature = 9,
+
+    DeploymentID = 100,
+    DeploymentEdition = 101,
+    DeploymentReverseID = 102,
+    DeploymentProgram = 103,
+    DeploymentVerifyingKey = 104,
+    DeploymentCertificate = 105,
+    DeploymentFee = 106,
+    DeploymentOwner = 107,
+
+    ExecutionID = 200,
+    ExecutionReverseID = 201,
+    ExecutionInclusion = 202,
+    ExecutionFee = 203,
+    ExecutionRejected = 204,
+
+    TransactionID = 300,
+    TransactionStatus = 301,
+
+    TransitionLocator = 400,
+    TransitionFinalize = 401,
+    TransitionProof = 402,
+    TransitionTPK = 403,
+    TransitionReverseTPK = 404,
+    TransitionTCM = 405,
+    TransitionReverseTCM = 406,
+    TransitionFee = 407,
+
+    InputID = 500,
+    InputReverseID = 501,
+    InputConstant = 502,
+    InputPublic = 503,
+    InputPrivate = 504,
+    InputRecord = 505,
+    InputRecordTag = 506,
+    InputExternalRecord = 507,
+
+    OutputID = 600,
+    OutputReverseID = 601,
+    OutputConstant = 602,
+    OutputPublic = 603,
+    OutputPrivate = 604,
+    OutputRecord = 605,
+    OutputRecordNonce = 606,
+    OutputExternalRecord = 607,
+
+    ProgramID = 700,
+    ProgramMappingID = 701,
+    ProgramKeyValueID = 702,
+    ProgramKey = 703,
+    ProgramValue = 704,
+    ProgramFinalizeLeaf = 705,
+    ProgramFinalizeNode = 706,
+}
+
+/// A RocksDB database, shared by all of the maps of a ledger.
+///
+/// The atomic batches of all of the maps are written to the database in a single write batch,
+/// once the last map in an atomic batch finishes it.
+#[derive(Clone)]
+pub struct RocksDB {
+    /// The database.
+    database: Arc<::rocksdb::DB>,
+    /// The number of maps with an atomic batch in progress.
+    atomic_depth: Arc<AtomicUsize>,
+    /// The write batch of the finished atomic batches, which is written once the atomic depth reaches zero.
+    atomic_batch: Arc<Mutex<::rocksdb::WriteBatch>>,
+    /// The network ID.
+    network_id: u16,
+    /// The optional development ID.
+    dev: Option<u16>,
+}
+
+impl RocksDB {
+    /// Opens the database for the given network ID and optional development ID.
+    pub fn open(network_id: u16, dev: Option<u16>) -> Result<Self> {
+        // Retrieve the storage directory.
+        let directory = storage_dir(network_id, dev);
+
+        // Retrieve the database, or open it if it is not open yet.
+        match DATABASES.lock().entry(directory.clone()) {
+            std::collections::hash_map::Entry::Occupied(entry) => Ok(entry.get().clone()),
+            std::collections::hash_map::Entry::Vacant(entry) => {
+                // Initialize the database options.
+                let mut options = ::rocksdb::Options::default();
+                options.create_if_missing(true);
+                options.set_compression_type(::rocksdb::DBCompressionType::Lz4);
+                // Open the database.
+                let database = ::rocksdb::DB::open(&options, &directory)
+                    .map_err(|error| anyhow!("Failed to open the database at {}: {error}", directory.display()))?;
+                let database = Self {
+                    database: Arc::new(database),
+                    atomic_depth: Default::default(),
+                    atomic_batch: Default::default(),
+                    network_id,
+                    dev,
+                };
+                Ok(entry.insert(database).clone())
+            }
+        }
+    }
+
+    /// Opens the map with the given ID, for the given network ID and optional development ID.
+    pub fn open_map<
+        K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+        V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    >(
+        network_id: u16,
+        dev: Option<u16>,
+        map_id: MapID,
+    ) -> Result<DataMap<K, V>> {
+        // Open the database.
+        let database = Self::open(network_id, dev)?;
+        // Prefix the keys of the map with the network ID and map ID.
+        let context = [network_id.to_le_bytes(), (map_id as u16).to_le_bytes()].concat();
+        // Return the map.
+        Ok(DataMap::new(database, context))
+    }
+
+    /// Returns the optional development ID.
+    pub const fn dev(&self) -> Option<u16> {
+        self.dev
+    }
+
+    /// Returns the network ID.
+    pub const fn network_id(&self) -> u16 {
+        self.network_id
+    }
+}
+
+impl Deref for RocksDB {
+    type Target = ::rocksdb::DB;
+
+    fn deref(&self) -> &Self::Target {
+        &self.database
+    }
+}
+
+/// Returns the storage directory for the given network ID and optional development ID.
+#[cfg(not(test))]
+fn storage_dir(network_id: u16, dev: Option<u16>) -> PathBuf {
+    aleo_std::aleo_ledger_dir(network_id, dev)
+}
+
+/// Returns the storage directory for the given network ID and optional development ID.
+/// Note: Tests use a temporary directory, so they do not touch the ledger of the node.
+#[cfg(test)]
+fn storage_dir(network_id: u16, dev: Option<u16>) -> PathBuf {
+    let dev = dev.map_or_else(|| "prod".to_string(), |dev| dev.to_string());
+    std::env::temp_dir().join(format!("snarkvm-test-ledger-{network_id}-{dev}-{}", std::process::id()))
+}
+
+/// Returns a new development ID, so each test opens its own database.
+#[cfg(test)]
+pub(crate) fn sample_dev() -> Option<u16> {
+    use core::sync::atomic::{AtomicU16, Ordering};
+
+    static DEV: AtomicU16 = AtomicU16::new(0);
+    Some(DEV.fetch_add(1, Ordering::SeqCst))
+}
@@ -0,0 +1,243 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::ProgramStorage;
+use crate::{
+    cow_to_cloned,
+    cow_to_copied,
+    store::helpers::{Map, MapRead},
+};
+use console::{
+    network::prelude::*,
+    program::{FinalizePath, Identifier, Plaintext, ProgramID, Value, FINALIZE_DEPTH},
+    types::{Field, U64},
+};
+
+/// A proof that a key is, or is not, in a mapping of the finalize state.
+///
+/// The finalize tree is a sparse Merkle tree of depth `FINALIZE_DEPTH`, where each mapping entry is in the leaf
+/// at the index given by the lowest `FINALIZE_DEPTH` bits of its key ID. A leaf commits to the `(key ID, value ID)`
+/// pairs of its entries, sorted by key ID. A key is proven to be in (or not in) a mapping by the entries of its leaf,
+/// and the path of its leaf. If there are no entries, the finalize root is zero.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MappingProof<N: Network> {
+    /// The `(key ID, value ID)` pairs in the leaf of the key.
+    entries: Vec<(Field<N>, Field<N>)>,
+    /// The path of the leaf of the key.
+    path: FinalizePath<N>,
+}
+
+impl<N: Network> MappingProof<N> {
+    /// Returns the `(key ID, value ID)` pairs in the leaf of the key.
+    pub fn entries(&self) -> &[(Field<N>, Field<N>)] {
+        &self.entries
+    }
+
+    /// Returns the path of the leaf of the key.
+    pub const fn path(&self) -> &FinalizePath<N> {
+        &self.path
+    }
+
+    /// Returns `true` if the proof shows that the given key has the given value in the given finalize root,
+    /// or that the key is not in the mapping if the value is `None`.
+    pub fn verify(
+        &self,
+        finalize_root: &Field<N>,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: &Plaintext<N>,
+        value: Option<&Value<N>>,
+    ) -> Result<bool> {
+        // Compute the key ID.
+        let key_id = to_key_id(&to_mapping_id(program_id, mapping_name)?, key)?;
+        // Ensure the path is for the leaf of the key ID.
+        if *self.path.leaf_index() != u64::from(to_leaf_index(&key_id)) {
+            return Ok(false);
+        }
+        // Ensure the leaf has the value ID of the given value, or no value ID for the key ID if the value is `None`.
+        let value_id = value.map(|value| to_value_id(&key_id, value)).transpose()?;
+        if self.entries.iter().find(|(k, _)| *k == key_id).map(|(_, v)| *v) != value_id {
+            return Ok(false);
+        }
+        match finalize_root.is_zero() {
+            // If the finalize root is zero, ensure there are no entries.
+            true => Ok(self.entries.is_empty()),
+            // Otherwise, ensure the leaf is in the finalize tree.
+            false => Ok(N::verify_merkle_path_bhp(&self.path, finalize_root, &to_leaf(&self.entries))),
+        }
+    }
+}
+
+/// Returns the mapping ID, as `Hash( program ID || mapping name )`.
+pub(super) fn to_mapping_id<N: Network>(program_id: &ProgramID<N>, mapping_name: &Identifier<N>) -> Result<Field<N>> {
+    N::hash_bhp1024(&(program_id, mapping_name).to_bits_le())
+}
+
+/// Returns the key ID, as `Hash( mapping ID || Hash(key) )`.
+pub(super) fn to_key_id<N: Network>(mapping_id: &Field<N>, key: &Plaintext<N>) -> Result<Field<N>> {
+    N::hash_bhp1024(&(*mapping_id, N::hash_bhp1024(&key.to_bits_le())?).to_bits_le())
+}
+
+/// Returns the value ID, as `Hash( key ID || Hash(value) )`.
+pub(super) fn to_value_id<N: Network>(key_id: &Field<N>, value: &Value<N>) -> Result<Field<N>> {
+    N::hash_bhp1024(&(*key_id, N::hash_bhp1024(&value.to_bits_le())?).to_bits_le())
+}
+
+/// Returns the index of the leaf for the given key ID, as the lowest `FINALIZE_DEPTH` bits of the key ID.
+fn to_leaf_index<N: Network>(key_id: &Field<N>) -> u32 {
+    key_id.to_bits_le().iter().take(FINALIZE_DEPTH as usize).rev().fold(0, |index, bit| (index << 1) | u32::from(*bit))
+}
+
+/// Returns the leaf of the finalize tree for the given `(key ID, value ID)` pairs.
+fn to_leaf<N: Network>(entries: &[(Field<N>, Field<N>)]) -> Vec<bool> {
+    entries.iter().flat_map(|(key_id, value_id)| (*key_id, *value_id).to_bits_le()).collect()
+}
+
+/// Returns the hash of the given leaf, as in a BHP Merkle tree of the network.
+fn hash_leaf<N: Network>(leaf: &[bool]) -> Result<Field<N>> {
+    // Prepend the leaf with a `false` bit.
+    let mut input = vec![false];
+    input.extend(leaf);
+    // Hash the input.
+    N::hash_bhp1024(&input)
+}
+
+/// Returns the hash of the given child nodes, as in a BHP Merkle tree of the network.
+fn hash_children<N: Network>(left: &Field<N>, right: &Field<N>) -> Result<Field<N>> {
+    // Prepend the nodes with a `true` bit.
+    let mut input = vec![true];
+    input.extend(left.to_bits_le());
+    input.extend(right.to_bits_le());
+    // Hash the input.
+    N::hash_bhp512(&input)
+}
+
+/// Returns the hash of an empty node at each level of the finalize tree, from the leaves to the root.
+fn empty_hashes<N: Network>() -> Result<Vec<Field<N>>> {
+    let mut hashes = vec![hash_leaf::<N>(&[])?];
+    for level in 0..FINALIZE_DEPTH as usize {
+        hashes.push(hash_children(&hashes[level], &hashes[level])?);
+    }
+    Ok(hashes)
+}
+
+/// Returns the hash of the node at the given level and index of the finalize tree.
+fn get_node<N: Network, P: ProgramStorage<N>>(
+    storage: &P,
+    empty_hashes: &[Field<N>],
+    level: u8,
+    index: u32,
+) -> Result<Field<N>> {
+    match storage.finalize_node_map().get_speculative(&(level, index))? {
+        Some(hash) => Ok(cow_to_copied!(hash)),
+        None => Ok(empty_hashes[level as usize]),
+    }
+}
+
+/// Returns the finalize root of the given storage.
+/// Note: The finalize root is zero if there are no entries.
+pub(super) fn to_finalize_root<N: Network, P: ProgramStorage<N>>(storage: &P) -> Result<Field<N>> {
+    match storage.finalize_node_map().get_speculative(&(FINALIZE_DEPTH, 0))? {
+        Some(root) => Ok(cow_to_copied!(root)),
+        None => Ok(Field::zero()),
+    }
+}
+
+/// Updates the finalize tree of the given storage with the given `(key ID, value ID)` pairs,
+/// where a value ID of `None` removes the key ID. Only the path of the leaf of each key ID is updated.
+/// This method assumes it is called within an atomic batch of the given storage.
+pub(super) fn update_finalize_tree<N: Network, P: ProgramStorage<N>>(
+    storage: &P,
+    changes: &[(Field<N>, Option<Field<N>>)],
+) -> Result<()> {
+    // Return early if there are no changes.
+    if changes.is_empty() {
+        return Ok(());
+    }
+    // Compute the empty hashes.
+    let empty_hashes = empty_hashes::<N>()?;
+
+    for (key_id, value_id) in changes {
+        // Retrieve the entries of the leaf.
+        let leaf_index = to_leaf_index(key_id);
+        let mut entries = match storage.finalize_leaf_map().get_speculative(&leaf_index)? {
+            Some(entries) => cow_to_cloned!(entries),
+            None => Vec::new(),
+        };
+        // Update the entry of the key ID, keeping the entries sorted by key ID.
+        match (entries.binary_search_by(|(k, _)| k.cmp(key_id)), value_id) {
+            (Ok(position), Some(value_id)) => entries[position].1 = *value_id,
+            (Ok(position), None) => {
+                entries.remove(position);
+            }
+            (Err(position), Some(value_id)) => entries.insert(position, (*key_id, *value_id)),
+            (Err(_), None) => continue,
+        }
+
+        // Update the leaf, and compute its hash.
+        let mut hash = match entries.is_empty() {
+            true => {
+                storage.finalize_leaf_map().remove(&leaf_index)?;
+                empty_hashes[0]
+            }
+            false => {
+                let hash = hash_leaf::<N>(&to_leaf(&entries))?;
+                storage.finalize_leaf_map().insert(leaf_index, entries)?;
+                hash
+            }
+        };
+        // Update the nodes along the path of the leaf, where empty nodes are not stored.
+        let mut index = leaf_index;
+        for level in 0..=FINALIZE_DEPTH {
+            match hash == empty_hashes[level as usize] {
+                true => storage.finalize_node_map().remove(&(level, index))?,
+                false => storage.finalize_node_map().insert((level, index), hash)?,
+            }
+            // Compute the hash of the parent node.
+            if level < FINALIZE_DEPTH {
+                let sibling = get_node(storage, &empty_hashes, level, index ^ 1)?;
+                hash = match index % 2 == 0 {
+                    true => hash_children(&hash, &sibling)?,
+                    false => hash_children(&sibling, &hash)?,
+                };
+                index /= 2;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns the mapping proof for the given key ID, in the finalize tree of the given storage.
+pub(super) fn to_mapping_proof<N: Network, P: ProgramStorage<N>>(
+    storage: &P,
+    key_id: &Field<N>,
+) -> Result<MappingProof<N>> {
+    // Compute the empty hashes.
+    let empty_hashes = empty_hashes::<N>()?;
+
+    // Retrieve the entries of the leaf.
+    let leaf_index = to_leaf_index(key_id);
+    let entries = match storage.finalize_leaf_map().get_speculative(&leaf_index)? {
+        Some(entries) => cow_to_cloned!(entries),
+        None => Vec::new(),
+    };
+    // Retrieve the siblings along the path of the leaf.
+    let siblings = (0..FINALIZE_DEPTH)
+        .map(|level| get_node(storage, &empty_hashes, level, (leaf_index >> level) ^ 1))
+        .collect::<Result<Vec<_>>>()?;
+    // Return the mapping proof.
+    Ok(MappingProof { entries, path: FinalizePath::try_from((U64::new(u64::from(leaf_index)), siblings))? })
+}
@@ -14,6 +14,12 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod finalize_tree;
+pub use finalize_tree::*;
+
+mod overlay;
+pub use overlay::*;
+
 use crate::{
     atomic_write_batch,
     cow_to_cloned,
@@ -49,10 +55,14 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
     type MappingIDMap: for<'a> Map<'a, (ProgramID<N>, Identifier<N>), Field<N>>;
     /// The mapping of `mapping ID` to `[(key ID, value ID)]`.
     type KeyValueIDMap: for<'a> Map<'a, Field<N>, IndexMap<Field<N>, Field<N>>>;
-    /// The mapping of `key ID` to `key`.
-    type KeyMap: for<'a> Map<'a, Field<N>, Plaintext<N>>;
-    /// The mapping of `key ID` to `value`.
-    type ValueMap: for<'a> Map<'a, Field<N>, Value<N>>;
+    /// The mapping of `(mapping ID, key ID)` to `key`.
+    type KeyMap: for<'a> Map<'a, (Field<N>, Field<N>), Plaintext<N>>;
+    /// The mapping of `(mapping ID, key ID)` to `value`.
+    type ValueMap: for<'a> Map<'a, (Field<N>, Field<N>), Value<N>>;
+    /// The mapping of `leaf index` to `[(key ID, value ID)]` in the finalize tree.
+    type FinalizeLeafMap: for<'a> Map<'a, u32, Vec<(Field<N>, Field<N>)>>;
+    /// The mapping of `(level, index)` to `hash` for each non-empty node in the finalize tree.
+    type FinalizeNodeMap: for<'a> Map<'a, (u8, u32), Field<N>>;
 
     /// Initializes the program state storage.
     fn open(dev: Option<u16>) -> Result<Self>;
@@ -67,6 +77,10 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
     fn key_map(&self) -> &Self::KeyMap;
     /// Returns the value map.
     fn value_map(&self) -> &Self::ValueMap;
+    /// Returns the finalize leaf map.
+    fn finalize_leaf_map(&self) -> &Self::FinalizeLeafMap;
+    /// Returns the finalize node map.
+    fn finalize_node_map(&self) -> &Self::FinalizeNodeMap;
 
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16>;
@@ -78,6 +92,8 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         self.key_value_id_map().start_atomic();
         self.key_map().start_atomic();
         self.value_map().start_atomic();
+        self.finalize_leaf_map().start_atomic();
+        self.finalize_node_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -87,6 +103,41 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
             || self.key_value_id_map().is_atomic_in_progress()
             || self.key_map().is_atomic_in_progress()
             || self.value_map().is_atomic_in_progress()
+            || self.finalize_leaf_map().is_atomic_in_progress()
+            || self.finalize_node_map().is_atomic_in_progress()
+    }
+
+    /// Checkpoints the atomic batch.
+    fn atomic_checkpoint(&self) {
+        self.program_id_map().atomic_checkpoint();
+        self.mapping_id_map().atomic_checkpoint();
+        self.key_value_id_map().atomic_checkpoint();
+        self.key_map().atomic_checkpoint();
+        self.value_map().atomic_checkpoint();
+        self.finalize_leaf_map().atomic_checkpoint();
+        self.finalize_node_map().atomic_checkpoint();
+    }
+
+    /// Clears the latest atomic batch checkpoint.
+    fn clear_latest_checkpoint(&self) {
+        self.program_id_map().clear_latest_checkpoint();
+        self.mapping_id_map().clear_latest_checkpoint();
+        self.key_value_id_map().clear_latest_checkpoint();
+        self.key_map().clear_latest_checkpoint();
+        self.value_map().clear_latest_checkpoint();
+        self.finalize_leaf_map().clear_latest_checkpoint();
+        self.finalize_node_map().clear_latest_checkpoint();
+    }
+
+    /// Rewinds the atomic batch to the latest checkpoint.
+    fn atomic_rewind(&self) {
+        self.program_id_map().atomic_rewind();
+        self.mapping_id_map().atomic_rewind();
+        self.key_value_id_map().atomic_rewind();
+        self.key_map().atomic_rewind();
+        self.value_map().atomic_rewind();
+        self.finalize_leaf_map().atomic_rewind();
+        self.finalize_node_map().atomic_rewind();
     }
 
     /// Aborts an atomic batch write operation.
@@ -96,6 +147,8 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         self.key_value_id_map().abort_atomic();
         self.key_map().abort_atomic();
         self.value_map().abort_atomic();
+        self.finalize_leaf_map().abort_atomic();
+        self.finalize_node_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
@@ -104,20 +157,22 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         self.mapping_id_map().finish_atomic()?;
         self.key_value_id_map().finish_atomic()?;
         self.key_map().finish_atomic()?;
-        self.value_map().finish_atomic()
+        self.value_map().finish_atomic()?;
+        self.finalize_leaf_map().finish_atomic()?;
+        self.finalize_node_map().finish_atomic()
     }
 
     /// Initializes the given `program ID` and `mapping name` in storage.
     fn initialize_mapping(&self, program_id: &ProgramID<N>, mapping_name: &Identifier<N>) -> Result<()> {
         // Ensure the mapping name does not already exist.
-        if self.mapping_id_map().contains_key(&(*program_id, *mapping_name))? {
+        if self.mapping_id_map().get_speculative(&(*program_id, *mapping_name))?.is_some() {
             bail!("Illegal operation: mapping '{mapping_name}' already exists in storage - cannot initialize again.")
         }
 
         // Compute the mapping ID.
         let mapping_id = N::hash_bhp1024(&(program_id, mapping_name).to_bits_le())?;
         // Ensure the mapping ID does not already exist.
-        if self.key_value_id_map().contains_key(&mapping_id)? {
+        if self.key_value_id_map().get_speculative(&mapping_id)?.is_some() {
             bail!("Illegal operation: mapping ID '{mapping_id}' already exists in storage - cannot initialize again.")
         }
 
@@ -165,7 +220,7 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         let value_id = N::hash_bhp1024(&(key_id, N::hash_bhp1024(&value.to_bits_le())?).to_bits_le())?;
 
         // Ensure the key ID does not already exist.
-        if self.key_map().contains_key(&key_id)? {
+        if self.key_map().get_speculative(&(mapping_id, key_id))?.is_some() {
             bail!("Illegal operation: key ID '{key_id}' already exists in storage - cannot insert again.")
         }
         // Retrieve the key-value IDs for the mapping ID.
@@ -184,9 +239,11 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
             // Update the key-value ID map with the new key-value ID.
             self.key_value_id_map().insert(mapping_id, key_value_ids)?;
             // Insert the key.
-            self.key_map().insert(key_id, key)?;
+            self.key_map().insert((mapping_id, key_id), key)?;
             // Insert the value.
-            self.value_map().insert(key_id, value)?;
+            self.value_map().insert((mapping_id, key_id), value)?;
+            // Update the finalize tree.
+            update_finalize_tree(self, &[(key_id, Some(value_id))])?;
 
             Ok(())
         });
@@ -222,7 +279,7 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
             }
         };
         // If the key ID does not exist, insert it in the key-value ID map.
-        if !self.key_map().contains_key(&key_id)? {
+        if self.key_map().get_speculative(&(mapping_id, key_id))?.is_none() {
             // Ensure the key ID does not already exist.
             // If this fails, then there is inconsistent state, and likely data corruption.
             if key_value_ids.contains_key(&key_id) {
@@ -236,9 +293,11 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
             // Update the key-value ID map with the new key-value ID.
             self.key_value_id_map().insert(mapping_id, key_value_ids)?;
             // Insert the key.
-            self.key_map().insert(key_id, key)?;
+            self.key_map().insert((mapping_id, key_id), key)?;
             // Insert the value.
-            self.value_map().insert(key_id, value)?;
+            self.value_map().insert((mapping_id, key_id), value)?;
+            // Update the finalize tree.
+            update_finalize_tree(self, &[(key_id, Some(value_id))])?;
 
             Ok(())
         });
@@ -276,9 +335,11 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
             // Update the key-value ID map with the new key ID.
             self.key_value_id_map().insert(mapping_id, key_value_ids)?;
             // Remove the key.
-            self.key_map().remove(&key_id)?;
+            self.key_map().remove(&(mapping_id, key_id))?;
             // Remove the value.
-            self.value_map().remove(&key_id)?;
+            self.value_map().remove(&(mapping_id, key_id))?;
+            // Update the finalize tree.
+            update_finalize_tree(self, &[(key_id, None)])?;
 
             Ok(())
         });
@@ -321,9 +382,11 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
             self.key_value_id_map().remove(&mapping_id)?;
             // Remove the keys.
             for key_id in key_value_ids.keys() {
-                self.key_map().remove(key_id)?;
-                self.value_map().remove(key_id)?;
+                self.key_map().remove(&(mapping_id, *key_id))?;
+                self.value_map().remove(&(mapping_id, *key_id))?;
             }
+            // Update the finalize tree.
+            update_finalize_tree(self, &key_value_ids.keys().map(|key_id| (*key_id, None)).collect::<Vec<_>>())?;
 
             Ok(())
         });
@@ -369,9 +432,11 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
                 self.key_value_id_map().remove(&mapping_id)?;
                 // Remove the keys.
                 for key_id in key_value_ids.keys() {
-                    self.key_map().remove(key_id)?;
-                    self.value_map().remove(key_id)?;
+                    self.key_map().remove(&(mapping_id, *key_id))?;
+                    self.value_map().remove(&(mapping_id, *key_id))?;
                 }
+                // Update the finalize tree.
+                update_finalize_tree(self, &key_value_ids.keys().map(|key_id| (*key_id, None)).collect::<Vec<_>>())?;
             }
 
             Ok(())
@@ -382,12 +447,12 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
 
     /// Returns `true` if the given `program ID` exist.
     fn contains_program(&self, program_id: &ProgramID<N>) -> Result<bool> {
-        self.program_id_map().contains_key(program_id)
+        Ok(self.program_id_map().get_speculative(program_id)?.is_some())
     }
 
     /// Returns `true` if the given `program ID` and `mapping name` exist.
     fn contains_mapping(&self, program_id: &ProgramID<N>, mapping_name: &Identifier<N>) -> Result<bool> {
-        self.mapping_id_map().contains_key(&(*program_id, *mapping_name))
+        Ok(self.mapping_id_map().get_speculative(&(*program_id, *mapping_name))?.is_some())
     }
 
     /// Returns `true` if the given `program ID`, `mapping name`, and `key` exist.
@@ -405,7 +470,7 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         // Compute the key ID.
         let key_id = N::hash_bhp1024(&(mapping_id, N::hash_bhp1024(&key.to_bits_le())?).to_bits_le())?;
         // Return whether the key ID exists.
-        self.key_map().contains_key(&key_id)
+        Ok(self.key_map().get_speculative(&(mapping_id, key_id))?.is_some())
     }
 
     /// Returns the mapping names for the given `program ID`.
@@ -440,15 +505,15 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         // Compute the key ID.
         let key_id = N::hash_bhp1024(&(mapping_id, N::hash_bhp1024(&key.to_bits_le())?).to_bits_le())?;
         // Ensure the key ID exists.
-        match self.key_map().contains_key(&key_id)? {
+        match self.key_map().get_speculative(&(mapping_id, key_id))?.is_some() {
             true => Ok(Some(key_id)),
             false => Ok(None),
         }
     }
 
-    /// Returns the key for the given `key ID`.
-    fn get_key(&self, key_id: &Field<N>) -> Result<Option<Plaintext<N>>> {
-        match self.key_map().get_speculative(key_id)? {
+    /// Returns the key for the given `mapping ID` and `key ID`.
+    fn get_key(&self, mapping_id: &Field<N>, key_id: &Field<N>) -> Result<Option<Plaintext<N>>> {
+        match self.key_map().get_speculative(&(*mapping_id, *key_id))? {
             Some(key) => Ok(Some(cow_to_cloned!(key))),
             None => Ok(None),
         }
@@ -461,22 +526,80 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         mapping_name: &Identifier<N>,
         key: &Plaintext<N>,
     ) -> Result<Option<Value<N>>> {
-        // Retrieve the key ID.
-        match self.get_key_id(program_id, mapping_name, key)? {
-            // Retrieve the value.
-            Some(key_id) => self.get_value_from_key_id(&key_id),
-            None => Ok(None),
-        }
+        // Retrieve the mapping ID.
+        let mapping_id = match self.get_mapping_id(program_id, mapping_name)? {
+            Some(mapping_id) => mapping_id,
+            None => return Ok(None),
+        };
+        // Compute the key ID.
+        let key_id = N::hash_bhp1024(&(mapping_id, N::hash_bhp1024(&key.to_bits_le())?).to_bits_le())?;
+        // Retrieve the value.
+        self.get_value_from_key_id(&mapping_id, &key_id)
     }
 
-    /// Returns the value for the given `key ID`.
-    fn get_value_from_key_id(&self, key_id: &Field<N>) -> Result<Option<Value<N>>> {
-        match self.value_map().get_speculative(key_id)? {
+    /// Returns the value for the given `mapping ID` and `key ID`.
+    fn get_value_from_key_id(&self, mapping_id: &Field<N>, key_id: &Field<N>) -> Result<Option<Value<N>>> {
+        match self.value_map().get_speculative(&(*mapping_id, *key_id))? {
             Some(value) => Ok(Some(cow_to_cloned!(value))),
             None => Ok(None),
         }
     }
 
+    /// Returns the `(key, value)` entries for the given `program ID` and `mapping name`, in order of their key IDs.
+    /// Note: This reads the entries in storage, and does not include the writes in a pending atomic batch.
+    fn get_mapping_entries(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+    ) -> Result<Option<Vec<(Plaintext<N>, Value<N>)>>> {
+        // Retrieve the mapping ID.
+        let mapping_id = match self.get_mapping_id(program_id, mapping_name)? {
+            Some(mapping_id) => mapping_id,
+            None => return Ok(None),
+        };
+        // Retrieve the values of the mapping, which are keyed by `(mapping ID, key ID)` like its keys.
+        let mut values = self.value_map().iter_prefix(&mapping_id)?;
+        // Retrieve the key and value for each key ID of the mapping, in the same order.
+        let entries = self
+            .key_map()
+            .iter_prefix(&mapping_id)?
+            .map(|(key_ids, key)| match values.next() {
+                Some((value_ids, value)) if *value_ids == *key_ids => Ok((cow_to_cloned!(key), cow_to_cloned!(value))),
+                _ => bail!("Missing value for key ID '{}' in storage", key_ids.1),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Ensure there are no values without a key.
+        if let Some((value_ids, _)) = values.next() {
+            bail!("Missing key for key ID '{}' in storage", value_ids.1)
+        }
+        Ok(Some(entries))
+    }
+
+    /// Returns the finalize root, as the root of the sparse Merkle tree over the entries of every mapping.
+    /// Note: The finalize root is zero if there are no entries.
+    fn get_finalize_root(&self) -> Result<Field<N>> {
+        to_finalize_root(self)
+    }
+
+    /// Returns the inclusion or non-inclusion proof for the given `program ID`, `mapping name`, and `key`,
+    /// against the finalize root.
+    fn get_mapping_proof(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<MappingProof<N>> {
+        // Retrieve the mapping ID.
+        let mapping_id = match self.get_mapping_id(program_id, mapping_name)? {
+            Some(mapping_id) => mapping_id,
+            None => bail!("Illegal operation: mapping '{mapping_name}' is not initialized - cannot prove a key."),
+        };
+        // Compute the key ID.
+        let key_id = to_key_id(&mapping_id, key)?;
+        // Compute the mapping proof.
+        to_mapping_proof(self, &key_id)
+    }
+
     /// Returns the checksum.
     fn get_checksum(&self) -> Result<Field<N>> {
         // Compute all mapping checksums.
@@ -510,9 +633,13 @@ pub struct ProgramMemory<N: Network> {
     /// The key-value ID map.
     key_value_id_map: MemoryMap<Field<N>, IndexMap<Field<N>, Field<N>>>,
     /// The key map.
-    key_map: MemoryMap<Field<N>, Plaintext<N>>,
+    key_map: MemoryMap<(Field<N>, Field<N>), Plaintext<N>>,
     /// The value map.
-    value_map: MemoryMap<Field<N>, Value<N>>,
+    value_map: MemoryMap<(Field<N>, Field<N>), Value<N>>,
+    /// The finalize leaf map.
+    finalize_leaf_map: MemoryMap<u32, Vec<(Field<N>, Field<N>)>>,
+    /// The finalize node map.
+    finalize_node_map: MemoryMap<(u8, u32), Field<N>>,
     /// The optional development ID.
     dev: Option<u16>,
 }
@@ -522,8 +649,10 @@ impl<N: Network> ProgramStorage<N> for ProgramMemory<N> {
     type ProgramIDMap = MemoryMap<ProgramID<N>, IndexSet<Identifier<N>>>;
     type MappingIDMap = MemoryMap<(ProgramID<N>, Identifier<N>), Field<N>>;
     type KeyValueIDMap = MemoryMap<Field<N>, IndexMap<Field<N>, Field<N>>>;
-    type KeyMap = MemoryMap<Field<N>, Plaintext<N>>;
-    type ValueMap = MemoryMap<Field<N>, Value<N>>;
+    type KeyMap = MemoryMap<(Field<N>, Field<N>), Plaintext<N>>;
+    type ValueMap = MemoryMap<(Field<N>, Field<N>), Value<N>>;
+    type FinalizeLeafMap = MemoryMap<u32, Vec<(Field<N>, Field<N>)>>;
+    type FinalizeNodeMap = MemoryMap<(u8, u32), Field<N>>;
 
     /// Initializes the program state storage.
     fn open(dev: Option<u16>) -> Result<Self> {
@@ -533,6 +662,8 @@ impl<N: Network> ProgramStorage<N> for ProgramMemory<N> {
             key_value_id_map: MemoryMap::default(),
             key_map: MemoryMap::default(),
             value_map: MemoryMap::default(),
+            finalize_leaf_map: MemoryMap::default(),
+            finalize_node_map: MemoryMap::default(),
             dev,
         })
     }
@@ -562,6 +693,16 @@ impl<N: Network> ProgramStorage<N> for ProgramMemory<N> {
         &self.value_map
     }
 
+    /// Returns the finalize leaf map.
+    fn finalize_leaf_map(&self) -> &Self::FinalizeLeafMap {
+        &self.finalize_leaf_map
+    }
+
+    /// Returns the finalize node map.
+    fn finalize_node_map(&self) -> &Self::FinalizeNodeMap {
+        &self.finalize_node_map
+    }
+
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16> {
         self.dev
@@ -650,6 +791,21 @@ impl<N: Network, P: ProgramStorage<N>> ProgramStore<N, P> {
         self.storage.is_atomic_in_progress()
     }
 
+    /// Checkpoints the atomic batch.
+    pub fn atomic_checkpoint(&self) {
+        self.storage.atomic_checkpoint();
+    }
+
+    /// Clears the latest atomic batch checkpoint.
+    pub fn clear_latest_checkpoint(&self) {
+        self.storage.clear_latest_checkpoint();
+    }
+
+    /// Rewinds the atomic batch to the latest checkpoint.
+    pub fn atomic_rewind(&self) {
+        self.storage.atomic_rewind();
+    }
+
     /// Aborts an atomic batch write operation.
     pub fn abort_atomic(&self) {
         self.storage.abort_atomic();
@@ -703,6 +859,31 @@ impl<N: Network, P: ProgramStorage<N>> ProgramStore<N, P> {
     ) -> Result<Option<Value<N>>> {
         self.storage.get_value(program_id, mapping_name, key)
     }
+
+    /// Returns the `(key, value)` entries for the given `program ID` and `mapping name`.
+    pub fn get_mapping_entries(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+    ) -> Result<Option<Vec<(Plaintext<N>, Value<N>)>>> {
+        self.storage.get_mapping_entries(program_id, mapping_name)
+    }
+
+    /// Returns the finalize root, as the root of the sparse Merkle tree over the entries of every mapping.
+    pub fn get_finalize_root(&self) -> Result<Field<N>> {
+        self.storage.get_finalize_root()
+    }
+
+    /// Returns the inclusion or non-inclusion proof for the given `program ID`, `mapping name`, and `key`,
+    /// against the finalize root.
+    pub fn get_mapping_proof(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<MappingProof<N>> {
+        self.storage.get_mapping_proof(program_id, mapping_name, key)
+    }
 }
 
 #[cfg(test)]
@@ -710,11 +891,14 @@ mod tests {
     use super::*;
     use console::network::Testnet3;
 
+    #[cfg(feature = "rocks")]
+    use crate::store::helpers::rocksdb::{sample_dev, ProgramDB};
+
     type CurrentNetwork = Testnet3;
 
     /// Checks `initialize_mapping`, `insert_key_value`, `remove_key_value`, and `remove_mapping`.
-    fn check_initialize_insert_remove<N: Network>(
-        program_store: &ProgramMemory<N>,
+    fn check_initialize_insert_remove<N: Network, P: ProgramStorage<N>>(
+        program_store: &P,
         program_id: ProgramID<N>,
         mapping_name: Identifier<N>,
     ) {
@@ -786,8 +970,8 @@ mod tests {
     }
 
     /// Checks `initialize_mapping`, `update_key_value`, `remove_key_value`, and `remove_mapping`.
-    fn check_initialize_update_remove<N: Network>(
-        program_store: &ProgramMemory<N>,
+    fn check_initialize_update_remove<N: Network, P: ProgramStorage<N>>(
+        program_store: &P,
         program_id: ProgramID<N>,
         mapping_name: Identifier<N>,
     ) {
@@ -924,6 +1108,128 @@ mod tests {
         check_initialize_update_remove(&program_store, program_id, mapping_name);
     }
 
+    #[test]
+    #[cfg(feature = "rocks")]
+    fn test_initialize_insert_remove_rocksdb() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Initialize a new program store.
+        let program_store = ProgramDB::open(sample_dev()).unwrap();
+        // Check the operations.
+        check_initialize_insert_remove(&program_store, program_id, mapping_name);
+    }
+
+    #[test]
+    #[cfg(feature = "rocks")]
+    fn test_initialize_update_remove_rocksdb() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Initialize a new program store.
+        let program_store = ProgramDB::open(sample_dev()).unwrap();
+        // Check the operations.
+        check_initialize_update_remove(&program_store, program_id, mapping_name);
+    }
+
+    #[test]
+    fn test_finalize_root_and_mapping_proof() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Initialize a new program store.
+        let program_store = ProgramMemory::open(None).unwrap();
+        program_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+
+        // Prepare the keys and values.
+        let key = |i: u64| Plaintext::from_str(&format!("{i}field")).unwrap();
+        let value = |i: u64| Value::from_str(&format!("{i}u64")).unwrap();
+
+        // Ensure the finalize root of an empty store is zero, and proves that a key is absent.
+        let root = program_store.get_finalize_root().unwrap();
+        assert_eq!(root, Field::zero());
+        let proof = program_store.get_mapping_proof(&program_id, &mapping_name, &key(0)).unwrap();
+        assert!(proof.verify(&root, &program_id, &mapping_name, &key(0), None).unwrap());
+
+        // Insert the entries.
+        for i in 0..5 {
+            program_store.insert_key_value(&program_id, &mapping_name, key(i), value(i)).unwrap();
+        }
+        let root = program_store.get_finalize_root().unwrap();
+        assert_ne!(root, Field::zero());
+
+        // Ensure each entry is proven to be included, with its value only.
+        for i in 0..5 {
+            let proof = program_store.get_mapping_proof(&program_id, &mapping_name, &key(i)).unwrap();
+            assert!(proof.verify(&root, &program_id, &mapping_name, &key(i), Some(&value(i))).unwrap());
+            assert!(!proof.verify(&root, &program_id, &mapping_name, &key(i), Some(&value(i + 1))).unwrap());
+            assert!(!proof.verify(&root, &program_id, &mapping_name, &key(i), None).unwrap());
+        }
+
+        // Ensure an absent key is proven to be absent.
+        let proof = program_store.get_mapping_proof(&program_id, &mapping_name, &key(5)).unwrap();
+        assert!(proof.verify(&root, &program_id, &mapping_name, &key(5), None).unwrap());
+        assert!(!proof.verify(&root, &program_id, &mapping_name, &key(5), Some(&value(5))).unwrap());
+
+        // Update an entry, and ensure the finalize root changes, and the stale proof fails.
+        let stale_proof = program_store.get_mapping_proof(&program_id, &mapping_name, &key(0)).unwrap();
+        program_store.update_key_value(&program_id, &mapping_name, key(0), value(10)).unwrap();
+        let new_root = program_store.get_finalize_root().unwrap();
+        assert_ne!(root, new_root);
+        assert!(!stale_proof.verify(&new_root, &program_id, &mapping_name, &key(0), Some(&value(0))).unwrap());
+        let proof = program_store.get_mapping_proof(&program_id, &mapping_name, &key(0)).unwrap();
+        assert!(proof.verify(&new_root, &program_id, &mapping_name, &key(0), Some(&value(10))).unwrap());
+    }
+
+    #[test]
+    fn test_finalize_root_is_incremental() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Prepare the keys and values.
+        let key = |i: u64| Plaintext::from_str(&format!("{i}field")).unwrap();
+        let value = |i: u64| Value::from_str(&format!("{i}u64")).unwrap();
+
+        // Initialize two program stores, with the same entries in a different order of writes.
+        let first_store = ProgramMemory::open(None).unwrap();
+        first_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+        for i in 0..4 {
+            first_store.insert_key_value(&program_id, &mapping_name, key(i), value(i)).unwrap();
+        }
+        let second_store = ProgramMemory::open(None).unwrap();
+        second_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+        for i in (0..5).rev() {
+            second_store.update_key_value(&program_id, &mapping_name, key(i), value(i + 1)).unwrap();
+        }
+        second_store.remove_key_value(&program_id, &mapping_name, &key(4)).unwrap();
+        for i in 0..4 {
+            second_store.update_key_value(&program_id, &mapping_name, key(i), value(i)).unwrap();
+        }
+        // Ensure the finalize roots match.
+        let root = first_store.get_finalize_root().unwrap();
+        assert_eq!(root, second_store.get_finalize_root().unwrap());
+
+        // Ensure the finalize root includes the atomic batch, and is restored when the batch is rewound.
+        first_store.start_atomic();
+        first_store.atomic_checkpoint();
+        first_store.update_key_value(&program_id, &mapping_name, key(0), value(10)).unwrap();
+        assert_ne!(root, first_store.get_finalize_root().unwrap());
+        first_store.atomic_rewind();
+        assert_eq!(root, first_store.get_finalize_root().unwrap());
+        first_store.abort_atomic();
+        assert_eq!(root, first_store.get_finalize_root().unwrap());
+
+        // Ensure the finalize root is zero once the mapping is removed.
+        first_store.remove_mapping(&program_id, &mapping_name).unwrap();
+        assert_eq!(first_store.get_finalize_root().unwrap(), Field::zero());
+        assert!(first_store.finalize_leaf_map().iter().next().is_none());
+        assert!(first_store.finalize_node_map().iter().next().is_none());
+    }
+
     #[test]
     fn test_remove_key_value() {
         // Initialize a program ID and mapping name.
@@ -1175,4 +1481,140 @@ mod tests {
         check_initialize_insert_remove(&program_store, program_id, mapping_name);
         check_initialize_update_remove(&program_store, program_id, mapping_name);
     }
+
+    #[test]
+    fn test_atomic_rewind() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Initialize a new program store, and the mapping.
+        let program_store = ProgramMemory::open(None).unwrap();
+        program_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+
+        // Prepare two keys and values.
+        let first_key = Plaintext::from_str("123456789field").unwrap();
+        let first_value = Value::from_str("987654321u128").unwrap();
+        let second_key = Plaintext::from_str("987654321field").unwrap();
+        let second_value = Value::from_str("123456789u128").unwrap();
+
+        // Start an atomic write batch.
+        program_store.start_atomic();
+
+        // Insert the first (key, value), and keep it.
+        program_store.atomic_checkpoint();
+        program_store.insert_key_value(&program_id, &mapping_name, first_key.clone(), first_value.clone()).unwrap();
+        program_store.clear_latest_checkpoint();
+
+        // Insert the second (key, value), and roll it back.
+        program_store.atomic_checkpoint();
+        program_store.insert_key_value(&program_id, &mapping_name, second_key.clone(), second_value).unwrap();
+        program_store.atomic_rewind();
+
+        // Finish the atomic write batch.
+        program_store.finish_atomic().unwrap();
+
+        // Ensure only the first (key, value) was written.
+        assert_eq!(program_store.get_value(&program_id, &mapping_name, &first_key).unwrap(), Some(first_value));
+        assert!(!program_store.contains_key(&program_id, &mapping_name, &second_key).unwrap());
+    }
+
+    #[test]
+    fn test_set_get_remove_in_atomic_batch() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Initialize a new program store.
+        let program_store = ProgramMemory::open(None).unwrap();
+
+        // Prepare two keys and values.
+        let first_key = Plaintext::from_str("123456789field").unwrap();
+        let first_value = Value::from_str("987654321u128").unwrap();
+        let second_key = Plaintext::from_str("987654321field").unwrap();
+        let second_value = Value::from_str("123456789u128").unwrap();
+
+        // Start an atomic write batch.
+        program_store.start_atomic();
+
+        // Initialize the mapping, and ensure it is visible within the batch.
+        program_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+        assert!(program_store.contains_program(&program_id).unwrap());
+        assert!(program_store.contains_mapping(&program_id, &mapping_name).unwrap());
+
+        // Set the first key, and ensure it is visible within the batch.
+        program_store.update_key_value(&program_id, &mapping_name, first_key.clone(), first_value.clone()).unwrap();
+        assert!(program_store.contains_key(&program_id, &mapping_name, &first_key).unwrap());
+        let candidate = program_store.get_value(&program_id, &mapping_name, &first_key).unwrap();
+        assert_eq!(candidate, Some(first_value.clone()));
+        // Ensure inserting the first key again fails.
+        assert!(program_store.insert_key_value(&program_id, &mapping_name, first_key.clone(), first_value).is_err());
+
+        // Set the second key twice, and ensure the latest value is visible within the batch.
+        let stale_value = Value::from_str("1u128").unwrap();
+        program_store.update_key_value(&program_id, &mapping_name, second_key.clone(), stale_value).unwrap();
+        program_store.update_key_value(&program_id, &mapping_name, second_key.clone(), second_value.clone()).unwrap();
+        let candidate = program_store.get_value(&program_id, &mapping_name, &second_key).unwrap();
+        assert_eq!(candidate, Some(second_value.clone()));
+
+        // Remove the first key, and ensure it is no longer visible within the batch.
+        program_store.remove_key_value(&program_id, &mapping_name, &first_key).unwrap();
+        assert!(!program_store.contains_key(&program_id, &mapping_name, &first_key).unwrap());
+        assert!(program_store.get_value(&program_id, &mapping_name, &first_key).unwrap().is_none());
+
+        // Ensure nothing has been committed yet.
+        assert!(program_store.program_id_map().get(&program_id).unwrap().is_none());
+
+        // Finish the atomic write batch.
+        program_store.finish_atomic().unwrap();
+
+        // Ensure only the second (key, value) was committed.
+        assert!(!program_store.contains_key(&program_id, &mapping_name, &first_key).unwrap());
+        assert_eq!(program_store.get_value(&program_id, &mapping_name, &second_key).unwrap(), Some(second_value));
+    }
+
+    #[test]
+    fn test_get_mapping_entries() {
+        // Initialize two program IDs and mapping names.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let other_program_id = ProgramID::<CurrentNetwork>::from_str("world.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let other_mapping_name = Identifier::from_str("balance").unwrap();
+
+        // Initialize a new program store.
+        let program_store = ProgramMemory::open(None).unwrap();
+        // Ensure an un-initialized mapping has no entries.
+        assert!(program_store.get_mapping_entries(&program_id, &mapping_name).unwrap().is_none());
+
+        // Initialize the mappings, and insert an entry in each of the other mappings.
+        for (program_id, mapping_name) in
+            [(program_id, mapping_name), (program_id, other_mapping_name), (other_program_id, mapping_name)]
+        {
+            program_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+        }
+        let other_key = Plaintext::from_str("0field").unwrap();
+        let other_value = Value::from_str("0u64").unwrap();
+        program_store
+            .insert_key_value(&program_id, &other_mapping_name, other_key.clone(), other_value.clone())
+            .unwrap();
+        program_store.insert_key_value(&other_program_id, &mapping_name, other_key, other_value).unwrap();
+        // Ensure the mapping is empty.
+        assert_eq!(program_store.get_mapping_entries(&program_id, &mapping_name).unwrap(), Some(vec![]));
+
+        // Insert the list of keys and values.
+        let mut expected = Vec::new();
+        for item in 0..10 {
+            let key = Plaintext::from_str(&format!("{item}field")).unwrap();
+            let value = Value::from_str(&format!("{item}u64")).unwrap();
+            program_store.insert_key_value(&program_id, &mapping_name, key.clone(), value.clone()).unwrap();
+            expected.push((key, value));
+        }
+        // Remove a key.
+        program_store.remove_key_value(&program_id, &mapping_name, &expected.remove(5).0).unwrap();
+
+        // Ensure the entries of the mapping, and only of the mapping, are returned.
+        let entries = program_store.get_mapping_entries(&program_id, &mapping_name).unwrap().unwrap();
+        assert_eq!(entries.len(), expected.len());
+        assert!(expected.iter().all(|entry| entries.contains(entry)));
+    }
 }
@@ -0,0 +1,235 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::store::helpers::overlay_map::OverlayMap;
+
+/// A program state storage that reads through to a base storage, and keeps its own writes in memory.
+/// The base storage is never written to, so the overlay may be discarded at any time.
+#[derive(Clone)]
+pub struct ProgramOverlay<N: Network, P: ProgramStorage<N>> {
+    /// The program ID map.
+    program_id_map: OverlayMap<ProgramID<N>, IndexSet<Identifier<N>>, P::ProgramIDMap>,
+    /// The mapping ID map.
+    mapping_id_map: OverlayMap<(ProgramID<N>, Identifier<N>), Field<N>, P::MappingIDMap>,
+    /// The key-value ID map.
+    key_value_id_map: OverlayMap<Field<N>, IndexMap<Field<N>, Field<N>>, P::KeyValueIDMap>,
+    /// The key map.
+    key_map: OverlayMap<(Field<N>, Field<N>), Plaintext<N>, P::KeyMap>,
+    /// The value map.
+    value_map: OverlayMap<(Field<N>, Field<N>), Value<N>, P::ValueMap>,
+    /// The finalize leaf map.
+    finalize_leaf_map: OverlayMap<u32, Vec<(Field<N>, Field<N>)>, P::FinalizeLeafMap>,
+    /// The finalize node map.
+    finalize_node_map: OverlayMap<(u8, u32), Field<N>, P::FinalizeNodeMap>,
+    /// The base storage.
+    base: P,
+}
+
+impl<N: Network, P: ProgramStorage<N>> ProgramOverlay<N, P> {
+    /// Initializes a new overlay over the given base storage.
+    pub fn new(base: &P) -> Self {
+        Self {
+            program_id_map: OverlayMap::new(base.program_id_map().clone()),
+            mapping_id_map: OverlayMap::new(base.mapping_id_map().clone()),
+            key_value_id_map: OverlayMap::new(base.key_value_id_map().clone()),
+            key_map: OverlayMap::new(base.key_map().clone()),
+            value_map: OverlayMap::new(base.value_map().clone()),
+            finalize_leaf_map: OverlayMap::new(base.finalize_leaf_map().clone()),
+            finalize_node_map: OverlayMap::new(base.finalize_node_map().clone()),
+            base: base.clone(),
+        }
+    }
+
+    /// Returns the base storage.
+    pub const fn base(&self) -> &P {
+        &self.base
+    }
+}
+
+#[rustfmt::skip]
+impl<N: Network, P: ProgramStorage<N>> ProgramStorage<N> for ProgramOverlay<N, P> {
+    type ProgramIDMap = OverlayMap<ProgramID<N>, IndexSet<Identifier<N>>, P::ProgramIDMap>;
+    type MappingIDMap = OverlayMap<(ProgramID<N>, Identifier<N>), Field<N>, P::MappingIDMap>;
+    type KeyValueIDMap = OverlayMap<Field<N>, IndexMap<Field<N>, Field<N>>, P::KeyValueIDMap>;
+    type KeyMap = OverlayMap<(Field<N>, Field<N>), Plaintext<N>, P::KeyMap>;
+    type ValueMap = OverlayMap<(Field<N>, Field<N>), Value<N>, P::ValueMap>;
+    type FinalizeLeafMap = OverlayMap<u32, Vec<(Field<N>, Field<N>)>, P::FinalizeLeafMap>;
+    type FinalizeNodeMap = OverlayMap<(u8, u32), Field<N>, P::FinalizeNodeMap>;
+
+    /// Initializes the program state storage, as an overlay over a newly-opened base storage.
+    fn open(dev: Option<u16>) -> Result<Self> {
+        Ok(Self::new(&P::open(dev)?))
+    }
+
+    /// Returns the program ID map.
+    fn program_id_map(&self) -> &Self::ProgramIDMap {
+        &self.program_id_map
+    }
+
+    /// Returns the mapping ID map.
+    fn mapping_id_map(&self) -> &Self::MappingIDMap {
+        &self.mapping_id_map
+    }
+
+    /// Returns the key-value ID map.
+    fn key_value_id_map(&self) -> &Self::KeyValueIDMap {
+        &self.key_value_id_map
+    }
+
+    /// Returns the key map.
+    fn key_map(&self) -> &Self::KeyMap {
+        &self.key_map
+    }
+
+    /// Returns the value map.
+    fn value_map(&self) -> &Self::ValueMap {
+        &self.value_map
+    }
+
+    /// Returns the finalize leaf map.
+    fn finalize_leaf_map(&self) -> &Self::FinalizeLeafMap {
+        &self.finalize_leaf_map
+    }
+
+    /// Returns the finalize node map.
+    fn finalize_node_map(&self) -> &Self::FinalizeNodeMap {
+        &self.finalize_node_map
+    }
+
+    /// Returns the optional development ID.
+    fn dev(&self) -> Option<u16> {
+        self.base.dev()
+    }
+}
+
+impl<N: Network, P: ProgramStorage<N>> ProgramStore<N, P> {
+    /// Returns a program store that reads through to this store, including its pending atomic batch,
+    /// and keeps its own writes in memory. This store is never written to by the overlay.
+    pub fn to_overlay(&self) -> ProgramStore<N, ProgramOverlay<N, P>> {
+        ProgramStore::from(ProgramOverlay::new(&self.storage))
+    }
+}
+
+impl<N: Network, P: ProgramStorage<N>> ProgramStore<N, ProgramOverlay<N, P>> {
+    /// Returns the changes to the given mapping in the overlay, relative to its base storage,
+    /// as `(key, old value, new value)` entries, where a missing value is `None`.
+    pub fn get_mapping_changes(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+    ) -> Result<Vec<(Plaintext<N>, Option<Value<N>>, Option<Value<N>>)>> {
+        // Retrieves the key-value IDs of the mapping in the given storage.
+        fn key_value_ids<N: Network, P: ProgramStorage<N>>(
+            storage: &P,
+            mapping_id: &Field<N>,
+        ) -> Result<IndexMap<Field<N>, Field<N>>> {
+            match storage.key_value_id_map().get_speculative(mapping_id)? {
+                Some(key_value_ids) => Ok(cow_to_cloned!(key_value_ids)),
+                None => Ok(IndexMap::new()),
+            }
+        }
+
+        let base = self.storage.base();
+        // Retrieve the mapping ID, from the overlay or its base storage.
+        let mapping_id = match self.storage.get_mapping_id(program_id, mapping_name)? {
+            Some(mapping_id) => mapping_id,
+            None => match base.get_mapping_id(program_id, mapping_name)? {
+                Some(mapping_id) => mapping_id,
+                None => return Ok(vec![]),
+            },
+        };
+        // Retrieve the key-value IDs of the mapping, before and after the overlay.
+        let old_ids = key_value_ids(base, &mapping_id)?;
+        let new_ids = key_value_ids(&self.storage, &mapping_id)?;
+        // If the mapping is unchanged, return early.
+        if old_ids == new_ids {
+            return Ok(vec![]);
+        }
+
+        let mut changes = Vec::new();
+        // Record the updated and removed entries.
+        for (key_id, old_value_id) in &old_ids {
+            if new_ids.get(key_id) != Some(old_value_id) {
+                let key = match base.get_key(&mapping_id, key_id)? {
+                    Some(key) => key,
+                    None => bail!("Missing key for key ID '{key_id}' in storage"),
+                };
+                let new_value = match new_ids.contains_key(key_id) {
+                    true => self.storage.get_value_from_key_id(&mapping_id, key_id)?,
+                    false => None,
+                };
+                changes.push((key, base.get_value_from_key_id(&mapping_id, key_id)?, new_value));
+            }
+        }
+        // Record the inserted entries.
+        for key_id in new_ids.keys().filter(|key_id| !old_ids.contains_key(*key_id)) {
+            let key = match self.storage.get_key(&mapping_id, key_id)? {
+                Some(key) => key,
+                None => bail!("Missing key for key ID '{key_id}' in storage"),
+            };
+            changes.push((key, None, self.storage.get_value_from_key_id(&mapping_id, key_id)?));
+        }
+        Ok(changes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_overlay_keeps_writes_and_reports_changes() {
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = |index: u64| Plaintext::from_str(&format!("{index}u64")).unwrap();
+        let value = |amount: u64| Value::from_str(&format!("{amount}u64")).unwrap();
+
+        // Initialize a program store, with a mapping of two entries.
+        let store = ProgramStore::<CurrentNetwork, ProgramMemory<CurrentNetwork>>::open(None).unwrap();
+        store.initialize_mapping(&program_id, &mapping_name).unwrap();
+        store.insert_key_value(&program_id, &mapping_name, key(0), value(0)).unwrap();
+        store.insert_key_value(&program_id, &mapping_name, key(1), value(1)).unwrap();
+        // Start an atomic batch, with an update that has not been written yet.
+        store.start_atomic();
+        store.update_key_value(&program_id, &mapping_name, key(2), value(2)).unwrap();
+
+        // Initialize an overlay, and ensure it reads the pending atomic batch.
+        let overlay = store.to_overlay();
+        assert_eq!(overlay.get_value(&program_id, &mapping_name, &key(2)).unwrap(), Some(value(2)));
+        assert!(overlay.get_mapping_changes(&program_id, &mapping_name).unwrap().is_empty());
+
+        // Update, remove, and insert an entry in the overlay.
+        overlay.update_key_value(&program_id, &mapping_name, key(0), value(10)).unwrap();
+        overlay.remove_key_value(&program_id, &mapping_name, &key(1)).unwrap();
+        overlay.update_key_value(&program_id, &mapping_name, key(3), value(3)).unwrap();
+        assert_eq!(overlay.get_mapping_changes(&program_id, &mapping_name).unwrap(), vec![
+            (key(0), Some(value(0)), Some(value(10))),
+            (key(1), Some(value(1)), None),
+            (key(3), None, Some(value(3))),
+        ]);
+
+        // Ensure the program store is untouched.
+        assert_eq!(store.get_value(&program_id, &mapping_name, &key(0)).unwrap(), Some(value(0)));
+        assert_eq!(store.get_value(&program_id, &mapping_name, &key(1)).unwrap(), Some(value(1)));
+        assert!(store.get_value(&program_id, &mapping_name, &key(3)).unwrap().is_none());
+        store.abort_atomic();
+        assert!(store.get_value(&program_id, &mapping_name, &key(2)).unwrap().is_none());
+    }
+}
@@ -30,6 +30,7 @@ use crate::{
     },
 };
 use console::{
+    account::{Address, Signature},
     network::prelude::*,
     program::{Identifier, ProgramID},
 };
@@ -53,6 +54,8 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
     type VerifyingKeyMap: for<'a> Map<'a, (ProgramID<N>, Identifier<N>, u16), VerifyingKey<N>>;
     /// The mapping of `(program ID, function name, edition)` to `certificate`.
     type CertificateMap: for<'a> Map<'a, (ProgramID<N>, Identifier<N>, u16), Certificate<N>>;
+    /// The mapping of `(program ID, edition)` to `(owner, signature)`.
+    type OwnerMap: for<'a> Map<'a, (ProgramID<N>, u16), (Address<N>, Signature<N>)>;
     /// The mapping of `transaction ID` to `(fee transition ID, global state root, inclusion proof)`.
     type FeeMap: for<'a> Map<'a, N::TransactionID, (N::TransitionID, N::StateRoot, Option<Proof<N>>)>;
     /// The transition storage.
@@ -73,6 +76,8 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
     fn verifying_key_map(&self) -> &Self::VerifyingKeyMap;
     /// Returns the certificate map.
     fn certificate_map(&self) -> &Self::CertificateMap;
+    /// Returns the owner map.
+    fn owner_map(&self) -> &Self::OwnerMap;
     /// Returns the fee map.
     fn fee_map(&self) -> &Self::FeeMap;
     /// Returns the transition storage.
@@ -91,6 +96,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.program_map().start_atomic();
         self.verifying_key_map().start_atomic();
         self.certificate_map().start_atomic();
+        self.owner_map().start_atomic();
         self.fee_map().start_atomic();
         self.transition_store().start_atomic();
     }
@@ -103,6 +109,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             || self.program_map().is_atomic_in_progress()
             || self.verifying_key_map().is_atomic_in_progress()
             || self.certificate_map().is_atomic_in_progress()
+            || self.owner_map().is_atomic_in_progress()
             || self.fee_map().is_atomic_in_progress()
             || self.transition_store().is_atomic_in_progress()
     }
@@ -115,6 +122,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.program_map().abort_atomic();
         self.verifying_key_map().abort_atomic();
         self.certificate_map().abort_atomic();
+        self.owner_map().abort_atomic();
         self.fee_map().abort_atomic();
         self.transition_store().abort_atomic();
     }
@@ -127,6 +135,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.program_map().finish_atomic()?;
         self.verifying_key_map().finish_atomic()?;
         self.certificate_map().finish_atomic()?;
+        self.owner_map().finish_atomic()?;
         self.fee_map().finish_atomic()?;
         self.transition_store().finish_atomic()
     }
@@ -177,6 +186,10 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
                 // Store the certificate.
                 self.certificate_map().insert((program_id, *function_name, edition), certificate.clone())?;
             }
+            // Store the owner and their signature, if the deployment is signed.
+            if let Some(owner) = deployment.owner() {
+                self.owner_map().insert((program_id, edition), *owner)?;
+            }
 
             // Store the fee.
             self.fee_map().insert(
@@ -194,16 +207,26 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
 
     /// Removes the deployment transaction for the given `transaction ID`.
     fn remove(&self, transaction_id: &N::TransactionID) -> Result<()> {
-        // Retrieve the program ID.
-        let program_id = match self.get_program_id(transaction_id)? {
-            Some(edition) => edition,
+        // Retrieve the program ID and edition.
+        let (program_id, edition) = match self.get_program_id_and_edition(transaction_id)? {
+            Some((program_id, edition)) => (program_id, edition),
             None => bail!("Failed to get the program ID for transaction '{transaction_id}'"),
         };
-        // Retrieve the edition.
-        let edition = match self.get_edition(&program_id)? {
+        // Retrieve the latest edition.
+        let latest_edition = match self.get_edition(&program_id)? {
             Some(edition) => edition,
             None => bail!("Failed to locate the edition for program '{program_id}'"),
         };
+        // If the latest edition is removed, find the previous edition, if one exists.
+        let mut previous_edition = None;
+        if edition == latest_edition {
+            for candidate in (0..edition).rev() {
+                if self.reverse_id_map().contains_key(&(program_id, candidate))? {
+                    previous_edition = Some(candidate);
+                    break;
+                }
+            }
+        }
         // Retrieve the program.
         let program = match self.program_map().get(&(program_id, edition))? {
             Some(program) => cow_to_cloned!(program),
@@ -218,8 +241,15 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         atomic_write_batch!(self, {
             // Remove the program ID.
             self.id_map().remove(transaction_id)?;
-            // Remove the edition.
-            self.edition_map().remove(&program_id)?;
+            // Update the edition, if the latest edition is removed.
+            if edition == latest_edition {
+                match previous_edition {
+                    // Restore the previous edition.
+                    Some(previous_edition) => self.edition_map().insert(program_id, previous_edition)?,
+                    // Remove the edition.
+                    None => self.edition_map().remove(&program_id)?,
+                }
+            }
 
             // Remove the reverse program ID.
             self.reverse_id_map().remove(&(program_id, edition))?;
@@ -233,6 +263,8 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
                 // Remove the certificate.
                 self.certificate_map().remove(&(program_id, *function_name, edition))?;
             }
+            // Remove the owner.
+            self.owner_map().remove(&(program_id, edition))?;
 
             // Remove the fee.
             self.fee_map().remove(transaction_id)?;
@@ -259,6 +291,18 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         }
     }
 
+    /// Returns the transaction ID that deployed the given edition of the `program ID`.
+    fn find_transaction_id_for_edition(
+        &self,
+        program_id: &ProgramID<N>,
+        edition: u16,
+    ) -> Result<Option<N::TransactionID>> {
+        match self.reverse_id_map().get(&(*program_id, edition))? {
+            Some(transaction_id) => Ok(Some(cow_to_copied!(transaction_id))),
+            None => Ok(None),
+        }
+    }
+
     /// Returns the program ID for the given `transaction ID`.
     fn get_program_id(&self, transaction_id: &N::TransactionID) -> Result<Option<ProgramID<N>>> {
         // Retrieve the program ID.
@@ -268,6 +312,29 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         }
     }
 
+    /// Returns the program ID and edition deployed in the given `transaction ID`.
+    fn get_program_id_and_edition(&self, transaction_id: &N::TransactionID) -> Result<Option<(ProgramID<N>, u16)>> {
+        // Retrieve the program ID.
+        let program_id = match self.get_program_id(transaction_id)? {
+            Some(program_id) => program_id,
+            None => return Ok(None),
+        };
+        // Retrieve the latest edition.
+        let latest_edition = match self.get_edition(&program_id)? {
+            Some(edition) => edition,
+            None => bail!("Failed to get the edition for program '{program_id}'"),
+        };
+        // Find the edition deployed in the transaction, starting from the latest edition.
+        for edition in (0..=latest_edition).rev() {
+            if let Some(candidate) = self.reverse_id_map().get(&(program_id, edition))? {
+                if cow_to_copied!(candidate) == *transaction_id {
+                    return Ok(Some((program_id, edition)));
+                }
+            }
+        }
+        bail!("Failed to locate the edition of program '{program_id}' for transaction '{transaction_id}'")
+    }
+
     /// Returns the edition for the given `program ID`.
     fn get_edition(&self, program_id: &ProgramID<N>) -> Result<Option<u16>> {
         match self.edition_map().get(program_id)? {
@@ -328,16 +395,11 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
 
     /// Returns the deployment for the given `transaction ID`.
     fn get_deployment(&self, transaction_id: &N::TransactionID) -> Result<Option<Deployment<N>>> {
-        // Retrieve the program ID.
-        let program_id = match self.get_program_id(transaction_id)? {
-            Some(edition) => edition,
+        // Retrieve the program ID and edition.
+        let (program_id, edition) = match self.get_program_id_and_edition(transaction_id)? {
+            Some((program_id, edition)) => (program_id, edition),
             None => return Ok(None),
         };
-        // Retrieve the edition.
-        let edition = match self.get_edition(&program_id)? {
-            Some(edition) => edition,
-            None => bail!("Failed to get the edition for program '{program_id}'"),
-        };
         // Retrieve the program.
         let program = match self.program_map().get(&(program_id, edition))? {
             Some(program) => cow_to_cloned!(program),
@@ -363,8 +425,14 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             verifying_keys.insert(*function_name, (verifying_key, certificate));
         }
 
+        // Initialize the deployment.
+        let mut deployment = Deployment::new(edition, program, verifying_keys)?;
+        // Restore the owner and their signature, if the deployment is signed.
+        if let Some(owner) = self.owner_map().get(&(program_id, edition))? {
+            deployment.restore_owner(cow_to_copied!(owner));
+        }
         // Return the deployment.
-        Ok(Some(Deployment::new(edition, program, verifying_keys)?))
+        Ok(Some(deployment))
     }
 
     /// Returns the fee for the given `transaction ID`.
@@ -420,6 +488,8 @@ pub struct DeploymentMemory<N: Network> {
     verifying_key_map: MemoryMap<(ProgramID<N>, Identifier<N>, u16), VerifyingKey<N>>,
     /// The certificate map.
     certificate_map: MemoryMap<(ProgramID<N>, Identifier<N>, u16), Certificate<N>>,
+    /// The owner map.
+    owner_map: MemoryMap<(ProgramID<N>, u16), (Address<N>, Signature<N>)>,
     /// The fee map.
     fee_map: MemoryMap<N::TransactionID, (N::TransitionID, N::StateRoot, Option<Proof<N>>)>,
     /// The transition store.
@@ -434,6 +504,7 @@ impl<N: Network> DeploymentStorage<N> for DeploymentMemory<N> {
     type ProgramMap = MemoryMap<(ProgramID<N>, u16), Program<N>>;
     type VerifyingKeyMap = MemoryMap<(ProgramID<N>, Identifier<N>, u16), VerifyingKey<N>>;
     type CertificateMap = MemoryMap<(ProgramID<N>, Identifier<N>, u16), Certificate<N>>;
+    type OwnerMap = MemoryMap<(ProgramID<N>, u16), (Address<N>, Signature<N>)>;
     type FeeMap = MemoryMap<N::TransactionID, (N::TransitionID, N::StateRoot, Option<Proof<N>>)>;
     type TransitionStorage = TransitionMemory<N>;
 
@@ -446,6 +517,7 @@ impl<N: Network> DeploymentStorage<N> for DeploymentMemory<N> {
             program_map: MemoryMap::default(),
             verifying_key_map: MemoryMap::default(),
             certificate_map: MemoryMap::default(),
+            owner_map: MemoryMap::default(),
             fee_map: MemoryMap::default(),
             transition_store,
         })
@@ -481,6 +553,11 @@ impl<N: Network> DeploymentStorage<N> for DeploymentMemory<N> {
         &self.certificate_map
     }
 
+    /// Returns the owner map.
+    fn owner_map(&self) -> &Self::OwnerMap {
+        &self.owner_map
+    }
+
     /// Returns the fee map.
     fn fee_map(&self) -> &Self::FeeMap {
         &self.fee_map
@@ -606,6 +683,15 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
     pub fn find_transaction_id(&self, program_id: &ProgramID<N>) -> Result<Option<N::TransactionID>> {
         self.storage.find_transaction_id(program_id)
     }
+
+    /// Returns the transaction ID that deployed the given edition of the `program ID`.
+    pub fn find_transaction_id_for_edition(
+        &self,
+        program_id: &ProgramID<N>,
+        edition: u16,
+    ) -> Result<Option<N::TransactionID>> {
+        self.storage.find_transaction_id_for_edition(program_id, edition)
+    }
 }
 
 impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
@@ -623,10 +709,7 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
 
     /// Returns an iterator over the program IDs, for all deployments.
     pub fn program_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, ProgramID<N>>> {
-        self.storage.id_map().values().map(|id| match id {
-            Cow::Borrowed(id) => Cow::Borrowed(id),
-            Cow::Owned(id) => Cow::Owned(id),
-        })
+        self.storage.edition_map().keys()
     }
 
     /// Returns an iterator over the programs, for all deployments.
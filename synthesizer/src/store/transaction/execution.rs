@@ -46,6 +46,8 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
     type InclusionMap: for<'a> Map<'a, N::TransactionID, (N::StateRoot, Option<Proof<N>>)>;
     /// The mapping of `transaction ID` to `(global state root, (optional) inclusion proof)`.
     type FeeMap: for<'a> Map<'a, N::TransactionID, (N::StateRoot, Option<Proof<N>>)>;
+    /// The mapping of `transaction ID` to the `[transition]` of a rejected execution.
+    type RejectedMap: for<'a> Map<'a, N::TransactionID, Vec<Transition<N>>>;
 
     /// Initializes the execution storage.
     fn open(transition_store: TransitionStore<N, Self::TransitionStorage>) -> Result<Self>;
@@ -60,6 +62,8 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
     fn inclusion_map(&self) -> &Self::InclusionMap;
     /// Returns the fee map.
     fn fee_map(&self) -> &Self::FeeMap;
+    /// Returns the rejected map.
+    fn rejected_map(&self) -> &Self::RejectedMap;
 
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16> {
@@ -73,6 +77,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.transition_store().start_atomic();
         self.inclusion_map().start_atomic();
         self.fee_map().start_atomic();
+        self.rejected_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -82,6 +87,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
             || self.transition_store().is_atomic_in_progress()
             || self.inclusion_map().is_atomic_in_progress()
             || self.fee_map().is_atomic_in_progress()
+            || self.rejected_map().is_atomic_in_progress()
     }
 
     /// Aborts an atomic batch write operation.
@@ -91,6 +97,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.transition_store().abort_atomic();
         self.inclusion_map().abort_atomic();
         self.fee_map().abort_atomic();
+        self.rejected_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
@@ -99,7 +106,8 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.reverse_id_map().finish_atomic()?;
         self.transition_store().finish_atomic()?;
         self.inclusion_map().finish_atomic()?;
-        self.fee_map().finish_atomic()
+        self.fee_map().finish_atomic()?;
+        self.rejected_map().finish_atomic()
     }
 
     /// Stores the given `execution transaction` pair into storage.
@@ -167,6 +175,8 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
             Some(ids) => cow_to_cloned!(ids),
             None => bail!("Failed to get the transition IDs for the transaction '{transaction_id}'"),
         };
+        // Determine if the execution was rejected.
+        let is_rejected = self.rejected_map().get(transaction_id)?.is_some();
 
         atomic_write_batch!(self, {
             // Remove the transition IDs.
@@ -176,8 +186,14 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
             for transition_id in transition_ids {
                 // Remove the transition ID.
                 self.reverse_id_map().remove(&transition_id)?;
-                // Remove the transition.
-                self.transition_store().remove(&transition_id)?;
+                // Remove the transition, if it was not excluded from the transition store.
+                if !is_rejected {
+                    self.transition_store().remove(&transition_id)?;
+                }
+            }
+            // Remove the transitions of the rejected execution.
+            if is_rejected {
+                self.rejected_map().remove(transaction_id)?;
             }
 
             // Remove the global state root and inclusion proof.
@@ -199,6 +215,43 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         Ok(())
     }
 
+    /// Excludes the transitions of the execution for the given `transaction ID` from the transition store,
+    /// as the execution was rejected. The transitions are kept in the rejected map, so the transaction
+    /// can still be retrieved. The additional fee, if one exists, remains in the transition store.
+    fn reject(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        // Retrieve the transition IDs.
+        let (transition_ids, _) = match self.id_map().get_speculative(transaction_id)? {
+            Some(ids) => cow_to_cloned!(ids),
+            None => bail!("Failed to get the transition IDs for the transaction '{transaction_id}'"),
+        };
+        // Ensure the execution was not already rejected.
+        if self.rejected_map().get_speculative(transaction_id)?.is_some() {
+            bail!("Execution '{transaction_id}' was already rejected")
+        }
+
+        // Retrieve the transitions.
+        let transitions = transition_ids
+            .iter()
+            .map(|transition_id| match self.transition_store().get_transition(transition_id)? {
+                Some(transition) => Ok(transition),
+                None => bail!("Failed to get transition '{transition_id}' for transaction '{transaction_id}'"),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        atomic_write_batch!(self, {
+            // Remove the transitions from the transition store.
+            for transition_id in &transition_ids {
+                self.transition_store().remove(transition_id)?;
+            }
+            // Store the transitions of the rejected execution.
+            self.rejected_map().insert(*transaction_id, transitions)?;
+
+            Ok(())
+        });
+
+        Ok(())
+    }
+
     /// Returns the transaction ID that contains the given `transition ID`.
     fn find_transaction_id(&self, transition_id: &N::TransitionID) -> Result<Option<N::TransactionID>> {
         match self.reverse_id_map().get(transition_id)? {
@@ -207,6 +260,31 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         }
     }
 
+    /// Returns the transitions for the given `transaction ID` and `[transition ID]`.
+    /// The transitions of a rejected execution are retrieved from the rejected map.
+    fn get_transitions(
+        &self,
+        transaction_id: &N::TransactionID,
+        transition_ids: &[N::TransitionID],
+    ) -> Result<Vec<Transition<N>>> {
+        // Retrieve the transitions of a rejected execution.
+        if let Some(transitions) = self.rejected_map().get(transaction_id)? {
+            return Ok(cow_to_cloned!(transitions));
+        }
+
+        // Initialize a vector for the transitions.
+        let mut transitions = Vec::new();
+
+        // Retrieve the transitions.
+        for transition_id in transition_ids {
+            match self.transition_store().get_transition(transition_id)? {
+                Some(transition) => transitions.push(transition),
+                None => bail!("Failed to get transition '{transition_id}' for transaction '{transaction_id}'"),
+            };
+        }
+        Ok(transitions)
+    }
+
     /// Returns the execution for the given `transaction ID`.
     fn get_execution(&self, transaction_id: &N::TransactionID) -> Result<Option<Execution<N>>> {
         // Retrieve the transition IDs and optional additional fee ID.
@@ -221,16 +299,8 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
             None => bail!("Failed to get the inclusion proof for the transaction '{transaction_id}'"),
         };
 
-        // Initialize a vector for the transitions.
-        let mut transitions = Vec::new();
-
         // Retrieve the transitions.
-        for transition_id in &transition_ids {
-            match self.transition_store().get_transition(transition_id)? {
-                Some(transition) => transitions.push(transition),
-                None => bail!("Failed to get transition '{transition_id}' for transaction '{transaction_id}'"),
-            };
-        }
+        let transitions = self.get_transitions(transaction_id, &transition_ids)?;
 
         // Return the execution.
         Ok(Some(Execution::from(transitions.into_iter(), global_state_root, inclusion_proof)?))
@@ -250,16 +320,8 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
             None => bail!("Failed to get the inclusion proof for the transaction '{transaction_id}'"),
         };
 
-        // Initialize a vector for the transitions.
-        let mut transitions = Vec::new();
-
         // Retrieve the transitions.
-        for transition_id in &transition_ids {
-            match self.transition_store().get_transition(transition_id)? {
-                Some(transition) => transitions.push(transition),
-                None => bail!("Failed to get transition '{transition_id}' for transaction '{transaction_id}'"),
-            };
-        }
+        let transitions = self.get_transitions(transaction_id, &transition_ids)?;
 
         // Construct the execution.
         let execution = Execution::from(transitions.into_iter(), global_state_root, inclusion_proof)?;
@@ -308,6 +370,8 @@ pub struct ExecutionMemory<N: Network> {
     inclusion_map: MemoryMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>,
     /// The fee map.
     fee_map: MemoryMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>,
+    /// The rejected map.
+    rejected_map: MemoryMap<N::TransactionID, Vec<Transition<N>>>,
 }
 
 #[rustfmt::skip]
@@ -317,6 +381,7 @@ impl<N: Network> ExecutionStorage<N> for ExecutionMemory<N> {
     type TransitionStorage = TransitionMemory<N>;
     type InclusionMap = MemoryMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>;
     type FeeMap = MemoryMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>;
+    type RejectedMap = MemoryMap<N::TransactionID, Vec<Transition<N>>>;
 
     /// Initializes the execution storage.
     fn open(transition_store: TransitionStore<N, Self::TransitionStorage>) -> Result<Self> {
@@ -326,6 +391,7 @@ impl<N: Network> ExecutionStorage<N> for ExecutionMemory<N> {
             transition_store,
             inclusion_map: MemoryMap::default(),
             fee_map: MemoryMap::default(),
+            rejected_map: MemoryMap::default(),
         })
     }
 
@@ -353,6 +419,11 @@ impl<N: Network> ExecutionStorage<N> for ExecutionMemory<N> {
     fn fee_map(&self) -> &Self::FeeMap {
         &self.fee_map
     }
+
+    /// Returns the rejected map.
+    fn rejected_map(&self) -> &Self::RejectedMap {
+        &self.rejected_map
+    }
 }
 
 /// The execution store.
@@ -388,6 +459,11 @@ impl<N: Network, E: ExecutionStorage<N>> ExecutionStore<N, E> {
         self.storage.remove(transaction_id)
     }
 
+    /// Excludes the transitions of the rejected execution for the given `transaction ID` from the transition store.
+    pub fn reject(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        self.storage.reject(transaction_id)
+    }
+
     /// Returns the transition store.
     pub fn transition_store(&self) -> &TransitionStore<N, E::TransitionStorage> {
         self.storage.transition_store()
@@ -51,10 +51,31 @@ pub enum TransactionType {
     Execute,
 }
 
+/// The finalize status of a transaction.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TransactionStatus {
+    /// A transaction that was finalized successfully.
+    Accepted,
+    /// A transaction that failed to finalize, and whose finalize writes were rolled back.
+    Rejected,
+}
+
+impl Display for TransactionStatus {
+    /// Prints the transaction status as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Accepted => write!(f, "accepted"),
+            Self::Rejected => write!(f, "rejected"),
+        }
+    }
+}
+
 /// A trait for transaction storage.
 pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// The mapping of `transaction ID` to `transaction type`.
     type IDMap: for<'a> Map<'a, N::TransactionID, TransactionType>;
+    /// The mapping of `transaction ID` to `transaction status`.
+    type StatusMap: for<'a> Map<'a, N::TransactionID, TransactionStatus>;
     /// The deployment storage.
     type DeploymentStorage: DeploymentStorage<N, TransitionStorage = Self::TransitionStorage>;
     /// The execution storage.
@@ -67,6 +88,8 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
 
     /// Returns the ID map.
     fn id_map(&self) -> &Self::IDMap;
+    /// Returns the status map.
+    fn status_map(&self) -> &Self::StatusMap;
     /// Returns the deployment store.
     fn deployment_store(&self) -> &DeploymentStore<N, Self::DeploymentStorage>;
     /// Returns the execution store.
@@ -85,6 +108,7 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// Starts an atomic batch write operation.
     fn start_atomic(&self) {
         self.id_map().start_atomic();
+        self.status_map().start_atomic();
         self.deployment_store().start_atomic();
         self.execution_store().start_atomic();
     }
@@ -92,6 +116,7 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// Checks if an atomic batch is in progress.
     fn is_atomic_in_progress(&self) -> bool {
         self.id_map().is_atomic_in_progress()
+            || self.status_map().is_atomic_in_progress()
             || self.deployment_store().is_atomic_in_progress()
             || self.execution_store().is_atomic_in_progress()
     }
@@ -99,6 +124,7 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// Aborts an atomic batch write operation.
     fn abort_atomic(&self) {
         self.id_map().abort_atomic();
+        self.status_map().abort_atomic();
         self.deployment_store().abort_atomic();
         self.execution_store().abort_atomic();
     }
@@ -106,6 +132,7 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// Finishes an atomic batch write operation.
     fn finish_atomic(&self) -> Result<()> {
         self.id_map().finish_atomic()?;
+        self.status_map().finish_atomic()?;
         self.deployment_store().finish_atomic()?;
         self.execution_store().finish_atomic()
     }
@@ -145,6 +172,8 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
         atomic_write_batch!(self, {
             // Remove the transaction type.
             self.id_map().remove(transaction_id)?;
+            // Remove the transaction status.
+            self.status_map().remove(transaction_id)?;
             // Remove the transaction.
             match transaction_type {
                 // Remove the deployment transaction.
@@ -159,6 +188,40 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
         Ok(())
     }
 
+    /// Stores the given finalize `status` for the given `transaction ID`.
+    /// If the transaction is a rejected execution, its transitions are excluded from the transition store,
+    /// so that only its additional fee takes effect.
+    fn insert_status(&self, transaction_id: &N::TransactionID, status: TransactionStatus) -> Result<()> {
+        // Ensure the transaction does not already have a status.
+        if self.status_map().get_speculative(transaction_id)?.is_some() {
+            bail!("Transaction '{transaction_id}' already has a finalize status")
+        }
+        // Retrieve the transaction type, if the transaction is in storage.
+        let transaction_type =
+            self.id_map().get_speculative(transaction_id)?.map(|transaction_type| cow_to_copied!(transaction_type));
+
+        atomic_write_batch!(self, {
+            // Store the transaction status.
+            self.status_map().insert(*transaction_id, status)?;
+            // Exclude the transitions of a rejected execution.
+            if (status, transaction_type) == (TransactionStatus::Rejected, Some(TransactionType::Execute)) {
+                self.execution_store().reject(transaction_id)?;
+            }
+
+            Ok(())
+        });
+
+        Ok(())
+    }
+
+    /// Returns the finalize status for the given `transaction ID`.
+    fn get_status(&self, transaction_id: &N::TransactionID) -> Result<Option<TransactionStatus>> {
+        match self.status_map().get_speculative(transaction_id)? {
+            Some(status) => Ok(Some(cow_to_copied!(status))),
+            None => Ok(None),
+        }
+    }
+
     /// Returns the transaction ID that contains the given `transition ID`.
     fn find_transaction_id(&self, transition_id: &N::TransitionID) -> Result<Option<N::TransactionID>> {
         self.execution_store().find_transaction_id(transition_id)
@@ -191,6 +254,8 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
 pub struct TransactionMemory<N: Network> {
     /// The mapping of `transaction ID` to `transaction type`.
     id_map: MemoryMap<N::TransactionID, TransactionType>,
+    /// The mapping of `transaction ID` to `transaction status`.
+    status_map: MemoryMap<N::TransactionID, TransactionStatus>,
     /// The deployment store.
     deployment_store: DeploymentStore<N, DeploymentMemory<N>>,
     /// The execution store.
@@ -200,6 +265,7 @@ pub struct TransactionMemory<N: Network> {
 #[rustfmt::skip]
 impl<N: Network> TransactionStorage<N> for TransactionMemory<N> {
     type IDMap = MemoryMap<N::TransactionID, TransactionType>;
+    type StatusMap = MemoryMap<N::TransactionID, TransactionStatus>;
     type DeploymentStorage = DeploymentMemory<N>;
     type ExecutionStorage = ExecutionMemory<N>;
     type TransitionStorage = TransitionMemory<N>;
@@ -211,7 +277,7 @@ impl<N: Network> TransactionStorage<N> for TransactionMemory<N> {
         // Initialize the execution store.
         let execution_store = ExecutionStore::<N, ExecutionMemory<N>>::open(transition_store)?;
         // Return the transaction storage.
-        Ok(Self { id_map: MemoryMap::default(), deployment_store, execution_store })
+        Ok(Self { id_map: MemoryMap::default(), status_map: MemoryMap::default(), deployment_store, execution_store })
     }
 
     /// Returns the ID map.
@@ -219,6 +285,11 @@ impl<N: Network> TransactionStorage<N> for TransactionMemory<N> {
         &self.id_map
     }
 
+    /// Returns the status map.
+    fn status_map(&self) -> &Self::StatusMap {
+        &self.status_map
+    }
+
     /// Returns the deployment store.
     fn deployment_store(&self) -> &DeploymentStore<N, Self::DeploymentStorage> {
         &self.deployment_store
@@ -263,6 +334,11 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         self.storage.remove(transaction_id)
     }
 
+    /// Stores the given finalize `status` for the given `transaction ID`.
+    pub fn insert_status(&self, transaction_id: &N::TransactionID, status: TransactionStatus) -> Result<()> {
+        self.storage.insert_status(transaction_id, status)
+    }
+
     /// Returns the transition store.
     pub fn transition_store(&self) -> &TransitionStore<N, T::TransitionStorage> {
         self.storage.transition_store()
@@ -300,6 +376,11 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         self.storage.get_transaction(transaction_id)
     }
 
+    /// Returns the finalize status for the given `transaction ID`.
+    pub fn get_status(&self, transaction_id: &N::TransactionID) -> Result<Option<TransactionStatus>> {
+        self.storage.get_status(transaction_id)
+    }
+
     /// Returns the deployment for the given `transaction ID`.
     pub fn get_deployment(&self, transaction_id: &N::TransactionID) -> Result<Option<Deployment<N>>> {
         // Retrieve the transaction type.
@@ -390,6 +471,15 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         self.storage.deployment_store().find_transaction_id(program_id)
     }
 
+    /// Returns the transaction ID that deployed the given edition of the `program ID`.
+    pub fn find_deployment_id_for_edition(
+        &self,
+        program_id: &ProgramID<N>,
+        edition: u16,
+    ) -> Result<Option<N::TransactionID>> {
+        self.storage.deployment_store().find_transaction_id_for_edition(program_id, edition)
+    }
+
     /// Returns the transaction ID that contains the given `transition ID`.
     pub fn find_transaction_id(&self, transition_id: &N::TransitionID) -> Result<Option<N::TransactionID>> {
         self.storage.execution_store().find_transaction_id(transition_id)
@@ -453,6 +543,32 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
 mod tests {
     use super::*;
 
+    /// Checks `insert`, `get_transaction`, and `remove` on the given transaction store.
+    fn check_insert_get_remove<N: Network, T: TransactionStorage<N>>(
+        transaction_store: TransactionStore<N, T>,
+        transaction: Transaction<N>,
+    ) {
+        let transaction_id = transaction.id();
+
+        // Ensure the transaction does not exist.
+        let candidate = transaction_store.get_transaction(&transaction_id).unwrap();
+        assert_eq!(None, candidate);
+
+        // Insert the transaction.
+        transaction_store.insert(&transaction).unwrap();
+
+        // Retrieve the transaction.
+        let candidate = transaction_store.get_transaction(&transaction_id).unwrap();
+        assert_eq!(Some(transaction), candidate);
+
+        // Remove the transaction.
+        transaction_store.remove(&transaction_id).unwrap();
+
+        // Ensure the transaction does not exist.
+        let candidate = transaction_store.get_transaction(&transaction_id).unwrap();
+        assert_eq!(None, candidate);
+    }
+
     #[test]
     fn test_insert_get_remove() {
         let rng = &mut TestRng::default();
@@ -462,31 +578,77 @@ mod tests {
             crate::vm::test_helpers::sample_deployment_transaction(rng),
             crate::vm::test_helpers::sample_execution_transaction(rng),
         ] {
-            let transaction_id = transaction.id();
-
             // Initialize a new transition store.
             let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
             // Initialize a new transaction store.
             let transaction_store = TransactionStore::<_, TransactionMemory<_>>::open(transition_store).unwrap();
+            // Check the operations.
+            check_insert_get_remove(transaction_store, transaction);
+        }
+    }
 
-            // Ensure the transaction does not exist.
-            let candidate = transaction_store.get_transaction(&transaction_id).unwrap();
-            assert_eq!(None, candidate);
+    #[test]
+    #[cfg(feature = "rocks")]
+    fn test_insert_get_remove_rocksdb() {
+        use crate::store::helpers::rocksdb::{sample_dev, TransactionDB, TransitionDB};
 
-            // Insert the transaction.
-            transaction_store.insert(&transaction).unwrap();
+        let rng = &mut TestRng::default();
+
+        // Sample the transactions.
+        for transaction in [
+            crate::vm::test_helpers::sample_deployment_transaction(rng),
+            crate::vm::test_helpers::sample_execution_transaction(rng),
+        ] {
+            // Initialize a new transition store.
+            let transition_store = TransitionStore::<_, TransitionDB<_>>::open(sample_dev()).unwrap();
+            // Initialize a new transaction store.
+            let transaction_store = TransactionStore::<_, TransactionDB<_>>::open(transition_store).unwrap();
+            // Check the operations.
+            check_insert_get_remove(transaction_store, transaction);
+        }
+    }
 
-            // Retrieve the transaction.
-            let candidate = transaction_store.get_transaction(&transaction_id).unwrap();
-            assert_eq!(Some(transaction), candidate);
+    #[test]
+    fn test_insert_status() {
+        let rng = &mut TestRng::default();
 
-            // Remove the transaction.
-            transaction_store.remove(&transaction_id).unwrap();
+        // Sample the execution transaction.
+        let transaction = crate::vm::test_helpers::sample_execution_transaction(rng);
+        let transaction_id = transaction.id();
+        let transition_ids = transaction.transition_ids().copied().collect::<Vec<_>>();
 
-            // Ensure the transaction does not exist.
-            let candidate = transaction_store.get_transaction(&transaction_id).unwrap();
-            assert_eq!(None, candidate);
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
+        // Initialize a new transaction store.
+        let transaction_store = TransactionStore::<_, TransactionMemory<_>>::open(transition_store).unwrap();
+
+        // Insert the transaction.
+        transaction_store.insert(&transaction).unwrap();
+        // Ensure the transitions are in the transition store.
+        for transition_id in &transition_ids {
+            assert!(transaction_store.transition_store().contains_transition_id(transition_id).unwrap());
         }
+
+        // Reject the transaction.
+        transaction_store.insert_status(&transaction_id, TransactionStatus::Rejected).unwrap();
+        assert_eq!(transaction_store.get_status(&transaction_id).unwrap(), Some(TransactionStatus::Rejected));
+        // Ensure the transitions of the rejected execution are excluded from the transition store.
+        for transition_id in &transition_ids {
+            assert!(!transaction_store.transition_store().contains_transition_id(transition_id).unwrap());
+        }
+        // Ensure the transaction can still be retrieved.
+        let candidate = transaction_store.get_transaction(&transaction_id).unwrap();
+        assert_eq!(Some(transaction), candidate);
+
+        // Ensure the status cannot be overwritten.
+        assert!(transaction_store.insert_status(&transaction_id, TransactionStatus::Accepted).is_err());
+        assert_eq!(transaction_store.get_status(&transaction_id).unwrap(), Some(TransactionStatus::Rejected));
+
+        // Remove the transaction.
+        transaction_store.remove(&transaction_id).unwrap();
+        // Ensure the transaction and its status do not exist.
+        assert_eq!(None, transaction_store.get_transaction(&transaction_id).unwrap());
+        assert_eq!(None, transaction_store.get_status(&transaction_id).unwrap());
     }
 
     #[test]
@@ -795,9 +795,12 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use console::network::Testnet3;
 
-    #[test]
-    fn test_insert_get_remove() {
+    type CurrentNetwork = Testnet3;
+
+    /// Samples the transitions to check.
+    fn sample_transitions() -> Vec<Transition<CurrentNetwork>> {
         let rng = &mut TestRng::default();
 
         // Sample the transitions.
@@ -812,9 +815,14 @@ mod tests {
         println!("\n\nNumber of transitions: {}\n", transitions.len());
         assert!(transitions.len() > 1, "\n\nNumber of transitions: {}\n", transitions.len());
 
-        // Initialize a new transition store.
-        let transition_store = TransitionMemory::open(None).unwrap();
+        transitions
+    }
 
+    /// Checks `insert`, `get`, and `remove` on the given transition storage.
+    fn check_insert_get_remove<N: Network, T: TransitionStorage<N>>(
+        transition_store: T,
+        transitions: Vec<Transition<N>>,
+    ) {
         // Test each transition in isolation.
         for transition in transitions.iter() {
             // Retrieve the transition ID.
@@ -879,4 +887,23 @@ mod tests {
             assert_eq!(None, candidate);
         }
     }
+
+    #[test]
+    fn test_insert_get_remove() {
+        // Initialize a new transition store.
+        let transition_store = TransitionMemory::open(None).unwrap();
+        // Check the operations.
+        check_insert_get_remove(transition_store, sample_transitions());
+    }
+
+    #[test]
+    #[cfg(feature = "rocks")]
+    fn test_insert_get_remove_rocksdb() {
+        use crate::store::helpers::rocksdb::{sample_dev, TransitionDB};
+
+        // Initialize a new transition store.
+        let transition_store = TransitionDB::open(sample_dev()).unwrap();
+        // Check the operations.
+        check_insert_get_remove(transition_store, sample_transitions());
+    }
 }
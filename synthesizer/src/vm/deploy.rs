@@ -44,4 +44,32 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // Process the logic.
         process!(self, logic)
     }
+
+    /// Deploys the next edition of the program with the given program ID.
+    #[inline]
+    pub fn upgrade<R: Rng + CryptoRng>(&self, program: &Program<N>, rng: &mut R) -> Result<Deployment<N>> {
+        let timer = timer!("VM::upgrade");
+
+        // Compute the core logic.
+        macro_rules! logic {
+            ($process:expr, $network:path, $aleo:path) => {{
+                // Prepare the program.
+                let program = cast_ref!(&program as Program<$network>);
+
+                // Compute the deployment.
+                let deployment = $process.upgrade::<$aleo, _>(program, rng)?;
+                lap!(timer, "Compute the deployment");
+
+                // Prepare the return.
+                let deployment = cast_ref!(deployment as Deployment<N>).clone();
+                lap!(timer, "Prepare the deployment");
+
+                finish!(timer);
+                // Return the deployment.
+                Ok(deployment)
+            }};
+        }
+        // Process the logic.
+        process!(self, logic)
+    }
 }
@@ -140,4 +140,12 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // Process the logic.
         process!(self, logic)
     }
+
+    /// Returns the estimated cost (in gates) of executing the given authorization.
+    /// Note: This does not include the byte cost of the execution, which is only known once the execution is
+    /// proven, nor the storage cost of the mapping writes, which is metered in finalize.
+    #[inline]
+    pub fn estimate_cost(&self, authorization: &Authorization<N>) -> Result<u64> {
+        self.process.read().authorization_cost(authorization)
+    }
 }
@@ -17,42 +17,146 @@
 use super::*;
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
-    /// Finalizes the given transactions into the VM.
+    /// Finalizes the given transactions into the VM, with the given finalize global state.
+    ///
+    /// Each transaction is finalized independently. If a transaction fails to finalize,
+    /// or its fee does not cover the storage cost of its mapping writes,
+    /// its writes to the program store and the process are rolled back and it is marked as rejected.
+    /// A rejected transaction remains in its block, so its fee is still charged. However, the transitions
+    /// of a rejected execution, other than its fee, are excluded from the transition store.
+    /// The status of each transaction is stored in the transaction store.
+    ///
+    /// The new program stacks are staged, and only added to the process if all writes are committed.
+    ///
     /// This method assumes the given transactions **are valid**.
     #[inline]
-    pub fn finalize(&self, transactions: &Transactions<N>) -> Result<()> {
-        let timer = timer!("VM::finalize");
-        atomic_write_batch!(self, {
-            // Acquire the write lock on the process.
-            let mut process = self.process.write();
-
-            for transaction in transactions.values() {
-                // Finalize the transaction.
-                match transaction {
-                    Transaction::Deploy(_, deployment, _) => {
-                        process.finalize_deployment(self.program_store(), deployment)?;
-                        lap!(timer, "Finalize deployment");
-                    }
-                    Transaction::Execute(_, execution, _) => {
-                        process.finalize_execution(self.program_store(), execution)?;
-                        lap!(timer, "Finalize execution");
-                    }
+    pub fn finalize(&self, state: FinalizeGlobalState, transactions: &Transactions<N>) -> Result<()> {
+        self.atomic_finalize(state, transactions, None)
+    }
+
+    /// Finalizes the given transactions into the VM, with the given finalize global state.
+    /// If a finalize root is given, the writes are only committed if they result in the given finalize root.
+    ///
+    /// This method assumes the given transactions **are valid**.
+    #[inline]
+    pub(super) fn atomic_finalize(
+        &self,
+        state: FinalizeGlobalState,
+        transactions: &Transactions<N>,
+        finalize_root: Option<Field<N>>,
+    ) -> Result<()> {
+        let timer = timer!("VM::atomic_finalize");
+
+        // Acquire the write lock on the process.
+        let mut process = self.process.write();
+
+        // Start an atomic batch on the storage, and stage the new program stacks in the process.
+        self.start_atomic();
+        process.start_atomic();
+
+        // Finalize the transactions, ensure the finalize root matches, and commit the atomic batch.
+        let result = self
+            .finalize_transactions(&mut process, state, transactions)
+            .and_then(|()| match finalize_root {
+                Some(expected_root) => {
+                    // Note: The finalize root is read speculatively, so it includes the atomic batch.
+                    let finalize_root = self.program_store().get_finalize_root()?;
+                    ensure!(
+                        finalize_root == expected_root,
+                        "The finalize root '{finalize_root}' does not match the expected root '{expected_root}'"
+                    );
+                    Ok(())
                 }
+                None => Ok(()),
+            })
+            .and_then(|()| self.finish_atomic());
+        // Add the staged program stacks to the process, or discard all writes if finalize failed.
+        match &result {
+            Ok(()) => process.finish_atomic(),
+            Err(_) => {
+                self.abort_atomic();
+                process.abort_atomic();
             }
-            Ok(())
-        });
+        }
 
         finish!(timer);
 
+        result
+    }
+
+    /// Finalizes the given transactions into the given process and the VM storage.
+    /// This method assumes an atomic batch is in progress on the storage and the process.
+    #[inline]
+    fn finalize_transactions(
+        &self,
+        process: &mut Process<N>,
+        state: FinalizeGlobalState,
+        transactions: &Transactions<N>,
+    ) -> Result<()> {
+        for transaction in transactions.values() {
+            // Checkpoint the program store and the process, so the writes of this transaction can be rolled back.
+            self.program_store().atomic_checkpoint();
+            process.atomic_checkpoint();
+
+            // Finalize the transaction.
+            let result = Self::finalize_transaction(process, state, self.program_store(), transaction);
+
+            // Determine the status of the transaction.
+            let status = match result {
+                Ok(()) => {
+                    // Keep the writes of the transaction.
+                    self.program_store().clear_latest_checkpoint();
+                    process.clear_latest_checkpoint();
+                    TransactionStatus::Accepted
+                }
+                Err(error) => {
+                    warn!("Rejected transaction '{}' during finalize: {error}", transaction.id());
+                    // Roll back the writes of the transaction.
+                    self.program_store().atomic_rewind();
+                    process.atomic_rewind();
+                    TransactionStatus::Rejected
+                }
+            };
+
+            // Store the status of the transaction.
+            self.transaction_store().insert_status(&transaction.id(), status)?;
+        }
         Ok(())
     }
+
+    /// Finalizes the given transaction into the given process and program store.
+    /// This method assumes the given transaction **is valid**.
+    #[inline]
+    pub(crate) fn finalize_transaction<P: ProgramStorage<N>>(
+        process: &mut Process<N>,
+        state: FinalizeGlobalState,
+        store: &ProgramStore<N, P>,
+        transaction: &Transaction<N>,
+    ) -> Result<()> {
+        match transaction {
+            Transaction::Deploy(_, deployment, _) => process.finalize_deployment(store, deployment),
+            Transaction::Execute(_, execution, additional_fee) => {
+                process.finalize_execution(state, store, execution).and_then(|storage_cost| {
+                    // Ensure the fee covers the cost of the execution, including the storage cost.
+                    let cost = process
+                        .execution_cost(execution, additional_fee.as_ref())?
+                        .checked_add(storage_cost)
+                        .ok_or_else(|| anyhow!("The cost of the execution overflowed"))?;
+                    Self::check_fee_covers_cost(transaction, cost)
+                })
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use console::account::{Address, ViewKey};
     use snarkvm_utilities::TestRng;
 
+    type CurrentNetwork = crate::vm::test_helpers::CurrentNetwork;
+
     #[test]
     fn test_finalize() {
         let rng = &mut TestRng::default();
@@ -63,9 +167,103 @@ mod tests {
         let deployment_transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
 
         // Finalize the transaction.
-        vm.finalize(&Transactions::from(&[deployment_transaction.clone()])).unwrap();
+        vm.finalize(FinalizeGlobalState::new(1, 0), &Transactions::from(&[deployment_transaction.clone()])).unwrap();
+        // Ensure the transaction is accepted.
+        let status = vm.transaction_store().get_status(&deployment_transaction.id()).unwrap();
+        assert_eq!(status, Some(TransactionStatus::Accepted));
+
+        // Ensure the VM refuses to finalize the same transaction again, and keeps its status.
+        let transactions = Transactions::from(&[deployment_transaction.clone()]);
+        assert!(vm.finalize(FinalizeGlobalState::new(1, 0), &transactions).is_err());
+        let status = vm.transaction_store().get_status(&deployment_transaction.id()).unwrap();
+        assert_eq!(status, Some(TransactionStatus::Accepted));
+    }
+
+    #[test]
+    fn test_finalize_rejected_execution() {
+        let rng = &mut TestRng::default();
+
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+        let address = Address::try_from(&caller_private_key).unwrap();
+
+        // Select a record to spend.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+        let record = genesis.records().next().unwrap().1.decrypt(&caller_view_key).unwrap();
+
+        // Authorize a transfer.
+        let inputs = [
+            Value::<CurrentNetwork>::Record(record),
+            Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(),
+            Value::<CurrentNetwork>::from_str("1u64").unwrap(),
+        ];
+        let authorization =
+            vm.authorize(&caller_private_key, "credits.aleo", "transfer", inputs.into_iter(), rng).unwrap();
+
+        // Execute the transfer, without a fee to cover its cost.
+        let transaction = Transaction::execute_authorization(&vm, authorization, None, rng).unwrap();
+        let transition_ids = transaction.transition_ids().copied().collect::<Vec<_>>();
+
+        // Store the transaction, as `VM::add_next_block` does before finalize.
+        vm.transaction_store().insert(&transaction).unwrap();
+
+        // Finalize the transaction.
+        vm.finalize(FinalizeGlobalState::new(1, 0), &Transactions::from(&[transaction.clone()])).unwrap();
+        // Ensure the transaction is rejected, as it does not pay a fee to cover its cost.
+        let status = vm.transaction_store().get_status(&transaction.id()).unwrap();
+        assert_eq!(status, Some(TransactionStatus::Rejected));
+
+        // Ensure the transitions of the rejected execution are excluded from the transition store.
+        for transition_id in &transition_ids {
+            assert!(!vm.transition_store().contains_transition_id(transition_id).unwrap());
+        }
+        // Ensure the transaction can still be retrieved.
+        assert_eq!(vm.transaction_store().get_transaction(&transaction.id()).unwrap(), Some(transaction));
+    }
+
+    #[test]
+    fn test_add_next_block_with_invalid_finalize_root() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Prepare the next block, with a mint.
+        let inputs = [caller.to_string(), "1_000_000_000_u64".to_string()];
+        let authorization = vm.authorize(&caller_private_key, "credits.aleo", "mint", inputs, rng).unwrap();
+        let transaction = Transaction::execute_authorization(&vm, authorization, None, rng).unwrap();
+        let block = crate::vm::test_helpers::sample_next_block(&vm, &caller_private_key, &[transaction], rng).unwrap();
+
+        // Re-sign the block, with a header that commits to an invalid finalize root.
+        let header = crate::block::Header::from(
+            block.previous_state_root(),
+            block.transactions_root(),
+            Field::one(),
+            block.header().coinbase_accumulator_point(),
+            *block.metadata(),
+        )
+        .unwrap();
+        let transactions = block.transactions().clone();
+        let invalid_block =
+            Block::new(&caller_private_key, block.previous_hash(), header, transactions, None, rng).unwrap();
+
+        // Ensure the block is rejected, and rolled back.
+        assert!(vm.add_next_block(&invalid_block).is_err());
+        assert_eq!(vm.block_store().current_block_height().unwrap(), Some(0));
+        assert!(!vm.is_atomic_in_progress());
+        for transaction_id in block.transaction_ids() {
+            assert!(vm.transaction_store().get_status(transaction_id).unwrap().is_none());
+        }
 
-        // Ensure the VM can't redeploy the same transaction.
-        assert!(vm.finalize(&Transactions::from(&[deployment_transaction])).is_err());
+        // Ensure the valid block is accepted.
+        vm.add_next_block(&block).unwrap();
+        assert_eq!(vm.block_store().current_block_height().unwrap(), Some(1));
     }
 }
@@ -56,4 +56,20 @@ macro_rules! process {
             _ => Err(anyhow!("Unsupported VM configuration for network: {}", N::ID)),
         }
     }};
+    // Example: process!(&process, logic)
+    (&$process:ident, $logic:ident) => {{
+        // Process the logic, with the given process.
+        match N::ID {
+            console::network::Testnet3::ID => {
+                // Cast the process.
+                let process = ($process as &dyn std::any::Any)
+                    .downcast_ref::<Process<console::network::Testnet3>>()
+                    .ok_or_else(|| anyhow!("Failed to downcast {}", stringify!($process)))
+                    .unwrap();
+
+                $logic!(process, console::network::Testnet3, circuit::AleoV0)
+            }
+            _ => Err(anyhow!("Unsupported VM configuration for network: {}", N::ID)),
+        }
+    }};
 }
@@ -20,16 +20,40 @@ mod authorize;
 mod deploy;
 mod execute;
 mod finalize;
+mod simulate;
+mod speculate;
 mod verify;
 
+pub use simulate::MappingDiff;
+
 use crate::{
     atomic_write_batch,
-    block::{Block, Transaction, Transactions, Transition},
+    block::{Block, Metadata, Transaction, Transactions, Transition},
     cast_ref,
+    coinbase_puzzle::{CoinbasePuzzle, EpochChallenge},
     process,
-    process::{Authorization, Deployment, Execution, Fee, Inclusion, InclusionAssignment, Process, Query},
+    process::{
+        Authorization,
+        Deployment,
+        Execution,
+        Fee,
+        FinalizeGlobalState,
+        Inclusion,
+        InclusionAssignment,
+        Process,
+        Query,
+    },
     program::Program,
-    store::{BlockStore, ConsensusStorage, ConsensusStore, ProgramStore, TransactionStore, TransitionStore},
+    store::{
+        BlockStore,
+        ConsensusStorage,
+        ConsensusStore,
+        ProgramStorage,
+        ProgramStore,
+        TransactionStatus,
+        TransactionStore,
+        TransitionStore,
+    },
     CallMetrics,
 };
 use console::{
@@ -58,16 +82,36 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // Initialize a new process.
         let mut process = Process::load()?;
 
-        // Retrieve the transaction store.
+        // Retrieve the block store and the transaction store.
+        let block_store = store.block_store();
         let transaction_store = store.transaction_store();
-        // Load the deployments from the store.
-        for transaction_id in transaction_store.deployment_ids() {
-            // Retrieve the deployment.
-            match transaction_store.get_deployment(&transaction_id)? {
-                // Load the deployment.
-                Some(deployment) => process.load_deployment(&deployment)?,
-                None => bail!("Deployment transaction '{transaction_id}' is not found in storage."),
+        // Load the deployments from the store, in the order they were finalized,
+        // as a program may only be loaded after the programs it imports.
+        for height in block_store.current_block_height()?.map_or(0..0, |height| 0..height + 1) {
+            // Retrieve the transaction IDs of the block.
+            let transaction_ids = match block_store.get_block_hash(height)? {
+                Some(block_hash) => match block_store.get_block_transaction_ids(&block_hash)? {
+                    Some(transaction_ids) => transaction_ids,
+                    None => bail!("Transactions of block {height} are not found in storage."),
+                },
+                None => bail!("Block {height} is not found in storage."),
             };
+            for transaction_id in transaction_ids {
+                // Skip the transaction, if it is not a deployment.
+                if transaction_store.get_program_id(&transaction_id)?.is_none() {
+                    continue;
+                }
+                // Skip the deployment, if it was rejected during finalize.
+                if transaction_store.get_status(&transaction_id)? == Some(TransactionStatus::Rejected) {
+                    continue;
+                }
+                // Retrieve the deployment.
+                match transaction_store.get_deployment(&transaction_id)? {
+                    // Load the deployment.
+                    Some(deployment) => process.load_deployment(&deployment)?,
+                    None => bail!("Deployment transaction '{transaction_id}' is not found in storage."),
+                };
+            }
         }
 
         // Return the new VM.
@@ -83,10 +127,14 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Adds the given block into the VM.
     #[inline]
     pub fn add_next_block(&self, block: &Block<N>) -> Result<()> {
-        // First, insert the block.
+        // Ensure the block is a valid next block.
+        self.check_next_block(block)?;
+        // Next, insert the block.
         self.block_store().insert(block)?;
-        // Next, finalize the transactions.
-        match self.finalize(block.transactions()) {
+        // Initialize the finalize global state from the block.
+        let state = FinalizeGlobalState::new(block.height(), block.timestamp());
+        // Next, finalize the transactions, and ensure they result in the finalize root of the block.
+        match self.atomic_finalize(state, block.transactions(), Some(block.header().finalize_root())) {
             Ok(_) => Ok(()),
             Err(error) => {
                 // Rollback the block.
@@ -151,12 +199,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 #[cfg(test)]
 pub(crate) mod test_helpers {
     use super::*;
-    use crate::{program::Program, Block, ConsensusMemory, Fee, Inclusion, Transition};
-    use console::{
-        account::{Address, ViewKey},
-        network::Testnet3,
-        program::Value,
-    };
+    use crate::{program::Program, Block, ConsensusMemory, Fee, Header, Inclusion, Transition};
+    use console::{account::ViewKey, network::Testnet3, program::Value};
 
     use indexmap::IndexMap;
     use once_cell::sync::OnceCell;
@@ -203,6 +247,45 @@ pub(crate) mod test_helpers {
         vm
     }
 
+    /// Returns the next block for the given VM, with the given transactions, one second after the latest block.
+    /// Note: The block does not have a coinbase solution, and its finalize root is zero.
+    pub(crate) fn sample_next_block<C: ConsensusStorage<CurrentNetwork>>(
+        vm: &VM<CurrentNetwork, C>,
+        private_key: &PrivateKey<CurrentNetwork>,
+        transactions: &[Transaction<CurrentNetwork>],
+        rng: &mut TestRng,
+    ) -> Result<Block<CurrentNetwork>> {
+        // Retrieve the latest block.
+        let latest_height = vm.block_store().current_block_height()?.unwrap();
+        let latest_hash = vm.block_store().get_block_hash(latest_height)?.unwrap();
+        let latest_header = vm.block_store().get_block_header(&latest_hash)?.unwrap();
+
+        // Construct the metadata, with the retargeted coinbase target and proof target.
+        let timestamp = latest_header.timestamp() + 1;
+        let coinbase_target = latest_header.metadata().next_coinbase_target(timestamp);
+        let metadata = Metadata::new(
+            CurrentNetwork::ID,
+            latest_header.round() + 1,
+            latest_height + 1,
+            coinbase_target,
+            Metadata::<CurrentNetwork>::to_proof_target(coinbase_target),
+            latest_header.last_coinbase_target(),
+            latest_header.last_coinbase_timestamp(),
+            timestamp,
+        )?;
+        // Construct the header.
+        let transactions = Transactions::from(transactions);
+        let header = Header::from(
+            *vm.block_store().current_state_root(),
+            transactions.to_root()?,
+            Field::zero(),
+            Field::zero(),
+            metadata,
+        )?;
+        // Construct the block.
+        Block::new(private_key, latest_hash, header, transactions, None, rng)
+    }
+
     pub(crate) fn sample_program() -> Program<CurrentNetwork> {
         static INSTANCE: OnceCell<Program<CurrentNetwork>> = OnceCell::new();
         INSTANCE
@@ -260,9 +343,8 @@ function compute:
                     genesis.transitions().cloned().flat_map(Transition::into_records).collect::<IndexMap<_, _>>();
                 trace!("Unspent Records:\n{:#?}", records);
 
-                // Prepare the additional fee.
+                // Prepare the credits for the fee.
                 let credits = records.values().next().unwrap().decrypt(&caller_view_key).unwrap();
-                let additional_fee = (credits, 10);
 
                 // Initialize the VM.
                 let vm = sample_vm();
@@ -270,8 +352,12 @@ function compute:
                 vm.add_next_block(&genesis).unwrap();
 
                 // Deploy.
-                let transaction =
-                    Transaction::deploy(&vm, &caller_private_key, &program, additional_fee, None, rng).unwrap();
+                let deployment = vm.deploy(&program, rng).unwrap();
+                // Compute the fee, to cover the cost of the deployment.
+                let cost = |fee: Option<&Fee<_>>| vm.process().read().deployment_cost(&deployment, fee);
+                let fee = sample_fee_for_cost(&vm, &caller_private_key, credits, cost, rng);
+                // Initialize the transaction.
+                let transaction = Transaction::from_deployment(deployment, fee).unwrap();
                 // Verify.
                 assert!(vm.verify(&transaction));
                 // Return the transaction.
@@ -287,7 +373,6 @@ function compute:
                 // Initialize a new caller.
                 let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
                 let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
-                let address = Address::try_from(&caller_private_key).unwrap();
 
                 // Initialize the genesis block.
                 let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
@@ -305,25 +390,35 @@ function compute:
                 // Update the VM.
                 vm.add_next_block(&genesis).unwrap();
 
-                // Authorize.
-                let authorization = vm
-                    .authorize(
-                        &caller_private_key,
-                        "credits.aleo",
-                        "transfer",
-                        [
-                            Value::<CurrentNetwork>::Record(record),
-                            Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(),
-                            Value::<CurrentNetwork>::from_str("1u64").unwrap(),
-                        ]
-                        .into_iter(),
-                        rng,
-                    )
-                    .unwrap();
-                assert_eq!(authorization.len(), 1);
-
-                // Execute.
-                let transaction = Transaction::execute_authorization(&vm, authorization, None, rng).unwrap();
+                // Execute 'credits.aleo/fee' with the given amount.
+                // Note: The genesis block contains a single record, so the execution pays for itself,
+                // instead of paying an additional fee with a second record.
+                let mut execute = |amount: u64| {
+                    // Authorize.
+                    let authorization = vm
+                        .authorize(
+                            &caller_private_key,
+                            "credits.aleo",
+                            "fee",
+                            [
+                                Value::<CurrentNetwork>::Record(record.clone()),
+                                Value::<CurrentNetwork>::from_str(&format!("{amount}u64")).unwrap(),
+                            ]
+                            .into_iter(),
+                            rng,
+                        )
+                        .unwrap();
+                    assert_eq!(authorization.len(), 1);
+                    // Execute.
+                    let (_, execution, _) = vm.execute(authorization, None, rng).unwrap();
+                    execution
+                };
+
+                // Compute the cost of the execution.
+                // Note: The size of the execution does not depend on the amount.
+                let cost = vm.process().read().execution_cost(&execute(1), None).unwrap();
+                // Execute, paying the cost of the execution.
+                let transaction = Transaction::from_execution(execute(cost), None).unwrap();
                 // Verify.
                 assert!(vm.verify(&transaction));
                 // Return the transaction.
@@ -332,6 +427,22 @@ function compute:
             .clone()
     }
 
+    /// Returns a fee, paid with the given credits, that covers the given cost, which includes the cost of the fee.
+    /// Note: The size of a fee does not depend on its amount, so the cost of the fee is computed from a first fee.
+    pub(crate) fn sample_fee_for_cost<C: ConsensusStorage<CurrentNetwork>>(
+        vm: &VM<CurrentNetwork, C>,
+        private_key: &PrivateKey<CurrentNetwork>,
+        credits: Record<CurrentNetwork, Plaintext<CurrentNetwork>>,
+        cost: impl Fn(Option<&Fee<CurrentNetwork>>) -> Result<u64>,
+        rng: &mut TestRng,
+    ) -> Fee<CurrentNetwork> {
+        // Compute a fee for the cost, without the cost of the fee.
+        let (_, fee, _) = vm.execute_fee(private_key, credits.clone(), cost(None).unwrap(), None, rng).unwrap();
+        // Compute the fee for the cost, including the cost of the fee.
+        let (_, fee, _) = vm.execute_fee(private_key, credits, cost(Some(&fee)).unwrap(), None, rng).unwrap();
+        fee
+    }
+
     pub(crate) fn sample_fee() -> Fee<CurrentNetwork> {
         static INSTANCE: OnceCell<Fee<CurrentNetwork>> = OnceCell::new();
         INSTANCE
@@ -0,0 +1,201 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use indexmap::IndexSet;
+
+/// The inputs for finalize of each evaluated function call, as `(program ID, function name, inputs)`.
+type FinalizeCalls<N> = Vec<(ProgramID<N>, Identifier<N>, Vec<Value<N>>)>;
+
+/// A change to a mapping entry, as produced by simulating a transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MappingDiff<N: Network> {
+    /// The program ID.
+    pub program_id: ProgramID<N>,
+    /// The mapping name.
+    pub mapping_name: Identifier<N>,
+    /// The key.
+    pub key: Plaintext<N>,
+    /// The value before the transaction, if the key existed.
+    pub old_value: Option<Value<N>>,
+    /// The value after the transaction, if the key still exists.
+    pub new_value: Option<Value<N>>,
+}
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Simulates a call to the program function for the given authorization, without proving.
+    /// This method evaluates the call, and applies its finalize logic to an in-memory overlay
+    /// over the program store, leaving the program store untouched.
+    ///
+    /// Returns the response, the mapping changes, and the error if finalize failed.
+    /// If finalize fails, no mapping changes are returned, as the transaction would be rejected.
+    #[inline]
+    pub fn simulate(
+        &self,
+        state: FinalizeGlobalState,
+        authorization: Authorization<N>,
+    ) -> Result<(Response<N>, Vec<MappingDiff<N>>, Option<Error>)> {
+        let timer = timer!("VM::simulate");
+
+        // Compute the core logic.
+        macro_rules! logic {
+            ($process:expr, $network:path, $aleo:path) => {{
+                // Prepare the authorization.
+                let authorization = cast_ref!(authorization as Authorization<$network>);
+                lap!(timer, "Prepare the authorization");
+
+                // Evaluate the call.
+                let (response, calls) = $process.evaluate_with_finalize::<$aleo>(authorization.clone())?;
+                lap!(timer, "Evaluate the call");
+
+                // Prepare the return.
+                let response = cast_ref!(response as Response<N>).clone();
+                let calls = cast_ref!(calls as FinalizeCalls<N>).clone();
+
+                Ok::<_, Error>((response, calls))
+            }};
+        }
+        // Process the logic.
+        let (response, calls) = process!(self, logic)?;
+
+        // Acquire the read lock on the process.
+        let process = self.process.read();
+
+        // Initialize an overlay over the program store, which reads through to its pending atomic batch.
+        let overlay = self.program_store().to_overlay();
+        lap!(timer, "Prepare the overlay");
+
+        // Finalize the calls on the overlay, in an atomic batch.
+        overlay.start_atomic();
+        if let Err(error) = process.finalize_evaluation(state, &overlay, &calls) {
+            overlay.abort_atomic();
+            finish!(timer, "Finalize failed");
+            return Ok((response, vec![], Some(error)));
+        }
+        overlay.finish_atomic()?;
+        lap!(timer, "Finalize the calls");
+
+        // Retrieve the called programs, and their imports.
+        let mut program_ids = IndexSet::new();
+        for (program_id, ..) in &calls {
+            program_ids.insert(*program_id);
+            program_ids.extend(process.get_program(program_id)?.imports().keys().copied());
+        }
+        // Compute the mapping changes.
+        let mut diffs = Vec::new();
+        for program_id in program_ids {
+            for mapping_name in process.get_program(&program_id)?.mappings().keys().copied() {
+                for (key, old_value, new_value) in overlay.get_mapping_changes(&program_id, &mapping_name)? {
+                    diffs.push(MappingDiff { program_id, mapping_name, key, old_value, new_value });
+                }
+            }
+        }
+        finish!(timer, "Compute the mapping changes");
+
+        Ok((response, diffs, None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::Address, program::Literal};
+    use snarkvm_utilities::TestRng;
+
+    #[test]
+    fn test_simulate() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new program.
+        let program = Program::from_str(
+            r"
+program simulate.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    increment account[r0] by r1;
+    decrement account[r0] by 10u64;
+",
+        )
+        .unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Initialize the VM, and add the program.
+        let vm = crate::vm::test_helpers::sample_vm();
+        vm.process().write().add_program(&program).unwrap();
+        vm.program_store().initialize_mapping(program.id(), &mapping_name).unwrap();
+
+        // Initialize a caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller = Address::try_from(&caller_private_key).unwrap();
+        let key = Plaintext::from(Literal::Address(caller));
+
+        // Simulate a call that increments the balance by 15.
+        let authorization =
+            vm.authorize(&caller_private_key, program.id(), "compute", [caller.to_string(), "15u64".to_string()], rng);
+        let (response, diffs, error) = vm.simulate(FinalizeGlobalState::new(1, 0), authorization.unwrap()).unwrap();
+        assert!(response.outputs().is_empty());
+        assert!(error.is_none());
+        assert_eq!(diffs, vec![MappingDiff {
+            program_id: *program.id(),
+            mapping_name,
+            key: key.clone(),
+            old_value: None,
+            new_value: Some(Value::from_str("5u64").unwrap()),
+        }]);
+        // Ensure the program store is untouched.
+        assert!(vm.program_store().get_value(program.id(), &mapping_name, &key).unwrap().is_none());
+
+        // Simulate a call that underflows the balance.
+        let authorization =
+            vm.authorize(&caller_private_key, program.id(), "compute", [caller.to_string(), "5u64".to_string()], rng);
+        let (_, diffs, error) = vm.simulate(FinalizeGlobalState::new(1, 0), authorization.unwrap()).unwrap();
+        assert!(diffs.is_empty());
+        assert!(error.is_some());
+
+        // Simulate the same call, with a pending balance of 20 in an atomic batch of the program store.
+        vm.program_store().start_atomic();
+        let value = Value::from_str("20u64").unwrap();
+        vm.program_store().update_key_value(program.id(), &mapping_name, key.clone(), value).unwrap();
+        let authorization =
+            vm.authorize(&caller_private_key, program.id(), "compute", [caller.to_string(), "5u64".to_string()], rng);
+        let (_, diffs, error) = vm.simulate(FinalizeGlobalState::new(1, 0), authorization.unwrap()).unwrap();
+        assert!(error.is_none());
+        assert_eq!(diffs, vec![MappingDiff {
+            program_id: *program.id(),
+            mapping_name,
+            key: key.clone(),
+            old_value: Some(Value::from_str("20u64").unwrap()),
+            new_value: Some(Value::from_str("15u64").unwrap()),
+        }]);
+        // Ensure the atomic batch of the program store is untouched.
+        let value = vm.program_store().get_value(program.id(), &mapping_name, &key).unwrap();
+        assert_eq!(value, Some(Value::from_str("20u64").unwrap()));
+        vm.program_store().abort_atomic();
+        assert!(vm.program_store().get_value(program.id(), &mapping_name, &key).unwrap().is_none());
+    }
+}
@@ -0,0 +1,235 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use std::collections::HashSet;
+
+/// The elements of the transactions accepted so far during speculation.
+struct Speculation<N: Network> {
+    /// The transaction IDs.
+    transaction_ids: HashSet<N::TransactionID>,
+    /// The serial numbers.
+    serial_numbers: HashSet<Field<N>>,
+    /// The tags.
+    tags: HashSet<Field<N>>,
+    /// The program IDs of the deployments.
+    program_ids: HashSet<ProgramID<N>>,
+}
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Speculatively verifies and finalizes the given candidate transactions, in order, with the given
+    /// finalize global state, and returns the accepted transactions, the rejected transactions with their reasons,
+    /// and the finalize root once the accepted transactions are finalized.
+    ///
+    /// Each candidate is checked against the store and the candidates accepted before it, for duplicate
+    /// transaction IDs, serial numbers, tags, and deployments. It is then verified and finalized against
+    /// a copy of the process and an in-memory overlay over the program store, so a candidate may use
+    /// a program deployed by an earlier candidate. The VM and its storage are left untouched.
+    #[inline]
+    pub fn speculate(
+        &self,
+        state: FinalizeGlobalState,
+        candidates: &[Transaction<N>],
+    ) -> Result<(Vec<Transaction<N>>, Vec<(Transaction<N>, Error)>, Field<N>)> {
+        let timer = timer!("VM::speculate");
+
+        // Copy the process, so deployments can be applied speculatively.
+        let mut process = self.process.read().clone();
+        // Initialize an overlay over the program store, which reads through to its pending atomic batch.
+        let overlay = self.program_store().to_overlay();
+        lap!(timer, "Prepare the overlay");
+
+        // Initialize the elements of the accepted transactions.
+        let mut speculation = Speculation {
+            transaction_ids: HashSet::new(),
+            serial_numbers: HashSet::new(),
+            tags: HashSet::new(),
+            program_ids: HashSet::new(),
+        };
+
+        let mut accepted = Vec::with_capacity(candidates.len());
+        let mut rejected = Vec::new();
+
+        // Start an atomic batch on the overlay, so the writes of each rejected transaction can be rolled back.
+        overlay.start_atomic();
+        for transaction in candidates {
+            // Checkpoint the overlay.
+            overlay.atomic_checkpoint();
+
+            // Check the transaction for conflicts, verify it, and finalize it.
+            let result = self.check_conflicts(&speculation, transaction).and_then(|()| {
+                // Verify the transaction against the speculative process and the overlay.
+                let is_valid = self.verify_with(&process, &overlay, transaction);
+                ensure!(is_valid, "Transaction '{}' failed verification", transaction.id());
+                Self::finalize_transaction(&mut process, state, &overlay, transaction)
+            });
+
+            match result {
+                Ok(()) => {
+                    // Keep the writes of the transaction.
+                    overlay.clear_latest_checkpoint();
+                    // Record the elements of the transaction.
+                    speculation.transaction_ids.insert(transaction.id());
+                    speculation.serial_numbers.extend(transaction.serial_numbers().copied());
+                    speculation.tags.extend(transaction.tags().copied());
+                    if let Transaction::Deploy(_, deployment, _) = transaction {
+                        speculation.program_ids.insert(*deployment.program_id());
+                    }
+                    accepted.push(transaction.clone());
+                }
+                Err(error) => {
+                    // Roll back the writes of the transaction.
+                    overlay.atomic_rewind();
+                    rejected.push((transaction.clone(), error));
+                }
+            }
+            lap!(timer, "Speculate on transaction");
+        }
+        // Apply the writes of the accepted transactions to the overlay, and compute the finalize root.
+        overlay.finish_atomic()?;
+        let finalize_root = overlay.get_finalize_root()?;
+
+        finish!(timer);
+
+        Ok((accepted, rejected, finalize_root))
+    }
+
+    /// Ensures the given transaction does not conflict with the store, or with the accepted transactions.
+    fn check_conflicts(&self, speculation: &Speculation<N>, transaction: &Transaction<N>) -> Result<()> {
+        // Retrieve the transaction ID.
+        let transaction_id = transaction.id();
+
+        // Ensure the transaction does not already exist.
+        if speculation.transaction_ids.contains(&transaction_id)
+            || self.transaction_store().contains_transaction_id(&transaction_id)?
+        {
+            bail!("Transaction '{transaction_id}' already exists")
+        }
+        // Ensure the serial numbers are not already spent.
+        for serial_number in transaction.serial_numbers() {
+            if speculation.serial_numbers.contains(serial_number)
+                || self.transition_store().contains_serial_number(serial_number)?
+            {
+                bail!("Serial number '{serial_number}' in transaction '{transaction_id}' is already spent")
+            }
+        }
+        // Ensure the tags do not already exist.
+        for tag in transaction.tags() {
+            if speculation.tags.contains(tag) || self.transition_store().contains_tag(tag)? {
+                bail!("Tag '{tag}' in transaction '{transaction_id}' already exists")
+            }
+        }
+        // Ensure the program is not already deployed by an accepted transaction.
+        if let Transaction::Deploy(_, deployment, _) = transaction {
+            let program_id = deployment.program_id();
+            if speculation.program_ids.contains(program_id) {
+                bail!("Program '{program_id}' in transaction '{transaction_id}' is already being deployed")
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Fee;
+    use console::account::{Address, ViewKey};
+    use snarkvm_utilities::TestRng;
+
+    use indexmap::IndexMap;
+
+    #[test]
+    fn test_speculate() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+        // Fetch a deployment transaction.
+        let deployment_transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let program_id = match &deployment_transaction {
+            Transaction::Deploy(_, deployment, _) => *deployment.program_id(),
+            _ => unreachable!("Expected a deployment transaction"),
+        };
+
+        // Speculate on the deployment, twice.
+        let candidates = [deployment_transaction.clone(), deployment_transaction.clone()];
+        let (accepted, rejected, finalize_root) = vm.speculate(FinalizeGlobalState::new(1, 0), &candidates).unwrap();
+
+        // Ensure the first deployment is accepted, and the duplicate is rejected.
+        assert_eq!(accepted, vec![deployment_transaction.clone()]);
+        assert_eq!(rejected.len(), 1);
+        assert_eq!(rejected[0].0, deployment_transaction);
+        // Ensure the finalize root is unchanged, as the deployment does not write any mapping entries.
+        assert_eq!(finalize_root, vm.program_store().get_finalize_root().unwrap());
+
+        // Ensure the VM and its storage are untouched.
+        assert!(!vm.contains_program(&program_id));
+        assert!(!vm.program_store().contains_program(&program_id).unwrap());
+        assert!(!vm.is_atomic_in_progress());
+    }
+
+    #[test]
+    fn test_speculate_deploy_and_execute() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Mint a second record, so the execution can pay its fee separately from the deployment.
+        let inputs = [caller.to_string(), "1_000_000_000_u64".to_string()];
+        let authorization = vm.authorize(&caller_private_key, "credits.aleo", "mint", inputs, rng).unwrap();
+        let transaction = Transaction::execute_authorization(&vm, authorization, None, rng).unwrap();
+        let block = crate::vm::test_helpers::sample_next_block(&vm, &caller_private_key, &[transaction], rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+
+        // Initialize a second VM, with the same blocks and the program, to prepare the execution.
+        let program = crate::vm::test_helpers::sample_program();
+        let other_vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+        other_vm.add_next_block(&block).unwrap();
+        other_vm.process().write().add_program(&program).unwrap();
+
+        // Execute 'testing.aleo/mint', and pay the fee with the minted record.
+        let inputs = [caller.to_string(), "10u64".to_string()];
+        let authorization = other_vm.authorize(&caller_private_key, program.id(), "mint", inputs, rng).unwrap();
+        let (_, execution, _) = other_vm.execute(authorization, None, rng).unwrap();
+        let records = block.records().collect::<IndexMap<_, _>>();
+        let credits = records.values().next().unwrap().decrypt(&caller_view_key).unwrap();
+        let cost = |fee: Option<&Fee<_>>| other_vm.process().read().execution_cost(&execution, fee);
+        let fee = crate::vm::test_helpers::sample_fee_for_cost(&other_vm, &caller_private_key, credits, cost, rng);
+        let execution_transaction = Transaction::from_execution(execution, Some(fee)).unwrap();
+
+        // Ensure the execution does not verify against the VM, as the program is not deployed.
+        assert!(!vm.verify(&execution_transaction));
+
+        // Speculate on the deployment of the program, followed by the execution.
+        let deployment_transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let candidates = [deployment_transaction, execution_transaction];
+        let (accepted, rejected, _) = vm.speculate(FinalizeGlobalState::new(2, 0), &candidates).unwrap();
+
+        // Ensure both transactions are accepted.
+        assert!(rejected.is_empty(), "{:?}", rejected.iter().map(|(_, error)| error.to_string()).collect::<Vec<_>>());
+        assert_eq!(accepted, candidates.to_vec());
+        // Ensure the VM is untouched.
+        assert!(!vm.contains_program(program.id()));
+    }
+}
@@ -16,10 +16,24 @@
 
 use super::*;
 
+use indexmap::IndexMap;
+
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Verifies the transaction in the VM.
     #[inline]
     pub fn verify(&self, transaction: &Transaction<N>) -> bool {
+        self.verify_with(&self.process.read(), self.program_store(), transaction)
+    }
+
+    /// Verifies the transaction against the given process and program store.
+    /// This allows a transaction to be verified against a speculative state of the VM.
+    #[inline]
+    pub(crate) fn verify_with<P: ProgramStorage<N>>(
+        &self,
+        process: &Process<N>,
+        store: &ProgramStore<N, P>,
+        transaction: &Transaction<N>,
+    ) -> bool {
         let timer = timer!("VM::verify");
 
         // Compute the Merkle root of the transaction.
@@ -77,9 +91,9 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     return false;
                 }
                 // Verify the deployment.
-                self.verify_deployment(deployment)
+                self.verify_deployment(process, store, deployment)
                     // Verify the fee.
-                    && self.verify_fee(fee)
+                    && self.verify_fee(process, fee)
             }
             Transaction::Execute(_, execution, additional_fee) => {
                 // Check the deployment size.
@@ -90,12 +104,12 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
                 // Verify the additional fee, if it exists.
                 let check_additional_fee = match additional_fee {
-                    Some(additional_fee) => self.verify_fee(additional_fee),
+                    Some(additional_fee) => self.verify_fee(process, additional_fee),
                     None => true,
                 };
 
                 // Verify the execution.
-                self.verify_execution(execution)
+                self.verify_execution(process, execution)
                     // Verify the additional fee.
                     && check_additional_fee
             }
@@ -103,16 +117,298 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
         lap!(timer, "Verify the transaction");
 
+        // Ensure the fee covers the cost of the transaction.
+        if verification {
+            if let Err(error) = Self::check_transaction_cost_with(process, transaction) {
+                warn!("Transaction cost check failed: {error}");
+                finish!(timer);
+                return false;
+            }
+            lap!(timer, "Check the transaction cost");
+        }
+
         finish!(timer);
 
         verification
     }
 
+    /// Verifies the execution of the given transaction, which is confirmed in a block, against the editions
+    /// of its programs at the time it was confirmed.
+    /// Note: This does not check the fee of the transaction.
+    #[inline]
+    pub fn verify_confirmed_execution(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        // Retrieve the execution.
+        let execution = match self.transaction_store().get_execution(transaction_id)? {
+            Some(execution) => execution,
+            None => bail!("Execution transaction '{transaction_id}' is not found in storage"),
+        };
+        // Retrieve the position of the transaction in the chain.
+        let position = self.find_confirmed_position(transaction_id)?;
+
+        let process = self.process.read();
+        // Retrieve the editions of the programs in the execution, as of the position of the transaction.
+        let mut editions = IndexMap::new();
+        for transition in execution.transitions() {
+            let program_id = transition.program_id();
+            if !editions.contains_key(program_id) {
+                let current_edition = process.get_stack(program_id)?.edition();
+                if let Some(edition) = self.find_edition_at(program_id, current_edition, position)? {
+                    editions.insert(*program_id, edition);
+                }
+            }
+        }
+        // Verify the execution.
+        process.verify_execution_at_editions::<true>(&execution, &editions)?;
+        // Ensure the global state root exists in the block store.
+        ensure!(
+            self.block_store().contains_state_root(&execution.global_state_root())?,
+            "The global state root of transaction '{transaction_id}' is not found"
+        );
+        Ok(())
+    }
+
+    /// Returns the position of the given confirmed transaction in the chain,
+    /// as its block height and its index in the block.
+    fn find_confirmed_position(&self, transaction_id: &N::TransactionID) -> Result<(u32, usize)> {
+        // Retrieve the block hash.
+        let block_hash = match self.block_store().find_block_hash(transaction_id)? {
+            Some(block_hash) => block_hash,
+            None => bail!("Transaction '{transaction_id}' is not confirmed in a block"),
+        };
+        // Retrieve the block height.
+        let height = match self.block_store().get_block_height(&block_hash)? {
+            Some(height) => height,
+            None => bail!("Missing block height for block hash '{block_hash}'"),
+        };
+        // Retrieve the index of the transaction in the block.
+        let transaction_ids = self.block_store().get_block_transaction_ids(&block_hash)?.unwrap_or_default();
+        match transaction_ids.iter().position(|id| id == transaction_id) {
+            Some(index) => Ok((height, index)),
+            None => bail!("Transaction '{transaction_id}' is not found in block {height}"),
+        }
+    }
+
+    /// Returns the latest edition of the given program, up to the given current edition, that was deployed
+    /// before the given position in the chain, or `None` if the program is not deployed in storage.
+    fn find_edition_at(
+        &self,
+        program_id: &ProgramID<N>,
+        current_edition: u16,
+        position: (u32, usize),
+    ) -> Result<Option<u16>> {
+        let mut is_deployed = false;
+        for edition in (0..=current_edition).rev() {
+            // Retrieve the deployment of the edition, if it exists.
+            let transaction_id = match self.transaction_store().find_deployment_id_for_edition(program_id, edition)? {
+                Some(transaction_id) => transaction_id,
+                None => continue,
+            };
+            is_deployed = true;
+            // Skip the deployment, if it was rejected during finalize.
+            if self.transaction_store().get_status(&transaction_id)? == Some(TransactionStatus::Rejected) {
+                continue;
+            }
+            // Return the edition, if it was deployed before the given position.
+            if self.find_confirmed_position(&transaction_id)? < position {
+                return Ok(Some(edition));
+            }
+        }
+        match is_deployed {
+            true => bail!("Program '{program_id}' was not deployed before block {}", position.0),
+            false => Ok(None),
+        }
+    }
+
+    /// Ensures the given block is a valid next block for the chain in the VM.
+    /// Note: This method does not verify the transactions in the block.
+    #[inline]
+    pub fn check_next_block(&self, block: &Block<N>) -> Result<()> {
+        let timer = timer!("VM::check_next_block");
+
+        // Ensure the block hash and the block signature are correct.
+        block.check_hash_and_signature()?;
+        lap!(timer, "Check the block hash and signature");
+
+        // Retrieve the latest block height.
+        let latest_height = match self.block_store().current_block_height()? {
+            Some(latest_height) => latest_height,
+            // If there are no blocks in storage, ensure the block is a genesis block.
+            None => {
+                ensure!(block.is_genesis(), "The first block must be a genesis block");
+                finish!(timer);
+                return Ok(());
+            }
+        };
+
+        // Retrieve the latest block hash.
+        let latest_hash = match self.block_store().get_block_hash(latest_height)? {
+            Some(latest_hash) => latest_hash,
+            None => bail!("Missing block hash for block {latest_height}"),
+        };
+        // Retrieve the latest block header.
+        let latest_header = match self.block_store().get_block_header(&latest_hash)? {
+            Some(latest_header) => latest_header,
+            None => bail!("Missing block header for block {latest_height}"),
+        };
+
+        // Ensure the block height increments by one.
+        ensure!(
+            Some(block.height()) == latest_height.checked_add(1),
+            "Expected block {}, found block {}",
+            latest_height.saturating_add(1),
+            block.height()
+        );
+        // Ensure the previous block hash is correct.
+        ensure!(block.previous_hash() == latest_hash, "Incorrect previous block hash for block {}", block.height());
+        // Ensure the previous state root is correct.
+        ensure!(
+            block.previous_state_root() == *self.block_store().current_state_root(),
+            "Incorrect previous state root for block {}",
+            block.height()
+        );
+        // Ensure the block header is well-formed.
+        ensure!(block.header().is_valid(), "Invalid block header for block {}", block.height());
+        // Ensure the network ID is correct.
+        ensure!(block.network() == N::ID, "Incorrect network ID for block {}", block.height());
+        lap!(timer, "Check the block header");
+
+        // Ensure the round increases.
+        ensure!(block.round() > latest_header.round(), "Block {} must advance the round", block.height());
+        // Ensure the timestamp increases.
+        ensure!(
+            block.timestamp() > latest_header.timestamp(),
+            "Block {} must have a timestamp after its previous block",
+            block.height()
+        );
+
+        // Ensure the coinbase target is retargeted from the last coinbase.
+        let expected_coinbase_target = latest_header.metadata().next_coinbase_target(block.timestamp());
+        ensure!(
+            block.coinbase_target() == expected_coinbase_target,
+            "Incorrect coinbase target for block {} (expected {expected_coinbase_target}, found {})",
+            block.height(),
+            block.coinbase_target()
+        );
+        // Ensure the proof target is derived from the coinbase target.
+        let expected_proof_target = Metadata::<N>::to_proof_target(expected_coinbase_target);
+        ensure!(
+            block.proof_target() == expected_proof_target,
+            "Incorrect proof target for block {} (expected {expected_proof_target}, found {})",
+            block.height(),
+            block.proof_target()
+        );
+
+        // Ensure the last coinbase target and timestamp are updated if, and only if, the block has a coinbase.
+        let (expected_last_coinbase_target, expected_last_coinbase_timestamp) = match block.coinbase() {
+            Some(_) => (block.coinbase_target(), block.timestamp()),
+            None => (latest_header.last_coinbase_target(), latest_header.last_coinbase_timestamp()),
+        };
+        ensure!(
+            block.last_coinbase_target() == expected_last_coinbase_target,
+            "Incorrect last coinbase target for block {}",
+            block.height()
+        );
+        ensure!(
+            block.last_coinbase_timestamp() == expected_last_coinbase_timestamp,
+            "Incorrect last coinbase timestamp for block {}",
+            block.height()
+        );
+        lap!(timer, "Check the block metadata");
+
+        // Ensure the coinbase solution is valid, if it exists.
+        if let Some(coinbase) = block.coinbase() {
+            // Ensure the puzzle commitments are new.
+            for puzzle_commitment in coinbase.puzzle_commitments() {
+                if self.block_store().contains_puzzle_commitment(&puzzle_commitment)? {
+                    bail!("Puzzle commitment '{puzzle_commitment}' in block {} already exists", block.height())
+                }
+            }
+
+            // Retrieve the epoch block hash, defined as the previous block hash of the epoch starting height.
+            let epoch_starting_height = block.epoch_number() * N::NUM_BLOCKS_PER_EPOCH;
+            let epoch_block_hash = match epoch_starting_height == block.height() {
+                true => block.previous_hash(),
+                false => match self.block_store().get_previous_block_hash(epoch_starting_height)? {
+                    Some(epoch_block_hash) => epoch_block_hash,
+                    None => bail!("Missing previous block hash for block {epoch_starting_height}"),
+                },
+            };
+            // Construct the epoch challenge.
+            let epoch_challenge =
+                EpochChallenge::new(block.epoch_number(), epoch_block_hash, N::COINBASE_PUZZLE_DEGREE)?;
+
+            // Ensure the coinbase solution is valid.
+            let coinbase_puzzle = CoinbasePuzzle::<N>::load()?;
+            ensure!(
+                coinbase_puzzle.verify(coinbase, &epoch_challenge, block.coinbase_target(), block.proof_target())?,
+                "Invalid coinbase solution for block {}",
+                block.height()
+            );
+            lap!(timer, "Check the coinbase solution");
+        }
+
+        finish!(timer);
+
+        Ok(())
+    }
+
+    /// Ensures the fee of the given transaction covers its cost.
+    /// Note: The storage cost of the mapping writes is checked separately, in `VM::finalize`.
+    #[inline]
+    pub fn check_transaction_cost(&self, transaction: &Transaction<N>) -> Result<()> {
+        Self::check_transaction_cost_with(&self.process.read(), transaction)
+    }
+
+    /// Ensures the fee of the given transaction covers its cost, as computed by the given process.
+    #[inline]
+    fn check_transaction_cost_with(process: &Process<N>, transaction: &Transaction<N>) -> Result<()> {
+        // Compute the cost of the transaction.
+        let cost = match transaction {
+            Transaction::Deploy(_, deployment, fee) => process.deployment_cost(deployment, Some(fee))?,
+            Transaction::Execute(_, execution, additional_fee) => {
+                process.execution_cost(execution, additional_fee.as_ref())?
+            }
+        };
+        // Ensure the fee covers the cost.
+        Self::check_fee_covers_cost(transaction, cost)
+    }
+
+    /// Ensures the fee of the given transaction covers the given cost (in gates).
+    #[inline]
+    pub(crate) fn check_fee_covers_cost(transaction: &Transaction<N>, cost: u64) -> Result<()> {
+        // Note: A coinbase transaction, with only the coinbase transition, mints credits and does not pay a fee.
+        let mut transitions = transaction.transitions();
+        if let (Some(transition), None) = (transitions.next(), transitions.next()) {
+            if Program::is_coinbase(transition.program_id(), transition.function_name()) {
+                return Ok(());
+            }
+        }
+        // Retrieve the fee of the transaction.
+        let fee = transaction.fee()?;
+        // Ensure the fee covers the cost.
+        match u64::try_from(fee) {
+            Ok(fee) if fee >= cost => Ok(()),
+            _ => bail!("Transaction '{}' pays a fee of {fee} gates, but costs {cost} gates", transaction.id()),
+        }
+    }
+
     /// Verifies the given deployment.
     #[inline]
-    fn verify_deployment(&self, deployment: &Deployment<N>) -> bool {
+    fn verify_deployment<P: ProgramStorage<N>>(
+        &self,
+        process: &Process<N>,
+        store: &ProgramStore<N, P>,
+        deployment: &Deployment<N>,
+    ) -> bool {
         let timer = timer!("VM::verify_deployment");
 
+        // Ensure the program ID and mappings are consistent with the program store.
+        if let Err(error) = process.check_deployment_storage(store, deployment) {
+            warn!("Deployment verification failed: {error}");
+            finish!(timer);
+            return false;
+        }
+
         // Compute the core logic.
         macro_rules! logic {
             ($process:expr, $network:path, $aleo:path) => {{
@@ -122,14 +418,15 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     // Initialize an RNG.
                     let rng = &mut rand::thread_rng();
                     // Verify the deployment.
-                    $process.verify_deployment::<$aleo, _>(&deployment, rng)
+                    $process.verify_deployment::<$aleo, _>(deployment, rng)
                 };
                 task()
             }};
         }
 
-        // Process the logic.
-        match process!(self, logic) {
+        // Process the logic, with the given process.
+        let verification = process!(&process, logic);
+        match verification {
             Ok(()) => {
                 finish!(timer);
                 true
@@ -144,11 +441,11 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
     /// Verifies the given execution.
     #[inline]
-    fn verify_execution(&self, execution: &Execution<N>) -> bool {
+    fn verify_execution(&self, process: &Process<N>, execution: &Execution<N>) -> bool {
         let timer = timer!("VM::verify_execution");
 
         // Verify the execution.
-        let verification = self.process.read().verify_execution::<true>(execution);
+        let verification = process.verify_execution::<true>(execution);
         finish!(timer);
 
         match verification {
@@ -173,11 +470,11 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
     /// Verifies the given fee.
     #[inline]
-    fn verify_fee(&self, fee: &Fee<N>) -> bool {
+    fn verify_fee(&self, process: &Process<N>, fee: &Fee<N>) -> bool {
         let timer = timer!("VM::verify_fee");
 
         // Verify the fee.
-        let verification = self.process.read().verify_fee(fee);
+        let verification = process.verify_fee(fee);
         finish!(timer);
 
         match verification {
@@ -205,7 +502,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 mod tests {
     use super::*;
 
-    use crate::{Block, Header, Inclusion, Metadata, Transaction, Transactions};
+    use crate::{Block, ConsensusMemory, Header, Inclusion, Metadata, Transaction, Transactions};
     use console::{
         account::{Address, ViewKey},
         types::Field,
@@ -241,7 +538,7 @@ mod tests {
         let deployment = vm.deploy(&program, rng).unwrap();
 
         // Ensure the deployment is valid.
-        assert!(vm.verify_deployment(&deployment));
+        assert!(vm.verify_deployment(&vm.process().read(), vm.program_store(), &deployment));
     }
 
     #[test]
@@ -259,12 +556,131 @@ mod tests {
                 // Verify the inclusion.
                 assert!(Inclusion::verify_execution(&execution).is_ok());
                 // Verify the execution.
-                assert!(vm.verify_execution(&execution));
+                assert!(vm.verify_execution(&vm.process().read(), &execution));
             }
             _ => panic!("Expected an execution transaction"),
         }
     }
 
+    #[test]
+    fn test_check_fee_covers_cost_for_coinbase() {
+        let rng = &mut TestRng::default();
+
+        // Fetch the coinbase transaction from the genesis block.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+        let coinbase = genesis.transactions().values().next().unwrap().clone();
+        // Ensure the coinbase transaction does not pay a fee.
+        assert!(VM::<CurrentNetwork, ConsensusMemory<_>>::check_fee_covers_cost(&coinbase, u64::MAX).is_ok());
+
+        // Retrieve the coinbase transition, and a fee transition.
+        let mint = coinbase.transitions().next().unwrap().clone();
+        let fee = crate::vm::test_helpers::sample_fee();
+
+        // Ensure a transaction with the coinbase transition and an additional fee must pay for its cost.
+        let execution = Execution::from([mint.clone()].into_iter(), Default::default(), None).unwrap();
+        let transaction = Transaction::from_execution(execution, Some(fee.clone())).unwrap();
+        assert!(VM::<CurrentNetwork, ConsensusMemory<_>>::check_fee_covers_cost(&transaction, u64::MAX).is_err());
+
+        // Ensure a transaction with the coinbase transition and another transition must pay for its cost.
+        let execution =
+            Execution::from([mint, fee.transition().clone()].into_iter(), Default::default(), None).unwrap();
+        let transaction = Transaction::from_execution(execution, None).unwrap();
+        assert!(VM::<CurrentNetwork, ConsensusMemory<_>>::check_fee_covers_cost(&transaction, u64::MAX).is_err());
+    }
+
+    #[test]
+    fn test_check_next_block() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm();
+        // Fetch the genesis block.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+
+        // Ensure the genesis block is a valid first block.
+        vm.check_next_block(&genesis).unwrap();
+        vm.add_next_block(&genesis).unwrap();
+
+        // Ensure the genesis block is not a valid next block.
+        assert!(vm.check_next_block(&genesis).is_err());
+        assert!(vm.add_next_block(&genesis).is_err());
+        assert_eq!(vm.block_store().current_block_height().unwrap(), Some(0));
+    }
+
+    #[test]
+    fn test_check_next_block_rejects_invalid_headers() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+        let private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+        // Prepare the transactions for the next blocks.
+        let transactions = Transactions::from(&[crate::vm::test_helpers::sample_execution_transaction(rng)]);
+
+        // Constructs the next block, with the given previous hash, height, timestamp, and coinbase and proof targets.
+        let next_block = |previous_hash, height: u32, timestamp, coinbase_target, proof_target, rng: &mut TestRng| {
+            let metadata = Metadata::new(
+                CurrentNetwork::ID,
+                u64::from(height),
+                height,
+                coinbase_target,
+                proof_target,
+                genesis.last_coinbase_target(),
+                genesis.last_coinbase_timestamp(),
+                timestamp,
+            )
+            .unwrap();
+            let header = Header::from(
+                *vm.block_store().current_state_root(),
+                transactions.to_root().unwrap(),
+                Field::zero(),
+                Field::zero(),
+                metadata,
+            )
+            .unwrap();
+            Block::new(&private_key, previous_hash, header, transactions.clone(), None, rng).unwrap()
+        };
+
+        // Compute the expected targets for the next block.
+        let timestamp = CurrentNetwork::GENESIS_TIMESTAMP + 1;
+        let coinbase_target = genesis.header().metadata().next_coinbase_target(timestamp);
+        let proof_target = Metadata::<CurrentNetwork>::to_proof_target(coinbase_target);
+        assert!(coinbase_target > CurrentNetwork::GENESIS_COINBASE_TARGET);
+
+        // Ensure a block with an incorrect previous hash is rejected.
+        let block = next_block(Default::default(), 1, timestamp, coinbase_target, proof_target, rng);
+        assert!(vm.check_next_block(&block).is_err());
+        // Ensure a block with an incorrect height is rejected.
+        let block = next_block(genesis.hash(), 2, timestamp, coinbase_target, proof_target, rng);
+        assert!(vm.check_next_block(&block).is_err());
+        // Ensure a block with an incorrect coinbase target is rejected.
+        let block = next_block(genesis.hash(), 1, timestamp, coinbase_target + 1, proof_target, rng);
+        assert!(vm.check_next_block(&block).is_err());
+        let block =
+            next_block(genesis.hash(), 1, timestamp, CurrentNetwork::GENESIS_COINBASE_TARGET, proof_target, rng);
+        assert!(vm.check_next_block(&block).is_err());
+        // Ensure a block with an incorrect proof target is rejected.
+        let block = next_block(genesis.hash(), 1, timestamp, coinbase_target, proof_target + 1, rng);
+        assert!(vm.check_next_block(&block).is_err());
+
+        // Ensure the valid block is accepted.
+        let block = next_block(genesis.hash(), 1, timestamp, coinbase_target, proof_target, rng);
+        vm.check_next_block(&block).unwrap();
+        vm.add_next_block(&block).unwrap();
+
+        // Ensure a block with a timestamp that does not advance is rejected.
+        let coinbase_target = block.header().metadata().next_coinbase_target(timestamp);
+        let proof_target = Metadata::<CurrentNetwork>::to_proof_target(coinbase_target);
+        let next = next_block(block.hash(), 2, timestamp, coinbase_target, proof_target, rng);
+        assert!(vm.check_next_block(&next).is_err());
+        // Ensure a block with a timestamp that advances is accepted.
+        let coinbase_target = block.header().metadata().next_coinbase_target(timestamp + 1);
+        let proof_target = Metadata::<CurrentNetwork>::to_proof_target(coinbase_target);
+        let next = next_block(block.hash(), 2, timestamp + 1, coinbase_target, proof_target, rng);
+        vm.check_next_block(&next).unwrap();
+    }
+
     #[test]
     fn test_verify_deploy_and_execute() {
         // Initialize the RNG.
@@ -281,9 +697,8 @@ mod tests {
         // Fetch the unspent records.
         let records = genesis.records().collect::<indexmap::IndexMap<_, _>>();
 
-        // Prepare the additional fee.
+        // Prepare the credits for the fee.
         let credits = records.values().next().unwrap().decrypt(&caller_view_key).unwrap();
-        let additional_fee = (credits, 10);
 
         // Initialize the VM.
         let vm = crate::vm::test_helpers::sample_vm();
@@ -292,21 +707,26 @@ mod tests {
 
         // Deploy.
         let program = crate::vm::test_helpers::sample_program();
-        let deployment_transaction =
-            Transaction::deploy(&vm, &caller_private_key, &program, additional_fee, None, rng).unwrap();
+        let deployment = vm.deploy(&program, rng).unwrap();
+        // Compute the fee, to cover the cost of the deployment.
+        let cost = |fee: Option<&Fee<_>>| vm.process().read().deployment_cost(&deployment, fee);
+        let fee = crate::vm::test_helpers::sample_fee_for_cost(&vm, &caller_private_key, credits, cost, rng);
+        let deployment_transaction = Transaction::from_deployment(deployment, fee).unwrap();
 
         // Construct the new block header.
         let transactions = Transactions::from(&[deployment_transaction]);
-        // Construct the metadata associated with the block.
+        // Construct the metadata associated with the block, with the retargeted coinbase target and proof target.
+        let timestamp = CurrentNetwork::GENESIS_TIMESTAMP + 1;
+        let coinbase_target = genesis.header().metadata().next_coinbase_target(timestamp);
         let deployment_metadata = Metadata::new(
             CurrentNetwork::ID,
             1,
             1,
-            CurrentNetwork::GENESIS_COINBASE_TARGET,
-            CurrentNetwork::GENESIS_PROOF_TARGET,
+            coinbase_target,
+            Metadata::<CurrentNetwork>::to_proof_target(coinbase_target),
             genesis.last_coinbase_target(),
             genesis.last_coinbase_timestamp(),
-            CurrentNetwork::GENESIS_TIMESTAMP + 1,
+            timestamp,
         )
         .unwrap();
 
@@ -314,6 +734,7 @@ mod tests {
             *vm.block_store().current_state_root(),
             transactions.to_root().unwrap(),
             Field::zero(),
+            Field::zero(),
             deployment_metadata,
         )
         .unwrap();
@@ -342,7 +763,26 @@ mod tests {
         assert_eq!(authorization.len(), 1);
 
         // Execute.
-        let transaction = Transaction::execute_authorization(&vm, authorization, None, rng).unwrap();
+        let (_, execution, _) = vm.execute(authorization, None, rng).unwrap();
+
+        // Ensure the execution does not verify without a fee.
+        let transaction = Transaction::from_execution(execution.clone(), None).unwrap();
+        assert!(!vm.verify(&transaction));
+
+        // Fetch the unspent record from the deployment fee.
+        let records = deployment_block.records().collect::<indexmap::IndexMap<_, _>>();
+        let credits = records.values().next().unwrap().decrypt(&caller_view_key).unwrap();
+
+        // Ensure the execution does not verify with a fee that does not cover its own cost.
+        let fee_in_gates = vm.process().read().execution_cost(&execution, None).unwrap();
+        let (_, fee, _) = vm.execute_fee(&caller_private_key, credits.clone(), fee_in_gates, None, rng).unwrap();
+        let transaction = Transaction::from_execution(execution.clone(), Some(fee)).unwrap();
+        assert!(!vm.verify(&transaction));
+
+        // Compute the fee, to cover the cost of the execution, including the cost of the fee.
+        let cost = |fee: Option<&Fee<_>>| vm.process().read().execution_cost(&execution, fee);
+        let fee = crate::vm::test_helpers::sample_fee_for_cost(&vm, &caller_private_key, credits, cost, rng);
+        let transaction = Transaction::from_execution(execution, Some(fee)).unwrap();
 
         // Verify.
         assert!(vm.verify(&transaction));
@@ -0,0 +1,213 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::prelude::{Address, Signature, ViewKey};
+
+use rand::{rngs::StdRng, SeedableRng};
+use std::path::{Path, PathBuf};
+
+/// The environment variable to read the private key from, if no private key file is given.
+const PRIVATE_KEY_ENV: &str = "ALEO_PRIVATE_KEY";
+
+/// Manage an Aleo account
+#[derive(Debug, Parser)]
+pub enum Account {
+    /// Generates a new Aleo account
+    New {
+        /// Seeds the RNG with the given value, for a reproducible account (for development only)
+        #[clap(short = 's', long, hide = true)]
+        seed: Option<u64>,
+        /// Prints the account as JSON
+        #[clap(long)]
+        json: bool,
+    },
+    /// Derives the view key and address of a private key, read from a file, `ALEO_PRIVATE_KEY`, or stdin
+    Import {
+        /// Reads the private key from the given file
+        #[clap(long)]
+        private_key_file: Option<PathBuf>,
+        /// Prints the account as JSON
+        #[clap(long)]
+        json: bool,
+    },
+    /// Signs a message with a private key, read from a file, `ALEO_PRIVATE_KEY`, or stdin
+    Sign {
+        /// Reads the private key from the given file
+        #[clap(long)]
+        private_key_file: Option<PathBuf>,
+        /// The message to sign
+        #[clap(long)]
+        message: String,
+        /// Prints the signature as JSON
+        #[clap(long)]
+        json: bool,
+    },
+    /// Verifies the signature of a message against the given address
+    Verify {
+        /// The address of the signer
+        #[clap(long)]
+        address: Address<CurrentNetwork>,
+        /// The signature
+        #[clap(long)]
+        signature: Signature<CurrentNetwork>,
+        /// The message that was signed
+        #[clap(long)]
+        message: String,
+        /// Prints the result as JSON
+        #[clap(long)]
+        json: bool,
+    },
+}
+
+impl Account {
+    /// Runs the account command.
+    pub fn start(&self) -> Result<String> {
+        match self {
+            Self::New { seed, json } => {
+                // Sample a new private key, from the seeded RNG if a seed is given.
+                let private_key = match seed {
+                    Some(seed) => {
+                        eprintln!("⚠️  Seeded accounts are reproducible, so they are for development only");
+                        PrivateKey::<CurrentNetwork>::new(&mut StdRng::seed_from_u64(*seed))?
+                    }
+                    None => PrivateKey::<CurrentNetwork>::new(&mut rand::thread_rng())?,
+                };
+                Self::format_account(&private_key, *json)
+            }
+            Self::Import { private_key_file, json } => {
+                Self::format_account(&Self::read_private_key(private_key_file.as_deref())?, *json)
+            }
+            Self::Sign { private_key_file, message, json } => {
+                // Read the private key.
+                let private_key = Self::read_private_key(private_key_file.as_deref())?;
+                // Sign the message.
+                let signature = Signature::sign_bytes(&private_key, message.as_bytes(), &mut rand::thread_rng())?;
+                match json {
+                    true => Ok(serde_json::to_string_pretty(&serde_json::json!({ "signature": signature }))?),
+                    false => Ok(signature.to_string()),
+                }
+            }
+            Self::Verify { address, signature, message, json } => {
+                // Verify the signature.
+                let is_valid = signature.verify_bytes(address, message.as_bytes());
+                match json {
+                    true => Ok(serde_json::to_string_pretty(&serde_json::json!({ "valid": is_valid }))?),
+                    false => match is_valid {
+                        true => Ok("✅ The signature is valid".to_string()),
+                        false => Ok("❌ The signature is invalid".to_string()),
+                    },
+                }
+            }
+        }
+    }
+
+    /// Reads the private key from the given file if one is given, or else from the `ALEO_PRIVATE_KEY`
+    /// environment variable if it is set, or else from stdin. This keeps the private key out of the process arguments.
+    fn read_private_key(file: Option<&Path>) -> Result<PrivateKey<CurrentNetwork>> {
+        let private_key = match (file, std::env::var(PRIVATE_KEY_ENV)) {
+            (Some(file), _) => std::fs::read_to_string(file)?,
+            (None, Ok(private_key)) => private_key,
+            (None, Err(_)) => {
+                let mut private_key = String::new();
+                std::io::stdin().read_line(&mut private_key)?;
+                private_key
+            }
+        };
+        PrivateKey::from_str(private_key.trim())
+    }
+
+    /// Returns the private key, view key, and address of the given private key.
+    fn format_account(private_key: &PrivateKey<CurrentNetwork>, json: bool) -> Result<String> {
+        // Derive the view key and address.
+        let view_key = ViewKey::try_from(private_key)?;
+        let address = Address::try_from(&view_key)?;
+
+        match json {
+            true => Ok(serde_json::to_string_pretty(&serde_json::json!({
+                "private_key": private_key,
+                "view_key": view_key,
+                "address": address,
+            }))?),
+            false => Ok(format!(
+                "\n {:>12}  {private_key}\n {:>12}  {view_key}\n {:>12}  {address}\n",
+                "Private Key".cyan().bold(),
+                "View Key".cyan().bold(),
+                "Address".cyan().bold(),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::CLI;
+
+    /// Writes the given private key to a file in a temporary directory, and returns the file path.
+    fn write_private_key(private_key: &PrivateKey<CurrentNetwork>) -> PathBuf {
+        let path = tempfile::tempdir().unwrap().into_path().join("private_key");
+        std::fs::write(&path, format!("{private_key}\n")).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_new_with_seed() {
+        // Ensure the same seed produces the same account.
+        let account = Account::New { seed: Some(1234), json: true };
+        let expected = account.start().unwrap();
+        assert_eq!(account.start().unwrap(), expected);
+
+        // Ensure the imported private key produces the same account.
+        let json: serde_json::Value = serde_json::from_str(&expected).unwrap();
+        let private_key = PrivateKey::from_str(json["private_key"].as_str().unwrap()).unwrap();
+        let private_key_file = Some(write_private_key(&private_key));
+        assert_eq!(Account::Import { private_key_file, json: true }.start().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        // Sample a private key.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut StdRng::seed_from_u64(1234)).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+        let message = "Hello, Aleo".to_string();
+
+        // Sign the message.
+        let private_key_file = Some(write_private_key(&private_key));
+        let output = Account::Sign { private_key_file, message: message.clone(), json: false }.start().unwrap();
+        let signature = Signature::from_str(&output).unwrap();
+
+        // Ensure the signature is valid for the message.
+        let output = Account::Verify { address, signature, message, json: true }.start().unwrap();
+        assert_eq!(serde_json::from_str::<serde_json::Value>(&output).unwrap()["valid"], true);
+
+        // Ensure the signature is invalid for a different message.
+        let output =
+            Account::Verify { address, signature, message: "Goodbye".to_string(), json: true }.start().unwrap();
+        assert_eq!(serde_json::from_str::<serde_json::Value>(&output).unwrap()["valid"], false);
+    }
+
+    #[test]
+    fn test_parse_rejects_private_key_arguments() {
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut StdRng::seed_from_u64(1234)).unwrap().to_string();
+        // Ensure the private key can not be passed as an argument.
+        assert!(CLI::try_parse_from(["snarkvm", "account", "import", &private_key]).is_err());
+        let arguments = ["snarkvm", "account", "sign", "--private-key", &private_key, "--message", "Hello"];
+        assert!(CLI::try_parse_from(arguments).is_err());
+        // Ensure the private key file can be passed as an argument.
+        assert!(CLI::try_parse_from(["snarkvm", "account", "import", "--private-key-file", "private_key"]).is_ok());
+    }
+}
@@ -0,0 +1,37 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// Compile the package in the current directory
+#[derive(Debug, Parser)]
+pub struct Build {
+    /// Uses the given endpoint to synthesize the proving and verifying keys
+    #[clap(long)]
+    endpoint: Option<String>,
+}
+
+impl Build {
+    /// Builds the package in the current directory.
+    pub fn start(&self) -> Result<String> {
+        // Open the package at the current directory.
+        let package = Package::<CurrentNetwork>::open(&std::env::current_dir()?)?;
+        // Build the package, if the package requires building.
+        package.build::<CurrentAleo>(self.endpoint.clone())?;
+
+        Ok(format!("✅ Built '{}'", package.program_id().to_string().bold()))
+    }
+}
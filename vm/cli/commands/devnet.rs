@@ -0,0 +1,292 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{
+    prelude::{Field, FromBytes, Network, Zero},
+    synthesizer::{Block, ConsensusStore, FinalizeGlobalState, Header, Metadata, Transaction, Transactions, VM},
+};
+
+use anyhow::anyhow;
+use rand::{CryptoRng, Rng};
+use std::{
+    io::Read,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+use tiny_http::{Method, Request, Response, Server};
+
+#[cfg(feature = "rocks")]
+type CurrentStorage = crate::synthesizer::store::helpers::rocksdb::ConsensusDB<CurrentNetwork>;
+#[cfg(not(feature = "rocks"))]
+type CurrentStorage = crate::synthesizer::ConsensusMemory<CurrentNetwork>;
+
+/// Start a local devnet, which produces blocks and serves the REST endpoints used by `Query`
+#[derive(Debug, Parser)]
+pub struct Devnet {
+    /// The port of the REST server, on localhost
+    #[clap(long, default_value = "3030")]
+    port: u16,
+    /// The number of seconds between blocks
+    #[clap(long, default_value = "5")]
+    interval: u64,
+    /// Signs the blocks with the given private key, instead of a new private key
+    #[clap(long)]
+    private_key: Option<PrivateKey<CurrentNetwork>>,
+    /// The storage ID, to isolate the storage of each run (without the 'rocks' feature, storage is in-memory)
+    #[clap(long)]
+    dev: Option<u16>,
+}
+
+impl Devnet {
+    /// Starts the devnet, and runs until it is interrupted.
+    pub fn start(&self) -> Result<String> {
+        ensure!(self.interval > 0, "The block interval must be at least 1 second");
+
+        // Initialize an RNG.
+        let rng = &mut rand::thread_rng();
+        // Retrieve the private key of the block producer.
+        let private_key = match self.private_key {
+            Some(private_key) => private_key,
+            None => PrivateKey::new(rng)?,
+        };
+
+        // Initialize the VM.
+        let vm = VM::from(ConsensusStore::<CurrentNetwork, CurrentStorage>::open(self.dev)?)?;
+        // Add the genesis block, if the storage is empty.
+        if vm.block_store().current_block_height()?.is_none() {
+            vm.add_next_block(&Block::from_bytes_le(CurrentNetwork::genesis_bytes())?)?;
+        }
+
+        // Start the REST server.
+        let server = Server::http(("127.0.0.1", self.port))
+            .map_err(|error| anyhow!("Failed to start the REST server on port {}: {error}", self.port))?;
+        println!("🌐 Started a devnet at '{}', producing a block every {}s\n", server.server_addr(), self.interval);
+        println!(" {:>12}  {private_key}\n", "Private Key".cyan().bold());
+
+        // Initialize the pending transactions.
+        let mut mempool = Vec::new();
+        // Initialize the time of the next block.
+        let interval = Duration::from_secs(self.interval);
+        let mut next_block_at = Instant::now() + interval;
+
+        loop {
+            // Handle the next request, until the next block is due.
+            if let Some(request) = server.recv_timeout(next_block_at.saturating_duration_since(Instant::now()))? {
+                if let Err(error) = Self::handle_request(&vm, &mut mempool, request) {
+                    eprintln!("Failed to respond to a request: {error}");
+                }
+            }
+
+            // Produce a block, if it is due.
+            if Instant::now() >= next_block_at {
+                // Note: A block must contain at least one transaction, so none is produced for an empty mempool.
+                if !mempool.is_empty() {
+                    match Self::produce_block(&vm, &private_key, core::mem::take(&mut mempool), rng) {
+                        Ok(Some(block)) => println!(
+                            "📦 Produced block {} ({}) with {} transaction(s)",
+                            block.height(),
+                            block.hash(),
+                            block.transactions().len()
+                        ),
+                        Ok(None) => (),
+                        Err(error) => eprintln!("Failed to produce a block: {error}"),
+                    }
+                }
+                next_block_at = Instant::now() + interval;
+            }
+        }
+    }
+
+    /// Produces the next block from the given candidate transactions, and adds it to the VM.
+    /// Returns `None` if every candidate transaction is rejected.
+    fn produce_block<R: Rng + CryptoRng>(
+        vm: &VM<CurrentNetwork, CurrentStorage>,
+        private_key: &PrivateKey<CurrentNetwork>,
+        candidates: Vec<Transaction<CurrentNetwork>>,
+        rng: &mut R,
+    ) -> Result<Option<Block<CurrentNetwork>>> {
+        // Retrieve the latest block hash and header.
+        let latest_height = vm.block_store().current_block_height()?.ok_or_else(|| anyhow!("Missing genesis block"))?;
+        let latest_hash = vm
+            .block_store()
+            .get_block_hash(latest_height)?
+            .ok_or_else(|| anyhow!("Missing block hash for block {latest_height}"))?;
+        let latest_header = vm
+            .block_store()
+            .get_block_header(&latest_hash)?
+            .ok_or_else(|| anyhow!("Missing block header for block {latest_height}"))?;
+
+        // Prepare the height and timestamp, ensuring the timestamp advances past the latest block.
+        let height = latest_height + 1;
+        let now = i64::try_from(SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs())?;
+        let timestamp = now.max(latest_header.timestamp() + 1);
+
+        // Speculate on the candidate transactions.
+        let (accepted, rejected, finalize_root) =
+            vm.speculate(FinalizeGlobalState::new(height, timestamp), &candidates)?;
+        for (transaction, error) in rejected {
+            eprintln!("Rejected transaction '{}': {error}", transaction.id());
+        }
+        if accepted.is_empty() {
+            return Ok(None);
+        }
+
+        // Construct the block, with the retargeted coinbase target and proof target.
+        let transactions = Transactions::from(&accepted);
+        let coinbase_target = latest_header.metadata().next_coinbase_target(timestamp);
+        let metadata = Metadata::new(
+            CurrentNetwork::ID,
+            latest_header.round() + 1,
+            height,
+            coinbase_target,
+            Metadata::<CurrentNetwork>::to_proof_target(coinbase_target),
+            latest_header.last_coinbase_target(),
+            latest_header.last_coinbase_timestamp(),
+            timestamp,
+        )?;
+        let header = Header::from(
+            *vm.block_store().current_state_root(),
+            transactions.to_root()?,
+            finalize_root,
+            Field::zero(),
+            metadata,
+        )?;
+        let block = Block::new(private_key, latest_hash, header, transactions, None, rng)?;
+
+        // Add the block to the VM.
+        vm.add_next_block(&block)?;
+        Ok(Some(block))
+    }
+
+    /// Responds to the given request, with JSON.
+    fn handle_request(
+        vm: &VM<CurrentNetwork, CurrentStorage>,
+        mempool: &mut Vec<Transaction<CurrentNetwork>>,
+        mut request: Request,
+    ) -> Result<()> {
+        // Read the request body.
+        let mut body = String::new();
+        request.as_reader().read_to_string(&mut body)?;
+
+        // Route the request.
+        let (status, json) = match Self::route(vm, mempool, request.method(), request.url(), &body) {
+            Ok(Some(json)) => (200, json),
+            Ok(None) => (404, serde_json::to_string("Not found")?),
+            Err(error) => (400, serde_json::to_string(&error.to_string())?),
+        };
+
+        // Respond to the request.
+        let header = tiny_http::Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..])
+            .map_err(|_| anyhow!("Invalid content type header"))?;
+        request.respond(Response::from_string(json).with_status_code(status).with_header(header))?;
+        Ok(())
+    }
+
+    /// Returns the JSON response for the given method and URL, or `None` if the endpoint does not exist.
+    fn route(
+        vm: &VM<CurrentNetwork, CurrentStorage>,
+        mempool: &mut Vec<Transaction<CurrentNetwork>>,
+        method: &Method,
+        url: &str,
+        body: &str,
+    ) -> Result<Option<String>> {
+        // Retrieve the block store.
+        let block_store = vm.block_store();
+        // Retrieve the latest block height.
+        let latest_height = block_store.current_block_height()?.ok_or_else(|| anyhow!("Missing genesis block"))?;
+
+        let segments = url.trim_matches('/').split('/').collect::<Vec<_>>();
+        let json = match (method, segments.as_slice()) {
+            (Method::Get, ["testnet3", "latest", "height"]) => serde_json::to_string(&latest_height)?,
+            (Method::Get, ["testnet3", "latest", "hash"]) => {
+                serde_json::to_string(&block_store.get_block_hash(latest_height)?)?
+            }
+            (Method::Get, ["testnet3", "latest", "block"]) => match block_store.get_block_hash(latest_height)? {
+                Some(hash) => serde_json::to_string(&block_store.get_block(&hash)?)?,
+                None => return Ok(None),
+            },
+            (Method::Get, ["testnet3", "latest", "stateRoot"]) => {
+                serde_json::to_string(&block_store.current_state_root())?
+            }
+            (Method::Get, ["testnet3", "block", height]) => match block_store.get_block_hash(height.parse()?)? {
+                Some(hash) => serde_json::to_string(&block_store.get_block(&hash)?)?,
+                None => return Ok(None),
+            },
+            (Method::Get, ["testnet3", "program", program_id]) => {
+                let program_id = ProgramID::from_str(program_id)?;
+                // Note: The process includes `credits.aleo`, which is not deployed in a block.
+                let process = vm.process();
+                let process = process.read();
+                match process.get_program(program_id) {
+                    Ok(program) => serde_json::to_string(program)?,
+                    Err(_) => return Ok(None),
+                }
+            }
+            (Method::Get, ["testnet3", "statePath", commitment]) => {
+                serde_json::to_string(&block_store.get_state_path_for_commitment(&Field::from_str(commitment)?)?)?
+            }
+            (Method::Get, ["testnet3", "transaction", transaction_id]) => {
+                let transaction_id = transaction_id.parse::<<CurrentNetwork as Network>::TransactionID>()?;
+                match vm.transaction_store().get_transaction(&transaction_id)? {
+                    Some(transaction) => serde_json::to_string(&transaction)?,
+                    None => return Ok(None),
+                }
+            }
+            (Method::Post, ["testnet3", "transaction", "broadcast"]) => {
+                let transaction = Transaction::from_str(body)?;
+                // Ensure the transaction is valid.
+                ensure!(vm.verify(&transaction), "Transaction '{}' failed verification", transaction.id());
+                // Add the transaction to the mempool.
+                let transaction_id = transaction.id();
+                mempool.push(transaction);
+                serde_json::to_string(&transaction_id)?
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(json))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_route() {
+        // Initialize the VM with the genesis block.
+        let vm = VM::from(ConsensusStore::<CurrentNetwork, CurrentStorage>::open(None).unwrap()).unwrap();
+        let genesis = Block::<CurrentNetwork>::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap();
+        vm.add_next_block(&genesis).unwrap();
+
+        let mut mempool = Vec::new();
+        let mut get = |url: &str| Devnet::route(&vm, &mut mempool, &Method::Get, url, "");
+
+        // Ensure the latest height and state root are served.
+        assert_eq!(get("/testnet3/latest/height").unwrap().unwrap(), "0");
+        let state_root = serde_json::to_string(&vm.block_store().current_state_root()).unwrap();
+        assert_eq!(get("/testnet3/latest/stateRoot").unwrap().unwrap(), state_root);
+        // Ensure the genesis block and `credits.aleo` are served.
+        assert_eq!(get("/testnet3/block/0").unwrap().unwrap(), serde_json::to_string(&Some(&genesis)).unwrap());
+        assert!(get("/testnet3/program/credits.aleo").unwrap().is_some());
+
+        // Ensure a missing block, or an unknown endpoint, is not found.
+        assert!(get("/testnet3/block/1").unwrap().is_none());
+        assert!(get("/testnet3/unknown").unwrap().is_none());
+        // Ensure an invalid request is an error.
+        assert!(get("/testnet3/block/invalid").is_err());
+        assert!(mempool.is_empty());
+    }
+}
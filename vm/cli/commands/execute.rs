@@ -0,0 +1,130 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::synthesizer::CallMetrics;
+
+use std::path::PathBuf;
+
+/// Execute a program function with the given inputs, and prove the execution
+#[derive(Debug, Parser)]
+pub struct Execute {
+    /// The function name
+    function: Identifier<CurrentNetwork>,
+    /// The function inputs
+    inputs: Vec<String>,
+    /// Uses the given private key, instead of the development private key in the manifest
+    #[clap(long)]
+    private_key: Option<PrivateKey<CurrentNetwork>>,
+    /// Writes the execution, including its proofs, to the given file
+    #[clap(long)]
+    proof: Option<PathBuf>,
+    /// Uses the given endpoint to synthesize the proving and verifying keys
+    #[clap(long)]
+    endpoint: Option<String>,
+}
+
+impl Execute {
+    /// Executes the function of the package in the current directory.
+    pub fn start(&self) -> Result<String> {
+        // Parse the inputs.
+        let inputs = self.inputs.iter().map(|input| Value::from_str(input)).collect::<Result<Vec<_>>>()?;
+
+        // Open the package at the current directory.
+        let package = Package::<CurrentNetwork>::open(&std::env::current_dir()?)?;
+        // Retrieve the private key.
+        let private_key = match self.private_key {
+            Some(private_key) => private_key,
+            None => *package.manifest_file().development_private_key(),
+        };
+
+        // Execute the function.
+        let (response, execution, _inclusion, metrics) = package.execute::<CurrentAleo, _>(
+            self.endpoint.clone(),
+            &private_key,
+            self.function,
+            &inputs,
+            &mut rand::thread_rng(),
+        )?;
+
+        // Write the execution to the given file.
+        if let Some(path) = &self.proof {
+            std::fs::write(path, execution.to_string())?;
+        }
+
+        // Prepare the outputs.
+        let mut output = format!("\n➡️  Output{}\n\n", if response.outputs().len() == 1 { "" } else { "s" });
+        for value in response.outputs() {
+            output += &format!(" • {}\n", value.to_string().replace('\n', "\n   "));
+        }
+        // Prepare the metrics.
+        output += &format!("\n{}", Self::format_metrics(&metrics));
+        // Prepare the proof path.
+        if let Some(path) = &self.proof {
+            output += &format!("\n✅ Wrote the execution to '{}'\n", path.display());
+        }
+        Ok(output)
+    }
+
+    /// Returns the call metrics as a table, with one row per function call.
+    fn format_metrics(metrics: &[CallMetrics<CurrentNetwork>]) -> String {
+        let mut table = format!(
+            "{:<40} {:>12} {:>12} {:>12} {:>12}\n",
+            "Function".bold(),
+            "Instructions".bold(),
+            "Request".bold(),
+            "Function".bold(),
+            "Response".bold()
+        );
+        for call in metrics {
+            table += &format!(
+                "{:<40} {:>12} {:>12} {:>12} {:>12}\n",
+                format!("{}/{}", call.program_id, call.function_name),
+                call.num_instructions,
+                call.num_request_constraints,
+                call.num_function_constraints,
+                call.num_response_constraints
+            );
+        }
+        table
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{Command, CLI};
+
+    #[test]
+    fn test_parse_execute() {
+        let cli =
+            CLI::try_parse_from(["snarkvm", "execute", "mint", "aleo1abc", "100u64", "--proof", "execution.json"]);
+        match cli.unwrap().command {
+            Command::Execute(execute) => {
+                assert_eq!(execute.function.to_string(), "mint");
+                assert_eq!(execute.inputs, vec!["aleo1abc".to_string(), "100u64".to_string()]);
+                assert_eq!(execute.proof, Some(PathBuf::from("execution.json")));
+                assert!(execute.private_key.is_none());
+            }
+            command => panic!("Expected the 'execute' command, found {command:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_execute_with_invalid_function_name() {
+        assert!(CLI::try_parse_from(["snarkvm", "execute", "1mint"]).is_err());
+    }
+}
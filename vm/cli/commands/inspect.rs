@@ -0,0 +1,328 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{
+    prelude::{FromBytes, Itertools, Network},
+    synthesizer::{Block, Deployment, Execution, Fee, Process, Transaction, Transition},
+};
+
+use anyhow::{anyhow, bail};
+use core::fmt::Write;
+use std::path::PathBuf;
+
+/// Decode and verify a block, transaction, deployment, execution, or fee
+#[derive(Debug, Parser)]
+pub struct Inspect {
+    /// The path to the file, containing the object as JSON, bytes, or hex-encoded bytes
+    file: PathBuf,
+}
+
+/// An object that can be inspected.
+enum Object<N: Network> {
+    Block(Box<Block<N>>),
+    Transaction(Box<Transaction<N>>),
+    Deployment(Box<Deployment<N>>),
+    Execution(Box<Execution<N>>),
+    Fee(Box<Fee<N>>),
+}
+
+impl<N: Network> Object<N> {
+    /// Parses the object from the given file contents, as JSON, hex-encoded bytes, or bytes.
+    fn parse(contents: &[u8]) -> Result<Self> {
+        // If the contents are a string, attempt to parse it as JSON, or as hex-encoded bytes.
+        if let Ok(string) = core::str::from_utf8(contents) {
+            let string = string.trim();
+            if let Some(object) = Self::from_json(string) {
+                return Ok(object);
+            }
+            if let Some(object) = decode_hex(string).and_then(|bytes| Self::from_bytes(&bytes)) {
+                return Ok(object);
+            }
+        }
+        // Otherwise, attempt to parse the contents as bytes.
+        Self::from_bytes(contents)
+            .ok_or_else(|| anyhow!("Expected a block, transaction, deployment, execution, or fee"))
+    }
+
+    /// Returns the object, if the given string is the JSON of a known object.
+    fn from_json(string: &str) -> Option<Self> {
+        if let Ok(block) = serde_json::from_str(string) {
+            Some(Self::Block(Box::new(block)))
+        } else if let Ok(transaction) = serde_json::from_str(string) {
+            Some(Self::Transaction(Box::new(transaction)))
+        } else if let Ok(deployment) = serde_json::from_str(string) {
+            Some(Self::Deployment(Box::new(deployment)))
+        } else if let Ok(execution) = serde_json::from_str(string) {
+            Some(Self::Execution(Box::new(execution)))
+        } else {
+            serde_json::from_str(string).ok().map(|fee| Self::Fee(Box::new(fee)))
+        }
+    }
+
+    /// Returns the object, if the given bytes are exactly the bytes of a known object.
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if let Some(block) = read_exact(bytes) {
+            Some(Self::Block(Box::new(block)))
+        } else if let Some(transaction) = read_exact(bytes) {
+            Some(Self::Transaction(Box::new(transaction)))
+        } else if let Some(deployment) = read_exact(bytes) {
+            Some(Self::Deployment(Box::new(deployment)))
+        } else if let Some(execution) = read_exact(bytes) {
+            Some(Self::Execution(Box::new(execution)))
+        } else {
+            read_exact(bytes).map(|fee| Self::Fee(Box::new(fee)))
+        }
+    }
+}
+
+/// Returns the object, if it is read from the given bytes with no bytes remaining.
+fn read_exact<T: FromBytes>(mut bytes: &[u8]) -> Option<T> {
+    let object = T::read_le(&mut bytes).ok()?;
+    match bytes.is_empty() {
+        true => Some(object),
+        false => None,
+    }
+}
+
+/// Returns the bytes of the given hex-encoded string, if it is valid hex.
+fn decode_hex(string: &str) -> Option<Vec<u8>> {
+    let string = string.strip_prefix("0x").unwrap_or(string);
+    if string.is_empty() || string.len() % 2 != 0 {
+        return None;
+    }
+    (0..string.len()).step_by(2).map(|i| u8::from_str_radix(string.get(i..i + 2)?, 16).ok()).collect()
+}
+
+impl Inspect {
+    /// Decodes the object in the file, and verifies it with the bundled `credits.aleo` keys.
+    pub fn start(&self) -> Result<String> {
+        // Parse the object.
+        let object = Object::<CurrentNetwork>::parse(&std::fs::read(&self.file)?)?;
+        // Load the process, with the bundled `credits.aleo` program and keys.
+        let process = Process::<CurrentNetwork>::load()?;
+
+        let mut output = String::new();
+        match object {
+            Object::Block(block) => Self::write_block(&mut output, &process, &block)?,
+            Object::Transaction(transaction) => Self::write_transaction(&mut output, &process, &transaction)?,
+            Object::Deployment(deployment) => {
+                writeln!(output, "\n🔎 {} for '{}'", "Deployment".bold(), deployment.program_id())?;
+                Self::write_deployment(&mut output, &deployment)?;
+                Self::write_status(&mut output, Self::verify_deployment(&process, &deployment))?;
+            }
+            Object::Execution(execution) => {
+                writeln!(output, "\n🔎 {}", "Execution".bold())?;
+                Self::write_execution(&mut output, &execution)?;
+                Self::write_status(&mut output, Self::verify_execution(&process, &execution))?;
+            }
+            Object::Fee(fee) => {
+                writeln!(output, "\n🔎 {}", "Fee".bold())?;
+                Self::write_transition(&mut output, fee.transition())?;
+                Self::write_status(&mut output, process.verify_fee(&fee).map(|_| true))?;
+            }
+        }
+        Ok(output)
+    }
+
+    /// Writes the given block, and the verification of its hash, signature, and transactions.
+    fn write_block(
+        output: &mut String,
+        process: &Process<CurrentNetwork>,
+        block: &Block<CurrentNetwork>,
+    ) -> Result<()> {
+        writeln!(output, "\n🔎 {} {} at height {}", "Block".bold(), block.hash(), block.height())?;
+        writeln!(output, "   {:<18} {}", "previous hash", block.previous_hash())?;
+        writeln!(output, "   {:<18} {}", "previous state root", block.previous_state_root())?;
+        writeln!(output, "   {:<18} {}", "transactions root", block.transactions_root())?;
+        writeln!(output, "   {:<18} {}", "round", block.round())?;
+        writeln!(output, "   {:<18} {}", "timestamp", block.timestamp())?;
+        writeln!(output, "   {:<18} {}", "transactions", block.transactions().len())?;
+        Self::write_status(output, block.check_hash_and_signature().map(|_| true))?;
+
+        for transaction in block.transactions().iter() {
+            Self::write_transaction(output, process, transaction)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the given transaction, and its verification.
+    fn write_transaction(
+        output: &mut String,
+        process: &Process<CurrentNetwork>,
+        transaction: &Transaction<CurrentNetwork>,
+    ) -> Result<()> {
+        match transaction {
+            Transaction::Deploy(id, deployment, fee) => {
+                writeln!(output, "\n🔎 {} {id} (deploy '{}')", "Transaction".bold(), deployment.program_id())?;
+                Self::write_deployment(output, deployment)?;
+                Self::write_transition(output, fee.transition())?;
+            }
+            Transaction::Execute(id, execution, additional_fee) => {
+                writeln!(output, "\n🔎 {} {id} (execute)", "Transaction".bold())?;
+                Self::write_execution(output, execution)?;
+                if let Some(additional_fee) = additional_fee {
+                    Self::write_transition(output, additional_fee.transition())?;
+                }
+            }
+        }
+        Self::write_status(output, Self::verify_transaction(process, transaction))
+    }
+
+    /// Writes the program and verifying keys of the given deployment.
+    fn write_deployment(output: &mut String, deployment: &Deployment<CurrentNetwork>) -> Result<()> {
+        writeln!(output, "   {:<18} {}", "edition", deployment.edition())?;
+        writeln!(output, "   {:<18} {}", "functions", deployment.verifying_keys().keys().join(", "))?;
+        writeln!(output, "\n{}", deployment.program())?;
+        Ok(())
+    }
+
+    /// Writes the transitions of the given execution.
+    fn write_execution(output: &mut String, execution: &Execution<CurrentNetwork>) -> Result<()> {
+        writeln!(output, "   {:<18} {}", "global state root", execution.global_state_root())?;
+        execution.transitions().try_for_each(|transition| Self::write_transition(output, transition))
+    }
+
+    /// Writes the given transition, with its inputs, outputs, transition commitment, and fee.
+    fn write_transition(output: &mut String, transition: &Transition<CurrentNetwork>) -> Result<()> {
+        writeln!(output, "\n • {} {}", "Transition".bold(), transition.id())?;
+        writeln!(output, "   {:<18} {}/{}", "function", transition.program_id(), transition.function_name())?;
+        writeln!(output, "   {:<18} {}", "tpk", transition.tpk())?;
+        writeln!(output, "   {:<18} {}", "tcm", transition.tcm())?;
+        writeln!(output, "   {:<18} {}", "fee", transition.fee())?;
+        writeln!(output, "   inputs")?;
+        for input in transition.inputs() {
+            writeln!(output, "     - {input}")?;
+        }
+        writeln!(output, "   outputs")?;
+        for output_ in transition.outputs() {
+            writeln!(output, "     - {output_}")?;
+        }
+        if let Some(finalize) = transition.finalize() {
+            writeln!(output, "   finalize")?;
+            for value in finalize {
+                writeln!(output, "     - {value}")?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the verification status, where `Ok(false)` indicates the verification was skipped.
+    fn write_status(output: &mut String, status: Result<bool>) -> Result<()> {
+        match status {
+            Ok(true) => writeln!(output, "\n   ✅ Verified")?,
+            Ok(false) => writeln!(output, "\n   ⚠️  Skipped verification, as a program is not available offline")?,
+            Err(error) => writeln!(output, "\n   ❌ {error}")?,
+        }
+        Ok(())
+    }
+
+    /// Verifies the given transaction, returning `false` if a program is not available offline.
+    fn verify_transaction(
+        process: &Process<CurrentNetwork>,
+        transaction: &Transaction<CurrentNetwork>,
+    ) -> Result<bool> {
+        // Ensure the transaction ID is correct.
+        ensure!(*transaction.id() == transaction.to_root()?, "Incorrect transaction ID ({})", transaction.id());
+
+        match transaction {
+            Transaction::Deploy(_, deployment, fee) => {
+                // Verify the fee.
+                process.verify_fee(fee)?;
+                // Verify the deployment.
+                Self::verify_deployment(process, deployment)
+            }
+            Transaction::Execute(_, execution, additional_fee) => {
+                // Verify the additional fee, if it exists.
+                if let Some(additional_fee) = additional_fee {
+                    process.verify_fee(additional_fee)?;
+                }
+                // Verify the execution.
+                Self::verify_execution(process, execution)
+            }
+        }
+    }
+
+    /// Verifies the given deployment, returning `false` if an imported program is not available offline.
+    fn verify_deployment(process: &Process<CurrentNetwork>, deployment: &Deployment<CurrentNetwork>) -> Result<bool> {
+        // Ensure the imported programs are available.
+        if !deployment.program().imports().keys().all(|program_id| process.contains_program(program_id)) {
+            return Ok(false);
+        }
+        // Verify the deployment.
+        process.verify_deployment::<CurrentAleo, _>(deployment, &mut rand::thread_rng())?;
+        Ok(true)
+    }
+
+    /// Verifies the given execution, returning `false` if a program is not available offline.
+    /// Note: The global state root is not checked against the ledger.
+    fn verify_execution(process: &Process<CurrentNetwork>, execution: &Execution<CurrentNetwork>) -> Result<bool> {
+        // Ensure the executed programs are available.
+        if !execution.transitions().all(|transition| process.contains_program(transition.program_id())) {
+            return Ok(false);
+        }
+        // Verify the execution.
+        process.verify_execution::<true>(execution)?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::ToBytes;
+
+    #[test]
+    fn test_decode_hex() {
+        assert_eq!(decode_hex("0x00ff10"), Some(vec![0x00, 0xff, 0x10]));
+        assert_eq!(decode_hex("00ff10"), Some(vec![0x00, 0xff, 0x10]));
+        assert_eq!(decode_hex("00f"), None);
+        assert_eq!(decode_hex("zz"), None);
+        assert_eq!(decode_hex(""), None);
+    }
+
+    #[test]
+    fn test_parse_genesis_block() {
+        // Load the genesis block.
+        let genesis = Block::<CurrentNetwork>::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap();
+
+        // Ensure the genesis block is detected from its bytes, hex-encoded bytes, and JSON.
+        let bytes = genesis.to_bytes_le().unwrap();
+        let hex = bytes.iter().map(|byte| format!("{byte:02x}")).collect::<String>();
+        let json = genesis.to_string();
+        for contents in [bytes, hex.into_bytes(), json.into_bytes()] {
+            match Object::<CurrentNetwork>::parse(&contents).unwrap() {
+                Object::Block(block) => assert_eq!(*block, genesis),
+                _ => panic!("Expected the genesis block to be detected as a block"),
+            }
+        }
+
+        // Ensure the transactions in the genesis block are detected as transactions.
+        for transaction in genesis.transactions().iter() {
+            match Object::<CurrentNetwork>::parse(&transaction.to_bytes_le().unwrap()).unwrap() {
+                Object::Transaction(candidate) => assert_eq!(*candidate, *transaction),
+                _ => panic!("Expected the genesis transaction to be detected as a transaction"),
+            }
+        }
+
+        // Ensure the genesis block is verified.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+        assert!(genesis.check_hash_and_signature().is_ok());
+        for transaction in genesis.transactions().iter() {
+            assert!(Inspect::verify_transaction(&process, transaction).unwrap());
+        }
+    }
+}
@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod account;
+pub use account::*;
+
+mod build;
+pub use build::*;
+
+mod clean;
+pub use clean::*;
+
+mod devnet;
+pub use devnet::*;
+
+mod execute;
+pub use execute::*;
+
+mod inspect;
+pub use inspect::*;
+
+mod new;
+pub use new::*;
+
+mod record;
+pub use record::*;
+
+mod run;
+pub use run::*;
+
+mod test;
+pub use test::*;
+
+mod update;
+pub use update::*;
+
+use crate::{
+    package::Package,
+    prelude::{Identifier, PrivateKey, ProgramID, Value},
+};
+
+use anyhow::{ensure, Result};
+use clap::Parser;
+use colored::Colorize;
+use core::str::FromStr;
+
+pub(crate) type CurrentNetwork = crate::prelude::Testnet3;
+pub(crate) type CurrentAleo = crate::circuit::network::AleoV0;
+
+#[derive(Debug, Parser)]
+pub enum Command {
+    #[clap(subcommand)]
+    Account(Account),
+    #[clap(name = "build")]
+    Build(Build),
+    #[clap(name = "clean")]
+    Clean(Clean),
+    #[clap(name = "devnet")]
+    Devnet(Devnet),
+    #[clap(name = "execute")]
+    Execute(Execute),
+    #[clap(name = "inspect")]
+    Inspect(Inspect),
+    #[clap(name = "new")]
+    New(New),
+    #[clap(subcommand)]
+    Record(Record),
+    #[clap(name = "run")]
+    Run(Run),
+    #[clap(name = "test")]
+    Test(Test),
+    #[clap(name = "update")]
+    Update(Update),
+}
+
+impl Command {
+    /// Parse the command.
+    pub fn start(&self) -> Result<String> {
+        match self {
+            Self::Account(command) => command.start(),
+            Self::Build(command) => command.start(),
+            Self::Clean(command) => command.start(),
+            Self::Devnet(command) => command.start(),
+            Self::Execute(command) => command.start(),
+            Self::Inspect(command) => command.start(),
+            Self::New(command) => command.start(),
+            Self::Record(command) => command.start(),
+            Self::Run(command) => command.start(),
+            Self::Test(command) => command.start(),
+            Self::Update(command) => command.start(),
+        }
+    }
+}
@@ -0,0 +1,42 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// Create a new Aleo package
+#[derive(Debug, Parser)]
+pub struct New {
+    /// The program name, with or without the '.aleo' suffix
+    name: String,
+}
+
+impl New {
+    /// Creates a new package in a directory named after the program.
+    pub fn start(&self) -> Result<String> {
+        // Parse the program ID, appending the '.aleo' suffix if it is omitted.
+        let program_id = match self.name.contains('.') {
+            true => ProgramID::<CurrentNetwork>::from_str(&self.name)?,
+            false => ProgramID::<CurrentNetwork>::from_str(&format!("{}.aleo", self.name))?,
+        };
+
+        // Prepare the package directory.
+        let directory = std::env::current_dir()?.join(program_id.name().to_string());
+        // Create the package.
+        let _package = Package::<CurrentNetwork>::create(&directory, &program_id)?;
+
+        Ok(format!("✅ Created an Aleo program '{}' at '{}'", program_id.to_string().bold(), directory.display()))
+    }
+}
@@ -0,0 +1,92 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::prelude::{Ciphertext, ViewKey};
+
+/// The record type of the current network.
+type ProgramRecord<N> = crate::prelude::Record<N, Ciphertext<N>>;
+
+/// Manage program records
+#[derive(Debug, Parser)]
+pub enum Record {
+    /// Decrypts a record ciphertext with the given view key
+    Decrypt {
+        /// The record ciphertext
+        ciphertext: ProgramRecord<CurrentNetwork>,
+        /// The view key of the record owner
+        #[clap(long)]
+        view_key: ViewKey<CurrentNetwork>,
+        /// Prints the record as JSON
+        #[clap(long)]
+        json: bool,
+    },
+}
+
+impl Record {
+    /// Runs the record command.
+    pub fn start(&self) -> Result<String> {
+        match self {
+            Self::Decrypt { ciphertext, view_key, json } => {
+                // Ensure the view key owns the record.
+                ensure!(ciphertext.is_owner(view_key), "The given view key does not own the record");
+                // Decrypt the record.
+                let record = ciphertext.decrypt(view_key)?;
+                match json {
+                    true => Ok(serde_json::to_string_pretty(&serde_json::json!({ "record": record }))?),
+                    false => Ok(record.to_string()),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{Address, Network, Plaintext, Scalar, Uniform};
+
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_decrypt() {
+        let rng = &mut StdRng::seed_from_u64(1234);
+
+        // Sample an owner.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let owner = Address::try_from(&private_key).unwrap();
+
+        // Sample a randomizer and its corresponding nonce.
+        let randomizer = Scalar::rand(rng);
+        let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+
+        // Encrypt a record to the owner.
+        let plaintext = crate::prelude::Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {owner}.private, gates: 5u64.private, amount: 100u64.private, _nonce: {nonce}.public }}"
+        ))
+        .unwrap();
+        let ciphertext = plaintext.encrypt(randomizer).unwrap();
+
+        // Ensure the owner can decrypt the record.
+        let output = Record::Decrypt { ciphertext: ciphertext.clone(), view_key, json: false }.start().unwrap();
+        assert!(output.contains("100u64"));
+
+        // Ensure a different view key cannot decrypt the record.
+        let view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        assert!(Record::Decrypt { ciphertext, view_key, json: false }.start().is_err());
+    }
+}
@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// Run a program function with the given inputs, without proving
+#[derive(Debug, Parser)]
+pub struct Run {
+    /// The function name
+    function: Identifier<CurrentNetwork>,
+    /// The function inputs
+    inputs: Vec<String>,
+    /// Uses the given private key, instead of the development private key in the manifest
+    #[clap(long)]
+    private_key: Option<PrivateKey<CurrentNetwork>>,
+}
+
+impl Run {
+    /// Runs the function of the package in the current directory, by evaluating it.
+    pub fn start(&self) -> Result<String> {
+        // Parse the inputs.
+        let inputs = self.inputs.iter().map(|input| Value::from_str(input)).collect::<Result<Vec<_>>>()?;
+
+        // Open the package at the current directory.
+        let package = Package::<CurrentNetwork>::open(&std::env::current_dir()?)?;
+        // Retrieve the private key.
+        let private_key = match self.private_key {
+            Some(private_key) => private_key,
+            None => *package.manifest_file().development_private_key(),
+        };
+
+        // Run the function.
+        let response = package.run::<CurrentAleo, _>(&private_key, self.function, &inputs, &mut rand::thread_rng())?;
+
+        // Prepare the outputs.
+        let mut output = format!("\n➡️  Output{}\n\n", if response.outputs().len() == 1 { "" } else { "s" });
+        for value in response.outputs() {
+            output += &format!(" • {}\n", value.to_string().replace('\n', "\n   "));
+        }
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{Command, CLI};
+
+    #[test]
+    fn test_parse_run() {
+        let cli = CLI::try_parse_from(["snarkvm", "run", "mint", "aleo1abc", "100u64"]);
+        match cli.unwrap().command {
+            Command::Run(run) => {
+                assert_eq!(run.function.to_string(), "mint");
+                assert_eq!(run.inputs, vec!["aleo1abc".to_string(), "100u64".to_string()]);
+                assert!(run.private_key.is_none());
+            }
+            command => panic!("Expected the 'run' command, found {command:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_run_with_invalid_function_name() {
+        assert!(CLI::try_parse_from(["snarkvm", "run", "1mint"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_run_rejects_proof() {
+        assert!(CLI::try_parse_from(["snarkvm", "run", "mint", "--proof", "execution.json"]).is_err());
+    }
+}
@@ -0,0 +1,57 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use anyhow::bail;
+
+/// Run the tests of the package in the current directory
+#[derive(Debug, Parser)]
+pub struct Test {
+    /// Executes each function with proving, instead of evaluating it
+    #[clap(long)]
+    prove: bool,
+    /// Uses the given endpoint to synthesize the proving and verifying keys
+    #[clap(long)]
+    endpoint: Option<String>,
+}
+
+impl Test {
+    /// Runs the tests of the package in the current directory, and reports each result.
+    pub fn start(&self) -> Result<String> {
+        // Open the package at the current directory.
+        let package = Package::<CurrentNetwork>::open(&std::env::current_dir()?)?;
+        // Run the tests.
+        let results = package.test::<CurrentAleo, _>(self.endpoint.clone(), self.prove, &mut rand::thread_rng())?;
+
+        // Report the result of each test.
+        let program_id = package.program_id().to_string();
+        let mut output = format!("\n🧪 Running {} test(s) for '{}'\n\n", results.len(), program_id.bold());
+        for result in &results {
+            match result.failure() {
+                None => output += &format!(" ✅ {}\n", result.name()),
+                Some(failure) => output += &format!(" ❌ {} - {failure}\n", result.name()),
+            }
+        }
+
+        // Ensure every test passed.
+        let num_failed = results.iter().filter(|result| !result.is_passed()).count();
+        match num_failed {
+            0 => Ok(format!("{output}\n✅ All {} test(s) passed", results.len())),
+            _ => bail!("{output}\n{num_failed} of {} test(s) failed", results.len()),
+        }
+    }
+}
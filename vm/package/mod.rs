@@ -17,11 +17,14 @@
 mod build;
 mod clean;
 mod deploy;
+mod execute;
 mod is_build_required;
 mod run;
+mod test;
 
 pub use build::{BuildRequest, BuildResponse};
 pub use deploy::{DeployRequest, DeployResponse};
+pub use test::{TestCase, TestResult};
 
 use crate::{
     file::{AVMFile, AleoFile, Manifest, ProverFile, VerifierFile, README},
@@ -319,6 +322,51 @@ function transfer:
         (directory, package)
     }
 
+    /// Samples a (temporary) package containing a program with a mapping and a finalize scope.
+    pub(crate) fn sample_package_with_mapping() -> (PathBuf, Package<CurrentNetwork>) {
+        // Initialize a temporary directory.
+        let directory = temp_dir();
+
+        // Initialize the program ID.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("counter.aleo").unwrap();
+        // Initialize the program.
+        let program_string = format!(
+            "
+program {program_id};
+
+mapping counts:
+    key owner as address.public;
+    value count as u64.public;
+
+function add:
+    input r0 as address.public;
+    input r1 as u64.public;
+    sub 10u64 r1 into r2;
+    output r2 as u64.public;
+    finalize r0 r1;
+
+finalize add:
+    input r0 as address.public;
+    input r1 as u64.public;
+    increment counts[r0] by r1;"
+        );
+
+        // Write the program string to a file in the temporary directory.
+        let main_filepath = directory.join("main.aleo");
+        let mut file = File::create(&main_filepath).unwrap();
+        file.write_all(program_string.as_bytes()).unwrap();
+
+        // Create the manifest file.
+        let _manifest_file = Manifest::create(&directory, &program_id).unwrap();
+
+        // Open the package at the temporary directory.
+        let package = Package::<CurrentNetwork>::open(&directory).unwrap();
+        assert_eq!(package.program_id(), &program_id);
+
+        // Return the temporary directory and the package.
+        (directory, package)
+    }
+
     /// Samples a candidate input to execute the sample package.
     pub(crate) fn sample_package_run(
         program_id: &ProgramID<CurrentNetwork>,
@@ -15,19 +15,16 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
-use snarkvm_synthesizer::CallMetrics;
 
 impl<N: Network> Package<N> {
-    /// Runs a program function with the given inputs.
-    #[allow(clippy::type_complexity)]
+    /// Runs a program function with the given inputs, by evaluating it without proving.
     pub fn run<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>, R: Rng + CryptoRng>(
         &self,
-        endpoint: Option<String>,
         private_key: &PrivateKey<N>,
         function_name: Identifier<N>,
         inputs: &[Value<N>],
         rng: &mut R,
-    ) -> Result<(Response<N>, Execution<N>, Inclusion<N>, Vec<CallMetrics<N>>)> {
+    ) -> Result<Response<N>> {
         // Retrieve the main program.
         let program = self.program();
         // Retrieve the program ID.
@@ -37,69 +34,18 @@ impl<N: Network> Package<N> {
             bail!("Function '{function_name}' does not exist.")
         }
 
-        // Build the package, if the package requires building.
-        self.build::<A>(endpoint)?;
-
         // Prepare the locator (even if logging is disabled, to sanity check the locator is well-formed).
         let _locator = Locator::<N>::from_str(&format!("{program_id}/{function_name}"))?;
 
         #[cfg(feature = "aleo-cli")]
-        println!("🚀 Executing '{}'...\n", _locator.to_string().bold());
+        println!("🚀 Running '{}'...\n", _locator.to_string().bold());
 
         // Construct the process.
         let process = self.get_process()?;
         // Authorize the function call.
         let authorization = process.authorize::<A, R>(private_key, program_id, function_name, inputs.iter(), rng)?;
-
-        // Retrieve the program.
-        let program = process.get_program(program_id)?;
-        // Retrieve the function from the program.
-        let function = program.get_function(&function_name)?;
-        // Save all the prover and verifier files for any function calls that are made.
-        for instruction in function.instructions() {
-            if let Instruction::Call(call) = instruction {
-                // Retrieve the program and resource.
-                let (program, resource) = match call.operator() {
-                    CallOperator::Locator(locator) => (process.get_program(locator.program_id())?, locator.resource()),
-                    CallOperator::Resource(resource) => (program, resource),
-                };
-                // If this is a function call, save its corresponding prover and verifier files.
-                if program.contains_function(resource) {
-                    // Set the function name to the resource, in this scope.
-                    let function_name = resource;
-                    // Prepare the build directory for the imported program.
-                    let import_build_directory =
-                        self.build_directory().join(format!("{}-{}", program.id().name(), program.id().network()));
-
-                    // Create the prover.
-                    let prover = ProverFile::open(&import_build_directory, function_name)?;
-                    // Adds the proving key to the process.
-                    process.insert_proving_key(program.id(), function_name, prover.proving_key().clone())?;
-
-                    // Create the verifier.
-                    let verifier = VerifierFile::open(&import_build_directory, function_name)?;
-                    // Adds the verifying key to the process.
-                    process.insert_verifying_key(program.id(), function_name, verifier.verifying_key().clone())?;
-                }
-            }
-        }
-
-        // Prepare the build directory.
-        let build_directory = self.build_directory();
-        // Load the prover.
-        let prover = ProverFile::open(&build_directory, &function_name)?;
-        // Load the verifier.
-        let verifier = VerifierFile::open(&build_directory, &function_name)?;
-
-        // Adds the proving key to the process.
-        process.insert_proving_key(program_id, &function_name, prover.proving_key().clone())?;
-        // Adds the verifying key to the process.
-        process.insert_verifying_key(program_id, &function_name, verifier.verifying_key().clone())?;
-
-        // Execute the circuit.
-        let (response, execution, inclusion, metrics) = process.execute::<A, R>(authorization, rng)?;
-
-        Ok((response, execution, inclusion, metrics))
+        // Evaluate the function.
+        process.evaluate::<A>(authorization)
     }
 }
 
@@ -114,21 +60,16 @@ mod tests {
         // Samples a new package at a temporary directory.
         let (directory, package) = crate::package::test_helpers::sample_package();
 
-        // Ensure the build directory does *not* exist.
-        assert!(!package.build_directory().exists());
-        // Build the package.
-        package.build::<CurrentAleo>(None).unwrap();
-        // Ensure the build directory exists.
-        assert!(package.build_directory().exists());
-
         // Initialize an RNG.
         let rng = &mut TestRng::default();
         // Sample the function inputs.
         let (private_key, function_name, inputs) =
             crate::package::test_helpers::sample_package_run(package.program_id());
         // Run the program function.
-        let (_response, _execution, _inclusion, _metrics) =
-            package.run::<CurrentAleo, _>(None, &private_key, function_name, &inputs, rng).unwrap();
+        let response = package.run::<CurrentAleo, _>(&private_key, function_name, &inputs, rng).unwrap();
+        assert_eq!(response.outputs().len(), 1);
+        // Ensure the package was not built, as running does not require the proving keys.
+        assert!(!package.build_directory().exists());
 
         // Proactively remove the temporary directory (to conserve space).
         std::fs::remove_dir_all(directory).unwrap();
@@ -139,21 +80,14 @@ mod tests {
         // Samples a new package at a temporary directory.
         let (directory, package) = crate::package::test_helpers::sample_package_with_import();
 
-        // Ensure the build directory does *not* exist.
-        assert!(!package.build_directory().exists());
-        // Build the package.
-        package.build::<CurrentAleo>(None).unwrap();
-        // Ensure the build directory exists.
-        assert!(package.build_directory().exists());
-
         // Initialize an RNG.
         let rng = &mut TestRng::default();
         // Sample the function inputs.
         let (private_key, function_name, inputs) =
             crate::package::test_helpers::sample_package_run(package.program_id());
         // Run the program function.
-        let (_response, _execution, _inclusion, _metrics) =
-            package.run::<CurrentAleo, _>(None, &private_key, function_name, &inputs, rng).unwrap();
+        let response = package.run::<CurrentAleo, _>(&private_key, function_name, &inputs, rng).unwrap();
+        assert_eq!(response.outputs().len(), 2);
 
         // Proactively remove the temporary directory (to conserve space).
         std::fs::remove_dir_all(directory).unwrap();
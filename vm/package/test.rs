@@ -0,0 +1,552 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{
+    prelude::Plaintext,
+    synthesizer::{FinalizeGlobalState, ProgramMemory, ProgramStore},
+};
+
+/// The entries of the mappings of the main program, as `(mapping name, [(key, value)])`.
+type MappingEntries<N> = Vec<(Identifier<N>, Vec<(Plaintext<N>, Value<N>)>)>;
+
+/// A test of a function in the main program, as specified in a JSON file in the `tests` directory:
+/// ```json
+/// {
+///     "function": "transfer_public",
+///     "inputs": ["aleo1...", "5u64"],
+///     "outputs": ["5u64", null],
+///     "expect_failure": false,
+///     "mappings": { "account": { "aleo1...": "10u64" } },
+///     "finalize": { "account": { "aleo1...": "5u64" } }
+/// }
+/// ```
+/// The `outputs` are optional, and a `null` output matches any output (such as a record).
+/// If `expect_failure` is `true`, the test passes only if the function or its finalize logic fails.
+/// If `expect_failure` is a string, the test passes only if it fails with an error that contains the string.
+/// A test that can not be set up, such as one whose starting `mappings` are invalid, fails regardless.
+/// The `mappings` are the optional starting entries of the mappings, and `finalize` optionally lists
+/// the expected entries of the given mappings, once the function is finalized.
+pub struct TestCase<N: Network> {
+    /// The test name.
+    name: String,
+    /// The function name.
+    function_name: Identifier<N>,
+    /// The function inputs.
+    inputs: Vec<Value<N>>,
+    /// The expected outputs, where `None` matches any output.
+    outputs: Option<Vec<Option<Value<N>>>>,
+    /// Whether the function, or its finalize logic, is expected to fail.
+    expect_failure: bool,
+    /// The expected substring of the error, if the test is expected to fail with a specific error.
+    expected_error: Option<String>,
+    /// The starting entries of the mappings.
+    mappings: MappingEntries<N>,
+    /// The expected entries of the mappings, once the function is finalized.
+    finalize: Option<MappingEntries<N>>,
+}
+
+impl<N: Network> TestCase<N> {
+    /// Opens the test case at the given path.
+    pub fn open(path: &Path) -> Result<Self> {
+        // Retrieve the test name.
+        let name = match path.file_stem().and_then(|stem| stem.to_str()) {
+            Some(name) => name.to_string(),
+            None => bail!("Invalid test file name: {}", path.display()),
+        };
+        // Parse the test file.
+        let json: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+
+        // Retrieve the function name.
+        let function_name = match json["function"].as_str() {
+            Some(function_name) => Identifier::from_str(function_name)?,
+            None => bail!("Test '{name}' is missing the function name"),
+        };
+        // Retrieve the inputs.
+        let inputs = match json["inputs"].as_array() {
+            Some(inputs) => inputs.iter().map(Self::parse_value).collect::<Result<Vec<_>>>()?,
+            None => vec![],
+        };
+        // Retrieve the expected outputs.
+        let outputs = match json["outputs"].as_array() {
+            Some(outputs) => Some(
+                outputs
+                    .iter()
+                    .map(|output| match output.is_null() {
+                        true => Ok(None),
+                        false => Self::parse_value(output).map(Some),
+                    })
+                    .collect::<Result<Vec<_>>>()?,
+            ),
+            None => None,
+        };
+        // Retrieve whether the test is expected to fail, and optionally with which error.
+        let (expect_failure, expected_error) = match &json["expect_failure"] {
+            serde_json::Value::Null => (false, None),
+            serde_json::Value::Bool(expect_failure) => (*expect_failure, None),
+            serde_json::Value::String(expected_error) => (true, Some(expected_error.clone())),
+            expect_failure => bail!("Expected 'expect_failure' as a boolean or a string, found '{expect_failure}'"),
+        };
+        // Retrieve the starting mappings.
+        let mappings = match json["mappings"].is_null() {
+            true => vec![],
+            false => Self::parse_mappings(&json["mappings"])?,
+        };
+        // Retrieve the expected mappings, once finalized.
+        let finalize = match json["finalize"].is_null() {
+            true => None,
+            false => Some(Self::parse_mappings(&json["finalize"])?),
+        };
+
+        Ok(Self { name, function_name, inputs, outputs, expect_failure, expected_error, mappings, finalize })
+    }
+
+    /// Returns the test name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the function name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the function inputs.
+    pub fn inputs(&self) -> &[Value<N>] {
+        &self.inputs
+    }
+
+    /// Returns `true` if the function, or its finalize logic, is expected to fail.
+    pub const fn expect_failure(&self) -> bool {
+        self.expect_failure
+    }
+
+    /// Returns the expected substring of the error, if the test is expected to fail with a specific error.
+    pub fn expected_error(&self) -> Option<&str> {
+        self.expected_error.as_deref()
+    }
+
+    /// Parses a value from the given JSON string.
+    fn parse_value(value: &serde_json::Value) -> Result<Value<N>> {
+        match value.as_str() {
+            Some(value) => Value::from_str(value),
+            None => bail!("Expected a value as a string, found '{value}'"),
+        }
+    }
+
+    /// Parses the mapping entries from the given JSON object, of `{ mapping name: { key: value } }`.
+    fn parse_mappings(mappings: &serde_json::Value) -> Result<MappingEntries<N>> {
+        let mappings = match mappings.as_object() {
+            Some(mappings) => mappings,
+            None => bail!("Expected the mappings as an object, found '{mappings}'"),
+        };
+        mappings
+            .iter()
+            .map(|(mapping_name, entries)| {
+                let entries = match entries.as_object() {
+                    Some(entries) => entries,
+                    None => bail!("Expected the entries of mapping '{mapping_name}' as an object, found '{entries}'"),
+                };
+                let entries = entries
+                    .iter()
+                    .map(|(key, value)| Ok((Plaintext::from_str(key)?, Self::parse_value(value)?)))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok((Identifier::from_str(mapping_name)?, entries))
+            })
+            .collect()
+    }
+}
+
+/// The result of a test case.
+pub struct TestResult {
+    /// The test name.
+    name: String,
+    /// The reason the test failed, if it failed.
+    failure: Option<String>,
+}
+
+impl TestResult {
+    /// Returns the test name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns `true` if the test passed.
+    pub const fn is_passed(&self) -> bool {
+        self.failure.is_none()
+    }
+
+    /// Returns the reason the test failed, if it failed.
+    pub fn failure(&self) -> Option<&str> {
+        self.failure.as_deref()
+    }
+}
+
+impl<N: Network> Package<N> {
+    /// Returns the tests directory.
+    pub fn tests_directory(&self) -> PathBuf {
+        self.directory.join("tests")
+    }
+
+    /// Returns the test cases in the tests directory, sorted by name.
+    pub fn get_test_cases(&self) -> Result<Vec<TestCase<N>>> {
+        // Prepare the tests directory.
+        let tests_directory = self.tests_directory();
+        // If the tests directory does not exist, there are no tests.
+        if !tests_directory.exists() {
+            return Ok(vec![]);
+        }
+
+        // Retrieve the paths of the test files.
+        let mut paths = std::fs::read_dir(&tests_directory)?
+            .map(|entry| Ok(entry?.path()))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .filter(|path| path.extension().map_or(false, |extension| extension == "json"))
+            .collect::<Vec<_>>();
+        paths.sort();
+
+        // Open each test case.
+        paths.iter().map(|path| TestCase::open(path)).collect()
+    }
+
+    /// Runs the test cases in the tests directory, with the development private key.
+    /// If `prove` is `true`, each function is executed with proving and verified, otherwise it is evaluated.
+    pub fn test<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>, R: Rng + CryptoRng>(
+        &self,
+        endpoint: Option<String>,
+        prove: bool,
+        rng: &mut R,
+    ) -> Result<Vec<TestResult>> {
+        // Retrieve the test cases.
+        let test_cases = self.get_test_cases()?;
+
+        // Construct the process.
+        let process = self.get_process()?;
+        // If the tests are proven, build the package, and load its verifying keys to verify each execution.
+        if prove {
+            self.build::<A>(endpoint.clone())?;
+            self.insert_verifying_keys(&process)?;
+        }
+
+        let mut results = Vec::with_capacity(test_cases.len());
+        for test_case in test_cases {
+            // Set up the program store of the test case, where a setup error fails the test regardless.
+            let store = match self.setup_test_case(&process, &test_case) {
+                Ok(store) => store,
+                Err(error) => {
+                    let failure = Some(format!("Failed to set up the test: {error}"));
+                    results.push(TestResult { name: test_case.name, failure });
+                    continue;
+                }
+            };
+            // Run the test case, where a halt (such as a failed assertion) is an error of the test case.
+            let result = catch_halt::<A, _>(|| {
+                self.run_test_case::<A, R>(&process, &test_case, &store, endpoint.clone(), prove, rng)
+            });
+            // Check the result against the expectations of the test case.
+            let failure = match (result, test_case.expect_failure) {
+                (Ok(_), true) => Some("Expected a failure, but the test succeeded".to_string()),
+                (Ok(response), false) => {
+                    self.check_test_case(&test_case, &response, &store).err().map(|error| error.to_string())
+                }
+                (Err(error), true) => match &test_case.expected_error {
+                    Some(expected_error) if !error.to_string().contains(expected_error.as_str()) => {
+                        Some(format!("Expected an error containing '{expected_error}', found '{error}'"))
+                    }
+                    _ => None,
+                },
+                (Err(error), false) => Some(error.to_string()),
+            };
+            results.push(TestResult { name: test_case.name, failure });
+        }
+        Ok(results)
+    }
+
+    /// Inserts the verifying keys in the build directory into the given process,
+    /// for the functions of the main program, and the imported functions it calls.
+    fn insert_verifying_keys(&self, process: &Process<N>) -> Result<()> {
+        // Insert the verifying keys of the imported functions.
+        for program_id in self.program().imports().keys() {
+            // Prepare the build directory for the imported program.
+            let import_build_directory =
+                self.build_directory().join(format!("{}-{}", program_id.name(), program_id.network()));
+            for function_name in process.get_program(program_id)?.functions().keys() {
+                // Note: Only the imported functions that are called have a verifier file.
+                if VerifierFile::<N>::exists_at(&import_build_directory, function_name) {
+                    let verifier = VerifierFile::open(&import_build_directory, function_name)?;
+                    process.insert_verifying_key(program_id, function_name, verifier.verifying_key().clone())?;
+                }
+            }
+        }
+        // Insert the verifying keys of the functions of the main program.
+        for function_name in self.program().functions().keys() {
+            let verifier = VerifierFile::open(&self.build_directory(), function_name)?;
+            process.insert_verifying_key(self.program_id(), function_name, verifier.verifying_key().clone())?;
+        }
+        Ok(())
+    }
+
+    /// Returns a program store for the given test case, with the mappings of the program and its imports,
+    /// and the starting entries of the mappings of the test case.
+    fn setup_test_case(
+        &self,
+        process: &Process<N>,
+        test_case: &TestCase<N>,
+    ) -> Result<ProgramStore<N, ProgramMemory<N>>> {
+        // Retrieve the program ID.
+        let program_id = self.program_id();
+
+        // Initialize a program store, with the mappings of the program and its imports.
+        let store = ProgramStore::<N, ProgramMemory<N>>::open(None)?;
+        for id in self.program().imports().keys().chain([program_id]) {
+            for mapping_name in process.get_program(id)?.mappings().keys() {
+                store.initialize_mapping(id, mapping_name)?;
+            }
+        }
+        // Insert the starting entries of the mappings.
+        for (mapping_name, entries) in &test_case.mappings {
+            for (key, value) in entries {
+                store.insert_key_value(program_id, mapping_name, key.clone(), value.clone())?;
+            }
+        }
+        Ok(store)
+    }
+
+    /// Runs the given test case, and returns the response once finalized into the given program store.
+    fn run_test_case<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>, R: Rng + CryptoRng>(
+        &self,
+        process: &Process<N>,
+        test_case: &TestCase<N>,
+        store: &ProgramStore<N, ProgramMemory<N>>,
+        endpoint: Option<String>,
+        prove: bool,
+        rng: &mut R,
+    ) -> Result<Response<N>> {
+        // Retrieve the program ID.
+        let program_id = self.program_id();
+        // Retrieve the development private key.
+        let private_key = self.manifest_file().development_private_key();
+
+        // Note: The finalize global state is fixed, so tests are reproducible.
+        let state = FinalizeGlobalState::new(1, 0);
+
+        match prove {
+            true => {
+                // Execute the function with proving.
+                let (response, execution, _, _) =
+                    self.execute::<A, R>(endpoint, private_key, test_case.function_name, &test_case.inputs, rng)?;
+                // Verify the execution.
+                process.verify_execution::<false>(&execution)?;
+                // Finalize the execution.
+                process.finalize_execution(state, store, &execution)?;
+                Ok(response)
+            }
+            false => {
+                // Authorize the function call.
+                let authorization = process.authorize::<A, R>(
+                    private_key,
+                    program_id,
+                    test_case.function_name,
+                    test_case.inputs.iter(),
+                    rng,
+                )?;
+                // Evaluate the function.
+                let (response, calls) = process.evaluate_with_finalize::<A>(authorization)?;
+                // Finalize the function calls.
+                process.finalize_evaluation(state, store, &calls)?;
+                Ok(response)
+            }
+        }
+    }
+
+    /// Ensures the given response and finalized program store match the expectations of the test case.
+    fn check_test_case(
+        &self,
+        test_case: &TestCase<N>,
+        response: &Response<N>,
+        store: &ProgramStore<N, ProgramMemory<N>>,
+    ) -> Result<()> {
+        // Ensure the outputs match.
+        if let Some(expected_outputs) = &test_case.outputs {
+            let outputs = response.outputs();
+            ensure!(
+                outputs.len() == expected_outputs.len(),
+                "Expected {} outputs, found {}",
+                expected_outputs.len(),
+                outputs.len()
+            );
+            for (index, (output, expected_output)) in outputs.iter().zip(expected_outputs).enumerate() {
+                if let Some(expected_output) = expected_output {
+                    ensure!(
+                        output == expected_output,
+                        "Expected output {index} to be '{expected_output}', found '{output}'"
+                    );
+                }
+            }
+        }
+
+        // Ensure the mappings match, once finalized.
+        if let Some(expected_mappings) = &test_case.finalize {
+            for (mapping_name, expected_entries) in expected_mappings {
+                let entries = store.get_mapping_entries(self.program_id(), mapping_name)?.unwrap_or_default();
+                // Note: The entries are compared regardless of their order.
+                let is_match = entries.len() == expected_entries.len()
+                    && expected_entries.iter().all(|entry| entries.contains(entry));
+                ensure!(
+                    is_match,
+                    "Expected mapping '{mapping_name}' to contain {}, found {}",
+                    format_entries(expected_entries),
+                    format_entries(&entries)
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Runs the given function, and returns an error with the halt message if it halts.
+/// Note: A failed assertion, or an arithmetic error, halts the console and circuit environments by panicking.
+fn catch_halt<A: crate::circuit::Aleo, T>(function: impl FnOnce() -> Result<T>) -> Result<T> {
+    match std::panic::catch_unwind(std::panic::AssertUnwindSafe(function)) {
+        Ok(result) => result,
+        Err(payload) => {
+            // Reset the circuit environment, as the halt may have left it with a partial circuit.
+            A::reset();
+            // Retrieve the halt message.
+            match (payload.downcast_ref::<String>(), payload.downcast_ref::<&str>()) {
+                (Some(message), _) => bail!("{message}"),
+                (None, Some(message)) => bail!("{message}"),
+                (None, None) => bail!("The function halted"),
+            }
+        }
+    }
+}
+
+/// Returns the given mapping entries, formatted as `{ key: value, ... }`.
+fn format_entries<N: Network>(entries: &[(Plaintext<N>, Value<N>)]) -> String {
+    format!("{{ {} }}", entries.iter().map(|(key, value)| format!("{key}: {value}")).collect::<Vec<_>>().join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_utilities::TestRng;
+
+    use std::{fs::File, io::Write};
+
+    type CurrentNetwork = snarkvm_console::network::Testnet3;
+    type CurrentAleo = snarkvm_circuit::network::AleoV0;
+
+    #[test]
+    fn test_tests_directory() {
+        // Samples a new package at a temporary directory.
+        let (directory, package) = crate::package::test_helpers::sample_package();
+
+        // Ensure the tests directory is correct.
+        assert_eq!(package.tests_directory(), directory.join("tests"));
+        // Ensure there are no tests, when the tests directory does not exist.
+        assert!(package.get_test_cases().unwrap().is_empty());
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_evaluate_tests() {
+        // Samples a new package at a temporary directory.
+        let (directory, package) = crate::package::test_helpers::sample_package_with_mapping();
+        // Retrieve the development address, which is the caller in each test.
+        let caller = package.manifest_file().development_address();
+
+        // Write the test files, which are named to run in order.
+        let tests = [
+            ("a_pass", format!(r#""inputs": ["{caller}", "3u64"], "outputs": ["7u64"],
+                "mappings": {{ "counts": {{ "{caller}": "1u64" }} }},
+                "finalize": {{ "counts": {{ "{caller}": "4u64" }} }}"#)),
+            ("b_wrong_output", format!(r#""inputs": ["{caller}", "3u64"], "outputs": ["8u64"]"#)),
+            ("c_wrong_finalize", format!(r#""inputs": ["{caller}", "3u64"], "finalize": {{ "counts": {{}} }}"#)),
+            ("d_expect_failure", format!(r#""inputs": ["{caller}", "11u64"], "expect_failure": true"#)),
+            ("e_unexpected_success", format!(r#""inputs": ["{caller}", "1u64"], "expect_failure": true"#)),
+            ("f_expect_error", format!(r#""inputs": ["{caller}", "11u64"], "expect_failure": "subtraction failed""#)),
+            ("g_unexpected_error", format!(r#""inputs": ["{caller}", "11u64"], "expect_failure": "overflow""#)),
+            (
+                "h_invalid_setup",
+                format!(r#""expect_failure": true, "mappings": {{ "missing": {{ "{caller}": "1u64" }} }}"#),
+            ),
+        ];
+        std::fs::create_dir_all(package.tests_directory()).unwrap();
+        for (name, test) in tests {
+            let mut file = File::create(package.tests_directory().join(format!("{name}.json"))).unwrap();
+            file.write_all(format!(r#"{{ "function": "add", {test} }}"#).as_bytes()).unwrap();
+        }
+        // Ensure files without the JSON extension are ignored.
+        File::create(package.tests_directory().join("README.md")).unwrap();
+
+        // Run the tests.
+        let results = package.test::<CurrentAleo, _>(None, false, &mut TestRng::default()).unwrap();
+        let passed = results.iter().map(|result| (result.name(), result.is_passed())).collect::<Vec<_>>();
+        assert_eq!(passed, vec![
+            ("a_pass", true),
+            ("b_wrong_output", false),
+            ("c_wrong_finalize", false),
+            ("d_expect_failure", true),
+            ("e_unexpected_success", false),
+            ("f_expect_error", true),
+            ("g_unexpected_error", false),
+            ("h_invalid_setup", false),
+        ]);
+        // Ensure the failures explain the unexpected error, and the setup error.
+        let failure = |index: usize| results[index].failure().unwrap().to_string();
+        assert!(failure(6).starts_with("Expected an error containing 'overflow'"), "{}", failure(6));
+        assert!(failure(7).starts_with("Failed to set up the test"), "{}", failure(7));
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_prove_tests() {
+        // Samples a new package at a temporary directory.
+        let (directory, package) = crate::package::test_helpers::sample_package_with_mapping();
+        // Retrieve the development address, which is the caller in each test.
+        let caller = package.manifest_file().development_address();
+
+        // Write the test files, which are named to run in order.
+        let tests = [
+            ("a_pass", format!(r#""inputs": ["{caller}", "3u64"], "outputs": ["7u64"],
+                "mappings": {{ "counts": {{ "{caller}": "1u64" }} }},
+                "finalize": {{ "counts": {{ "{caller}": "4u64" }} }}"#)),
+            ("b_wrong_finalize", format!(r#""inputs": ["{caller}", "3u64"], "finalize": {{ "counts": {{}} }}"#)),
+            ("c_expect_failure", format!(r#""inputs": ["{caller}", "11u64"], "expect_failure": true"#)),
+        ];
+        std::fs::create_dir_all(package.tests_directory()).unwrap();
+        for (name, test) in tests {
+            let mut file = File::create(package.tests_directory().join(format!("{name}.json"))).unwrap();
+            file.write_all(format!(r#"{{ "function": "add", {test} }}"#).as_bytes()).unwrap();
+        }
+
+        // Run the tests, with proving.
+        let results = package.test::<CurrentAleo, _>(None, true, &mut TestRng::default()).unwrap();
+        let passed = results.iter().map(|result| (result.name(), result.is_passed())).collect::<Vec<_>>();
+        assert_eq!(passed, vec![("a_pass", true), ("b_wrong_finalize", false), ("c_expect_failure", true)]);
+        // Ensure the package was built.
+        assert!(package.build_directory().exists());
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}